@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+#[constant]
+pub const TREASURY_SEED: &[u8] = b"treasury";
+
+#[constant]
+pub const TREASURY_RWD_SEED: &[u8] = b"treasury-rwd";
+
+#[constant]
+pub const TREASURY_USDC_SEED: &[u8] = b"treasury-usdc";
+
+#[constant]
+pub const STREAM_SEED: &[u8] = b"stream";
+
+#[constant]
+pub const DEPOSIT_SEED: &[u8] = b"deposit";
+
+#[constant]
+pub const VOTE_SEED: &[u8] = b"vote";
+
+#[constant]
+pub const RANKED_TALLY_SEED: &[u8] = b"ranked-tally";
+
+#[constant]
+pub const DELEGATION_SEED: &[u8] = b"delegation";
+
+// Delegations with this topic cover proposals of every topic
+#[constant]
+pub const ALL_TOPICS: u8 = 0;
+
+#[constant]
+pub const MEMBER_SEED: &[u8] = b"member";
+
+#[constant]
+pub const LOCK_VAULT_SEED: &[u8] = b"lock-vault";
+
+#[constant]
+pub const MIN_LOCK_DURATION: i64 = 7 * 24 * 60 * 60;
+
+// A lock this long gives one unit of voting power per RWD base unit
+#[constant]
+pub const MAX_LOCK_DURATION: i64 = 4 * 365 * 24 * 60 * 60;
+
+// Lock and reputation changes remembered per member; once full the two oldest are merged
+#[constant]
+pub const MAX_POWER_CHECKPOINTS: usize = 16;
+
+#[constant]
+pub const DAO_CONFIG_SEED: &[u8] = b"dao-config";
+
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+#[constant]
+pub const MAX_TITLE_LEN: usize = 64;
+
+#[constant]
+pub const MAX_DESCRIPTION_URI_LEN: usize = 200;
+
+#[constant]
+pub const MAX_OPTIONS: usize = 8;
+
+#[constant]
+pub const MAX_OPTION_LEN: usize = 32;
+
+pub const DISCRIMINATOR: usize = 8;
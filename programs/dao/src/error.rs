@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum GovernanceError {
+    #[msg("Cannot get results for an open proposal")]
+    ProposalStillOpen, // 6000
+
+    #[msg("Only the DAO admin can close the proposal before voting ends")]
+    UnauthorizedClose, // 6001
+
+    #[msg("The proposal does not carry a treasury action of this kind")]
+    InvalidTreasuryAction, // 6002
+
+    #[msg("The proposal did not pass")]
+    ProposalNotApproved, // 6003
+
+    #[msg("The proposal has already been executed")]
+    ProposalAlreadyExecuted, // 6004
+
+    #[msg("Spend amount must be greater than zero")]
+    InvalidSpendAmount, // 6005
+
+    #[msg("Stream must end after it starts")]
+    InvalidStreamSchedule, // 6006
+
+    #[msg("Recipient does not match the proposal")]
+    InvalidRecipient, // 6007
+
+    #[msg("Nothing is available to withdraw from the stream yet")]
+    NothingToWithdraw, // 6008
+
+    #[msg("Proposal title exceeds the maximum length")]
+    TitleTooLong, // 6009
+
+    #[msg("Proposal description URI exceeds the maximum length")]
+    DescriptionUriTooLong, // 6010
+
+    #[msg("Proposal has too many options")]
+    TooManyOptions, // 6011
+
+    #[msg("Proposal option exceeds the maximum length")]
+    OptionTooLong, // 6012
+
+    #[msg("Unauthorized access: Caller does not have the required permissions.")]
+    Unauthorized, // 6013
+
+    #[msg("Reputation config is invalid")]
+    InvalidReputationConfig, // 6014
+
+    #[msg("The proposal does not carry an action of this kind")]
+    InvalidProposalAction, // 6015
+
+    #[msg("Members cannot delegate to themselves")]
+    SelfDelegation, // 6016
+
+    #[msg("Delegation does not apply to this vote")]
+    InvalidDelegation, // 6017
+
+    #[msg("Delegator has already voted on this proposal")]
+    DelegatorAlreadyVoted, // 6018
+
+    #[msg("Delegated votes must be passed as (delegation, delegator member, delegator vote record) triples")]
+    InvalidDelegationAccounts, // 6019
+
+    #[msg("Deposit config is invalid")]
+    InvalidDepositConfig, // 6020
+
+    #[msg("Proposer needs more reputation or RWD stake to submit proposals")]
+    InsufficientProposerStanding, // 6021
+
+    #[msg("Proposal deposit has already been settled")]
+    DepositAlreadySettled, // 6022
+
+    #[msg("Ballot does not match the proposal's voting mode")]
+    BallotModeMismatch, // 6023
+
+    #[msg("Ballot is malformed")]
+    InvalidBallot, // 6024
+
+    #[msg("Quadratic ballots need the treasury and RWD payment accounts")]
+    MissingPaymentAccounts, // 6025
+
+    #[msg("Ranked-choice results need every vote record of the proposal tallied")]
+    IncompleteBallots, // 6026
+
+    #[msg("Vote refers to an option the proposal does not have")]
+    OptionIndexOutOfRange, // 6027
+
+    #[msg("Proposal is not open for voting")]
+    ProposalNotOpen, // 6028
+
+    #[msg("Proposals need at least two options")]
+    NotEnoughOptions, // 6029
+
+    #[msg("Proposal options cannot be empty")]
+    EmptyOption, // 6030
+
+    #[msg("Proposal options must be unique")]
+    DuplicateOption, // 6031
+
+    #[msg("Lock duration is outside the allowed range")]
+    InvalidLockDuration, // 6032
+
+    #[msg("Lock amount must be greater than zero")]
+    InvalidLockAmount, // 6033
+
+    #[msg("Member already has an active lock")]
+    LockAlreadyExists, // 6034
+
+    #[msg("Member has no active lock")]
+    NoActiveLock, // 6035
+
+    #[msg("Locked RWD cannot be withdrawn before the lock ends")]
+    LockNotExpired, // 6036
+
+    #[msg("Governance config is invalid")]
+    InvalidGovernanceConfig, // 6037
+
+    #[msg("Voting on this proposal has ended")]
+    VotingEnded, // 6038
+
+    #[msg("Voting on this proposal has not ended yet")]
+    VotingNotEnded, // 6039
+
+    #[msg("The ranked-choice tally is already finished")]
+    TallyFinished, // 6040
+
+    #[msg("Vote record does not belong to this proposal")]
+    InvalidVoteRecord, // 6041
+
+    #[msg("Vote count overflow")]
+    VoteCountOverflow, // 6042
+}
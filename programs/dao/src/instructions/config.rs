@@ -0,0 +1,195 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::*;
+use crate::events::*;
+
+pub fn _initialize_dao_config(
+    ctx: Context<InitializeDaoConfig>,
+    reputation: ReputationConfig,
+    deposit: DepositConfig,
+    governance: GovernanceConfig,
+) -> Result<()> {
+    reputation.validate()?;
+    deposit.validate()?;
+    governance.validate()?;
+
+    let dao_config = &mut ctx.accounts.dao_config;
+    dao_config.admin = ctx.accounts.signer.key();
+    dao_config.reputation = reputation;
+    dao_config.deposit = deposit;
+    dao_config.governance = governance;
+    dao_config.bump = ctx.bumps.dao_config;
+
+    emit!(ReputationConfigUpdatedEvent {
+        admin: dao_config.admin,
+        reputation: dao_config.reputation.clone(),
+    });
+    emit!(DepositConfigUpdatedEvent {
+        admin: dao_config.admin,
+        deposit: dao_config.deposit.clone(),
+    });
+    emit!(GovernanceConfigUpdatedEvent {
+        admin: dao_config.admin,
+        governance: dao_config.governance.clone(),
+    });
+
+    Ok(())
+}
+
+pub fn _update_reputation_config(ctx: Context<UpdateDaoConfig>, reputation: ReputationConfig) -> Result<()> {
+    reputation.validate()?;
+
+    let dao_config = &mut ctx.accounts.dao_config;
+    dao_config.reputation = reputation;
+
+    emit!(ReputationConfigUpdatedEvent {
+        admin: dao_config.admin,
+        reputation: dao_config.reputation.clone(),
+    });
+
+    Ok(())
+}
+
+pub fn _update_deposit_config(ctx: Context<UpdateDaoConfig>, deposit: DepositConfig) -> Result<()> {
+    deposit.validate()?;
+
+    let dao_config = &mut ctx.accounts.dao_config;
+    dao_config.deposit = deposit;
+
+    emit!(DepositConfigUpdatedEvent {
+        admin: dao_config.admin,
+        deposit: dao_config.deposit.clone(),
+    });
+
+    Ok(())
+}
+
+// Applies to proposals submitted afterwards; open proposals keep the quorum and
+// voting period they were submitted with
+pub fn _update_governance_config(ctx: Context<UpdateDaoConfig>, governance: GovernanceConfig) -> Result<()> {
+    governance.validate()?;
+
+    let dao_config = &mut ctx.accounts.dao_config;
+    dao_config.governance = governance;
+
+    emit!(GovernanceConfigUpdatedEvent {
+        admin: dao_config.admin,
+        governance: dao_config.governance.clone(),
+    });
+
+    Ok(())
+}
+
+impl ReputationConfig {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.decay_bps as u64 <= BPS_DENOMINATOR,
+            GovernanceError::InvalidReputationConfig
+        );
+        require!(self.decay_period > 0, GovernanceError::InvalidReputationConfig);
+        Ok(())
+    }
+}
+
+impl DepositConfig {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.forfeit_threshold_bps as u64 <= BPS_DENOMINATOR,
+            GovernanceError::InvalidDepositConfig
+        );
+        Ok(())
+    }
+}
+
+impl GovernanceConfig {
+    pub fn validate(&self) -> Result<()> {
+        require!(self.quorum > 0, GovernanceError::InvalidGovernanceConfig);
+        require!(self.voting_period > 0, GovernanceError::InvalidGovernanceConfig);
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct InitializeDaoConfig<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [DAO_CONFIG_SEED],
+        bump,
+        space = DISCRIMINATOR + DaoConfig::INIT_SPACE,
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    // Only the program's upgrade authority can claim the DAO admin role
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, crate::program::Dao>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(signer.key()) @ GovernanceError::Unauthorized,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateDaoConfig<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [DAO_CONFIG_SEED],
+        bump = dao_config.bump,
+        constraint = dao_config.admin == signer.key() @ GovernanceError::Unauthorized,
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct DaoConfig {
+    pub admin: Pubkey,
+    pub reputation: ReputationConfig,
+    pub deposit: DepositConfig,
+    pub governance: GovernanceConfig,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Debug)]
+pub struct ReputationConfig {
+    pub proposal_points: u64,
+    pub vote_points: u64,
+    // Points per unit of rewards activity reported by the admin
+    pub activity_points: u64,
+    pub max_points: u64,
+    // Share of points lost every `decay_period` seconds
+    pub decay_bps: u16,
+    pub decay_period: i64,
+    // Voting power bonus per reputation point, capped at `max_multiplier_bonus_bps`
+    pub multiplier_bps_per_point: u16,
+    pub max_multiplier_bonus_bps: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Debug)]
+pub struct DepositConfig {
+    // RWD escrowed with every proposal
+    pub amount: u64,
+    // Proposers need either this much reputation or this much RWD in an active lock
+    pub min_reputation: u64,
+    pub min_stake: u64,
+    // Total vote weight at which the deposit is always refunded
+    pub quorum: u64,
+    // Below quorum, the deposit is forfeited when option 0 got less than this share of the votes
+    pub forfeit_threshold_bps: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Debug)]
+pub struct GovernanceConfig {
+    // Total vote weight a proposal needs before its action can be executed
+    pub quorum: u64,
+    // Seconds a proposal stays open for voting; it only passes once this has elapsed
+    pub voting_period: i64,
+}
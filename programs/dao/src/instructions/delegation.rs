@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::*;
+use crate::events::*;
+use crate::utils::*;
+use crate::{Ballot, GovernanceProposal, Member, VoteRecord};
+
+pub fn _delegate_votes(ctx: Context<DelegateVotes>, topic: u8) -> Result<()> {
+    let delegator_member = &ctx.accounts.delegator_member;
+    let delegate_member = &ctx.accounts.delegate_member;
+    require_keys_neq!(delegator_member.key(), delegate_member.key(), GovernanceError::SelfDelegation);
+
+    let delegation = &mut ctx.accounts.delegation;
+    delegation.delegator = delegator_member.key();
+    delegation.delegate = delegate_member.key();
+    delegation.topic = topic;
+    delegation.created_at = Clock::get()?.unix_timestamp;
+    delegation.bump = ctx.bumps.delegation;
+
+    emit!(DelegationCreatedEvent {
+        delegator: delegation.delegator,
+        delegate: delegation.delegate,
+        topic,
+    });
+
+    Ok(())
+}
+
+pub fn _revoke_delegation(ctx: Context<RevokeDelegation>, topic: u8) -> Result<()> {
+    let delegation = &ctx.accounts.delegation;
+
+    emit!(DelegationRevokedEvent {
+        delegator: delegation.delegator,
+        delegate: delegation.delegate,
+        topic,
+    });
+
+    Ok(())
+}
+
+/// Counts the votes delegated to `delegate` on `proposal`. `remaining_accounts` holds
+/// (delegation, delegator member, delegator vote record) triples; a vote record is
+/// created for every delegator so neither they nor another delegate can vote again.
+/// Returns the total delegated weight and the number of delegators counted.
+pub fn collect_delegated_votes<'info>(
+    proposal: &Account<'info, GovernanceProposal>,
+    delegate: &Pubkey,
+    ballot: &Ballot,
+    remaining_accounts: &[AccountInfo<'info>],
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<(u64, u32)> {
+    let triples = remaining_accounts.chunks_exact(3);
+    require!(triples.remainder().is_empty(), GovernanceError::InvalidDelegationAccounts);
+
+    let mut delegated_weight: u64 = 0;
+    let mut delegators: u32 = 0;
+
+    for accounts in triples {
+        let (delegation_info, delegator_info, vote_record_info) = (&accounts[0], &accounts[1], &accounts[2]);
+        require_keys_eq!(*delegation_info.owner, crate::ID, GovernanceError::InvalidDelegationAccounts);
+        require_keys_eq!(*delegator_info.owner, crate::ID, GovernanceError::InvalidDelegationAccounts);
+
+        let delegation = Delegation::try_deserialize(&mut &delegation_info.try_borrow_data()?[..])?;
+        let delegator = Member::try_deserialize(&mut &delegator_info.try_borrow_data()?[..])?;
+
+        require_keys_eq!(delegation.delegate, *delegate, GovernanceError::InvalidDelegation);
+        require_keys_eq!(delegation.delegator, delegator_info.key(), GovernanceError::InvalidDelegation);
+        require!(
+            delegation.topic == ALL_TOPICS || delegation.topic == proposal.topic,
+            GovernanceError::InvalidDelegation
+        );
+
+        let proposal_key = proposal.key();
+        let delegator_key = delegator_info.key();
+        let (vote_record_key, bump) = Pubkey::find_program_address(
+            &[VOTE_SEED, proposal_key.as_ref(), delegator_key.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(vote_record_info.key(), vote_record_key, GovernanceError::InvalidDelegationAccounts);
+        require!(vote_record_info.data_is_empty(), GovernanceError::DelegatorAlreadyVoted);
+
+        let weight = proposal.voting_power_of(&delegator);
+
+        create_pda_account(
+            payer.clone(),
+            vote_record_info.clone(),
+            system_program.clone(),
+            DISCRIMINATOR + VoteRecord::INIT_SPACE,
+            &[&[VOTE_SEED, proposal_key.as_ref(), delegator_key.as_ref(), &[bump]]],
+        )?;
+
+        let vote_record = VoteRecord {
+            proposal: proposal_key,
+            member: delegator_key,
+            ballot: ballot.clone(),
+            weight,
+            voted_by: *delegate,
+            tally_round: 0,
+        };
+        vote_record.try_serialize(&mut &mut vote_record_info.try_borrow_mut_data()?[..])?;
+
+        delegated_weight = delegated_weight.checked_add(weight).ok_or(GovernanceError::VoteCountOverflow)?;
+        delegators = delegators.checked_add(1).ok_or(GovernanceError::VoteCountOverflow)?;
+    }
+
+    Ok((delegated_weight, delegators))
+}
+
+#[derive(Accounts)]
+#[instruction(topic: u8)]
+pub struct DelegateVotes<'info> {
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+
+    #[account(
+        seeds = [MEMBER_SEED, delegator.key().as_ref()],
+        bump = delegator_member.bump,
+    )]
+    pub delegator_member: Account<'info, Member>,
+
+    pub delegate_member: Account<'info, Member>,
+
+    #[account(
+        init,
+        payer = delegator,
+        seeds = [DELEGATION_SEED, delegator_member.key().as_ref(), &[topic]],
+        bump,
+        space = DISCRIMINATOR + Delegation::INIT_SPACE,
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(topic: u8)]
+pub struct RevokeDelegation<'info> {
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+
+    #[account(
+        seeds = [MEMBER_SEED, delegator.key().as_ref()],
+        bump = delegator_member.bump,
+    )]
+    pub delegator_member: Account<'info, Member>,
+
+    #[account(
+        mut,
+        close = delegator,
+        seeds = [DELEGATION_SEED, delegator_member.key().as_ref(), &[topic]],
+        bump = delegation.bump,
+    )]
+    pub delegation: Account<'info, Delegation>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Delegation {
+    // Member accounts, not wallets
+    pub delegator: Pubkey,
+    pub delegate: Pubkey,
+    pub topic: u8,
+    pub created_at: i64,
+    pub bump: u8,
+}
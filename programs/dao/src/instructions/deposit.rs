@@ -0,0 +1,156 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::*;
+use crate::events::*;
+use crate::utils::*;
+use crate::{DaoConfig, DepositConfig, GovernanceProposal, ProposalStatus, Treasury};
+
+use anchor_spl::token_interface::{
+    close_account,
+    CloseAccount,
+    Mint as Mint2022,
+    TokenAccount as TokenAccount2022,
+    TokenInterface,
+};
+
+// Remaining accounts: transfer hook extra accounts for the refund or forfeit transfer
+pub fn _settle_deposit<'info>(
+    ctx: Context<'_, '_, '_, 'info, SettleDeposit<'info>>,
+) -> Result<()> {
+    let proposal = &ctx.accounts.governance_proposal;
+    require!(proposal.status == ProposalStatus::Closed, GovernanceError::ProposalStillOpen);
+    require!(
+        Clock::get()?.unix_timestamp >= proposal.voting_ends_at,
+        GovernanceError::VotingNotEnded
+    );
+    require!(!proposal.deposit_settled, GovernanceError::DepositAlreadySettled);
+
+    let refunded = proposal.deposit_refundable(&ctx.accounts.dao_config.deposit);
+    let destination = if refunded {
+        ctx.accounts.proposer_rwd.to_account_info()
+    } else {
+        ctx.accounts.treasury_rwd_vault.to_account_info()
+    };
+
+    let treasury = &ctx.accounts.treasury;
+    let signer_seeds: &[&[&[u8]]] = &[&[TREASURY_SEED, &[treasury.bump]]];
+    let amount = ctx.accounts.deposit_vault.amount;
+
+    if amount > 0 {
+        transfer_rwd_signed(
+            &ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.deposit_vault.to_account_info(),
+            ctx.accounts.rwd_mint.to_account_info(),
+            destination,
+            treasury.to_account_info(),
+            ctx.remaining_accounts,
+            amount,
+            ctx.accounts.rwd_mint.decimals,
+            signer_seeds,
+        )?;
+    }
+
+    // The vault is emptied, so hand its rent back to the proposer
+    close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.deposit_vault.to_account_info(),
+            destination: ctx.accounts.proposer.to_account_info(),
+            authority: treasury.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    let proposal = &mut ctx.accounts.governance_proposal;
+    proposal.deposit_settled = true;
+
+    emit!(DepositSettledEvent {
+        proposal: proposal.key(),
+        proposer: proposal.proposer,
+        amount,
+        refunded,
+    });
+
+    Ok(())
+}
+
+impl GovernanceProposal {
+    /// Deposits come back once the proposal reaches quorum. Below quorum they are only
+    /// kept when option 0 still drew at least `forfeit_threshold_bps` of the votes cast.
+    /// The proposer's own votes count toward neither.
+    pub fn deposit_refundable(&self, config: &DepositConfig) -> bool {
+        let results: Vec<u64> = self
+            .tally()
+            .iter()
+            .zip(self.proposer_votes)
+            .map(|(count, own)| count.saturating_sub(own))
+            .collect();
+        let total: u64 = results.iter().sum();
+        if total >= config.quorum {
+            return true;
+        }
+        if total == 0 {
+            return false;
+        }
+
+        let approval_bps = results[0] as u128 * BPS_DENOMINATOR as u128 / total as u128;
+        approval_bps >= config.forfeit_threshold_bps as u128
+    }
+}
+
+#[derive(Accounts)]
+pub struct SettleDeposit<'info> {
+    #[account(mut)]
+    pub governance_proposal: Account<'info, GovernanceProposal>,
+
+    #[account(
+        seeds = [DAO_CONFIG_SEED],
+        bump = dao_config.bump,
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        address = treasury.rwd_mint,
+        mint::token_program = token_program,
+    )]
+    pub rwd_mint: Box<InterfaceAccount<'info, Mint2022>>,
+
+    #[account(
+        mut,
+        seeds = [DEPOSIT_SEED, governance_proposal.key().as_ref()],
+        bump,
+        token::mint = rwd_mint,
+        token::authority = treasury,
+        token::token_program = token_program,
+    )]
+    pub deposit_vault: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    #[account(
+        mut,
+        token::mint = rwd_mint,
+        token::authority = governance_proposal.proposer,
+        token::token_program = token_program,
+    )]
+    pub proposer_rwd: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    #[account(
+        mut,
+        address = treasury.rwd_vault,
+    )]
+    pub treasury_rwd_vault: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    /// CHECK: receives the escrow vault's rent
+    #[account(
+        mut,
+        address = governance_proposal.proposer,
+    )]
+    pub proposer: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
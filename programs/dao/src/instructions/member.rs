@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+pub fn _initialize_member(ctx: Context<InitializeMember>) -> Result<()> {
+    let member = &mut ctx.accounts.member;
+    member.authority = ctx.accounts.user.key();
+    member.reputation_points = 0;
+    member.last_decay_ts = Clock::get()?.unix_timestamp;
+    member.locked_amount = 0;
+    member.lock_end = 0;
+    member.power_checkpoints = Vec::new();
+    member.bump = ctx.bumps.member;
+    Ok(())
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Member {
+    pub authority: Pubkey,
+    pub reputation_points: u64,
+    pub last_decay_ts: i64,
+    pub bump: u8,
+    // Vote-escrowed RWD held in the member's lock vault. The transfer hook reads
+    // `lock_end` at a fixed offset, so keep these fields where they are.
+    pub locked_amount: u64,
+    pub lock_end: i64,
+    // History of the lock and reputation, oldest first, so votes can use the voting
+    // power as of a past slot
+    #[max_len(MAX_POWER_CHECKPOINTS)]
+    pub power_checkpoints: Vec<PowerCheckpoint>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq)]
+pub struct PowerCheckpoint {
+    pub slot: u64,
+    pub locked_amount: u64,
+    pub lock_end: i64,
+    pub reputation_points: u64,
+    pub last_decay_ts: i64,
+}
+
+#[derive(Accounts)]
+pub struct InitializeMember<'info> {
+    #[account(
+        init,
+        payer = user,
+        seeds = [MEMBER_SEED, user.key().as_ref()],
+        bump,
+        space = DISCRIMINATOR + Member::INIT_SPACE,
+    )]
+    pub member: Account<'info, Member>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
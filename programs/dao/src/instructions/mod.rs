@@ -0,0 +1,19 @@
+pub mod config;
+pub mod delegation;
+pub mod deposit;
+pub mod member;
+pub mod proposal;
+pub mod ranked_tally;
+pub mod reputation;
+pub mod staking;
+pub mod treasury;
+
+pub use config::*;
+pub use delegation::*;
+pub use deposit::*;
+pub use member::*;
+pub use proposal::*;
+pub use ranked_tally::*;
+pub use reputation::*;
+pub use staking::*;
+pub use treasury::*;
@@ -0,0 +1,670 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::*;
+use crate::events::*;
+use crate::utils::*;
+use crate::{
+    apply_reputation_change,
+    collect_delegated_votes,
+    DaoConfig,
+    Member,
+    RankedTally,
+    ReputationConfig,
+    ReputationReason,
+    Treasury,
+    TreasuryAsset,
+};
+
+use anchor_spl::token_interface::{
+    Mint as Mint2022,
+    TokenAccount as TokenAccount2022,
+    TokenInterface,
+};
+
+// Remaining accounts: transfer hook extra accounts for the RWD deposit
+pub fn _submit_proposal<'info>(
+    ctx: Context<'_, '_, '_, 'info, SubmitProposal<'info>>,
+    title: String,
+    description_uri: String,
+    description_hash: [u8; 32],
+    topic: u8,
+    voting_mode: VotingMode,
+    options: Vec<String>,
+) -> Result<()> {
+    validate_proposal_content(&title, &description_uri, &options)?;
+    ctx.accounts.escrow_deposit(ctx.remaining_accounts)?;
+
+    let proposal = &mut ctx.accounts.governance_proposal;
+    let clock = Clock::get()?;
+    proposal.created_slot = clock.slot;
+    proposal.created_at = clock.unix_timestamp;
+    proposal.open_voting(&ctx.accounts.dao_config, clock.unix_timestamp);
+    proposal.title = title;
+    proposal.description_uri = description_uri;
+    proposal.description_hash = description_hash;
+    proposal.topic = topic;
+    proposal.voting_mode = voting_mode;
+    proposal.options = options;
+    proposal.status = ProposalStatus::Open;
+    proposal.vote_counts = [0; MAX_OPTIONS];
+    proposal.proposer_votes = [0; MAX_OPTIONS];
+    proposal.voters = 0;
+    proposal.action = None;
+    proposal.executed = false;
+
+    let config = &ctx.accounts.dao_config.reputation;
+    apply_reputation_change(
+        &mut ctx.accounts.proposer_member,
+        config,
+        ReputationReason::ProposalCreated,
+        config.proposal_points,
+    )
+}
+
+pub fn _submit_action_proposal<'info>(
+    ctx: Context<'_, '_, '_, 'info, SubmitProposal<'info>>,
+    title: String,
+    description_uri: String,
+    description_hash: [u8; 32],
+    topic: u8,
+    action: ProposalAction,
+) -> Result<()> {
+    action.validate()?;
+
+    // Action proposals are a yes/no question: option 0 approves the action
+    let options = vec!["Approve".to_string(), "Reject".to_string()];
+    validate_proposal_content(&title, &description_uri, &options)?;
+    ctx.accounts.escrow_deposit(ctx.remaining_accounts)?;
+
+    let proposal = &mut ctx.accounts.governance_proposal;
+    let clock = Clock::get()?;
+    proposal.created_slot = clock.slot;
+    proposal.created_at = clock.unix_timestamp;
+    proposal.open_voting(&ctx.accounts.dao_config, clock.unix_timestamp);
+    proposal.title = title;
+    proposal.description_uri = description_uri;
+    proposal.description_hash = description_hash;
+    proposal.topic = topic;
+    proposal.voting_mode = VotingMode::Single;
+    proposal.options = options;
+    proposal.status = ProposalStatus::Open;
+    proposal.vote_counts = [0; MAX_OPTIONS];
+    proposal.proposer_votes = [0; MAX_OPTIONS];
+    proposal.voters = 0;
+    proposal.action = Some(action);
+    proposal.executed = false;
+
+    let config = &ctx.accounts.dao_config.reputation;
+    apply_reputation_change(
+        &mut ctx.accounts.proposer_member,
+        config,
+        ReputationReason::ProposalCreated,
+        config.proposal_points,
+    )
+}
+
+// Remaining accounts: (delegation, delegator member, delegator vote record) triples
+// for every delegator whose votes the member casts alongside their own. Quadratic
+// ballots cannot carry delegations and pass the RWD transfer hook accounts instead.
+pub fn _cast_vote<'info>(
+    ctx: Context<'_, '_, '_, 'info, CastVote<'info>>,
+    ballot: Ballot,
+) -> Result<()> {
+    let proposal = &ctx.accounts.governance_proposal;
+    require!(proposal.status == ProposalStatus::Open, GovernanceError::ProposalNotOpen);
+    require!(
+        Clock::get()?.unix_timestamp < proposal.voting_ends_at,
+        GovernanceError::VotingEnded
+    );
+    ballot.validate(proposal.voting_mode, proposal.options.len())?;
+
+    if let Ballot::Quadratic { votes, .. } = ballot {
+        ctx.accounts.pay_quadratic_cost(votes, ctx.remaining_accounts)?;
+    }
+
+    let member = &mut ctx.accounts.member;
+
+    let (weight, delegated_weight, delegators) = match ballot {
+        // Quadratic votes are bought, not weighted by reputation
+        Ballot::Quadratic { votes, .. } => (votes, 0, 0),
+        _ => {
+            let weight = ctx.accounts.governance_proposal.voting_power_of(member);
+            let (delegated_weight, delegators) = collect_delegated_votes(
+                &ctx.accounts.governance_proposal,
+                &member.key(),
+                &ballot,
+                ctx.remaining_accounts,
+                &ctx.accounts.voter.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+            )?;
+            (weight, delegated_weight, delegators)
+        }
+    };
+
+    let vote_record = &mut ctx.accounts.vote_record;
+    vote_record.proposal = ctx.accounts.governance_proposal.key();
+    vote_record.member = member.key();
+    vote_record.ballot = ballot.clone();
+    vote_record.weight = weight;
+    vote_record.voted_by = member.key();
+    vote_record.tally_round = 0;
+
+    let proposal = &mut ctx.accounts.governance_proposal;
+    let total_weight = weight.checked_add(delegated_weight).ok_or(GovernanceError::VoteCountOverflow)?;
+    proposal.record_ballot(&ballot, total_weight)?;
+    if member.authority == proposal.proposer {
+        proposal.record_proposer_ballot(&ballot, weight)?;
+    }
+    proposal.voters = delegators
+        .checked_add(1)
+        .and_then(|voters| proposal.voters.checked_add(voters))
+        .ok_or(GovernanceError::VoteCountOverflow)?;
+
+    emit!(VoteCastEvent {
+        proposal: proposal.key(),
+        member: member.key(),
+        ballot,
+        weight,
+        delegated_weight,
+        delegators,
+    });
+
+    let config = &ctx.accounts.dao_config.reputation;
+    apply_reputation_change(member, config, ReputationReason::VoteCast, config.vote_points)
+}
+
+// Anyone can close a proposal once voting has ended; the DAO admin can also close it
+// early, but a proposal closed before its voting period is over never passes
+pub fn _close_proposal(ctx: Context<CloseProposal>) -> Result<()> {
+    let proposal = &mut ctx.accounts.governance_proposal;
+    require!(proposal.status == ProposalStatus::Open, GovernanceError::ProposalNotOpen);
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now >= proposal.voting_ends_at || ctx.accounts.dao.key() == ctx.accounts.dao_config.admin,
+        GovernanceError::UnauthorizedClose
+    );
+
+    proposal.status = ProposalStatus::Closed;
+    proposal.closed_at = now;
+    Ok(())
+}
+
+// Ranked-choice results come from the finished `tally_ranked_votes` crank
+pub fn _get_proposal_results(ctx: Context<GetProposalResults>) -> Result<Vec<u64>> {
+    let proposal = &ctx.accounts.governance_proposal;
+
+    require!(
+        proposal.status == ProposalStatus::Closed,
+        GovernanceError::ProposalStillOpen
+    );
+
+    if proposal.voting_mode != VotingMode::RankedChoice {
+        return Ok(proposal.tally());
+    }
+
+    let tally = ctx.accounts.ranked_tally.as_ref().ok_or(GovernanceError::IncompleteBallots)?;
+    require!(tally.finished, GovernanceError::IncompleteBallots);
+
+    Ok(tally.counts[..proposal.options.len()].to_vec())
+}
+
+fn validate_proposal_content(title: &str, description_uri: &str, options: &[String]) -> Result<()> {
+    require!(title.len() <= MAX_TITLE_LEN, GovernanceError::TitleTooLong);
+    require!(
+        description_uri.len() <= MAX_DESCRIPTION_URI_LEN,
+        GovernanceError::DescriptionUriTooLong
+    );
+    require!(options.len() >= 2, GovernanceError::NotEnoughOptions);
+    require!(options.len() <= MAX_OPTIONS, GovernanceError::TooManyOptions);
+    require!(
+        options.iter().all(|option| option.len() <= MAX_OPTION_LEN),
+        GovernanceError::OptionTooLong
+    );
+    require!(
+        options.iter().all(|option| !option.trim().is_empty()),
+        GovernanceError::EmptyOption
+    );
+    require!(
+        options.iter().enumerate().all(|(index, option)| !options[..index].contains(option)),
+        GovernanceError::DuplicateOption
+    );
+    Ok(())
+}
+
+impl<'info> SubmitProposal<'info> {
+    /// Checks the proposer's reputation or RWD stake, then moves the configured
+    /// deposit into the proposal's escrow vault.
+    fn escrow_deposit(&mut self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let deposit = &self.dao_config.deposit;
+        let member = &self.proposer_member;
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            member.reputation_points >= deposit.min_reputation
+                || (member.has_active_lock(now) && member.locked_amount >= deposit.min_stake),
+            GovernanceError::InsufficientProposerStanding
+        );
+
+        if deposit.amount > 0 {
+            transfer_rwd_signed(
+                &self.token_program.to_account_info(),
+                self.proposer_rwd.to_account_info(),
+                self.rwd_mint.to_account_info(),
+                self.deposit_vault.to_account_info(),
+                self.proposer.to_account_info(),
+                remaining_accounts,
+                deposit.amount,
+                self.rwd_mint.decimals,
+                &[],
+            )?;
+        }
+
+        let proposal = &mut self.governance_proposal;
+        proposal.proposer = self.proposer.key();
+        proposal.deposit_amount = deposit.amount;
+        proposal.deposit_settled = false;
+
+        emit!(ProposalDepositedEvent {
+            proposal: proposal.key(),
+            proposer: proposal.proposer,
+            amount: deposit.amount,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> CastVote<'info> {
+    /// Charges votes² whole RWD for a quadratic ballot, paid into the treasury.
+    fn pay_quadratic_cost(&self, votes: u64, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let (Some(treasury), Some(rwd_mint), Some(voter_rwd), Some(treasury_rwd_vault), Some(token_program)) = (
+            &self.treasury,
+            &self.rwd_mint,
+            &self.voter_rwd,
+            &self.treasury_rwd_vault,
+            &self.token_program,
+        ) else {
+            return err!(GovernanceError::MissingPaymentAccounts);
+        };
+        treasury.check_asset_accounts(TreasuryAsset::Rwd, &rwd_mint.key(), &treasury_rwd_vault.key())?;
+
+        let cost = quadratic_cost(votes)
+            .and_then(|credits| credits.checked_mul(10u64.pow(rwd_mint.decimals as u32)))
+            .ok_or(GovernanceError::InvalidBallot)?;
+
+        transfer_rwd_signed(
+            &token_program.to_account_info(),
+            voter_rwd.to_account_info(),
+            rwd_mint.to_account_info(),
+            treasury_rwd_vault.to_account_info(),
+            self.voter.to_account_info(),
+            remaining_accounts,
+            cost,
+            rwd_mint.decimals,
+            &[],
+        )
+    }
+}
+
+impl Ballot {
+    pub fn validate(&self, voting_mode: VotingMode, option_count: usize) -> Result<()> {
+        let matches_mode = matches!(
+            (self, voting_mode),
+            (Ballot::Single { .. }, VotingMode::Single)
+                | (Ballot::Approval { .. }, VotingMode::Approval)
+                | (Ballot::Ranked { .. }, VotingMode::RankedChoice)
+                | (Ballot::Quadratic { .. }, VotingMode::Quadratic)
+        );
+        require!(matches_mode, GovernanceError::BallotModeMismatch);
+
+        match self {
+            Ballot::Single { option_index } => {
+                require!((*option_index as usize) < option_count, GovernanceError::OptionIndexOutOfRange);
+            }
+            Ballot::Approval { options_mask } => {
+                require!(*options_mask != 0, GovernanceError::InvalidBallot);
+                require!(
+                    (*options_mask as u32) >> option_count == 0,
+                    GovernanceError::OptionIndexOutOfRange
+                );
+            }
+            Ballot::Ranked { ranking } => {
+                require!(
+                    !ranking.is_empty() && ranking.len() <= MAX_OPTIONS,
+                    GovernanceError::InvalidBallot
+                );
+                require!(
+                    ranking.iter().enumerate().all(|(index, option)| !ranking[..index].contains(option)),
+                    GovernanceError::InvalidBallot
+                );
+                require!(
+                    ranking.iter().all(|option| (*option as usize) < option_count),
+                    GovernanceError::OptionIndexOutOfRange
+                );
+            }
+            Ballot::Quadratic { option_index, votes } => {
+                require!((*option_index as usize) < option_count, GovernanceError::OptionIndexOutOfRange);
+                require!(*votes > 0, GovernanceError::InvalidBallot);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ProposalAction {
+    pub fn validate(&self) -> Result<()> {
+        match *self {
+            ProposalAction::Transfer { amount, .. } => {
+                require!(amount > 0, GovernanceError::InvalidSpendAmount);
+            }
+            ProposalAction::Stream { amount, start_ts, end_ts, .. } => {
+                require!(amount > 0, GovernanceError::InvalidSpendAmount);
+                require!(end_ts > start_ts, GovernanceError::InvalidStreamSchedule);
+            }
+            ProposalAction::SlashReputation { points, .. } => {
+                require!(points > 0, GovernanceError::InvalidProposalAction);
+            }
+            ProposalAction::Clawback { amount, .. } => {
+                require!(amount > 0, GovernanceError::InvalidProposalAction);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl GovernanceProposal {
+    pub fn tally(&self) -> Vec<u64> {
+        self.vote_counts[..self.options.len()].to_vec()
+    }
+
+    /// Adds `weight` to every option the ballot counts for. Ranked ballots only add
+    /// to their first preference here; the runoff runs in `tally_ranked_votes`.
+    pub fn record_ballot(&mut self, ballot: &Ballot, weight: u64) -> Result<()> {
+        add_ballot(&mut self.vote_counts, self.options.len(), ballot, weight)
+    }
+
+    /// Tracks the proposer's own weight separately so it can't rescue their deposit.
+    pub fn record_proposer_ballot(&mut self, ballot: &Ballot, weight: u64) -> Result<()> {
+        add_ballot(&mut self.proposer_votes, self.options.len(), ballot, weight)
+    }
+
+    pub fn open_voting(&mut self, dao_config: &DaoConfig, now: i64) {
+        self.voting_ends_at = now + dao_config.governance.voting_period;
+        self.quorum = dao_config.governance.quorum;
+        self.reputation = dao_config.reputation.clone();
+        self.closed_at = 0;
+    }
+
+    /// The member's voting power as of the proposal's creation, under the reputation
+    /// config that was in force then.
+    pub fn voting_power_of(&self, member: &Member) -> u64 {
+        member.voting_power(&self.reputation, self.created_slot, self.created_at)
+    }
+
+    /// A proposal passes once it was closed after its full voting period, drew at
+    /// least its quorum, and option 0 strictly leads every other option.
+    pub fn is_approved(&self) -> bool {
+        if self.status != ProposalStatus::Closed || self.closed_at < self.voting_ends_at {
+            return false;
+        }
+
+        let results = self.tally();
+        if results.iter().sum::<u64>() < self.quorum {
+            return false;
+        }
+        match results.split_first() {
+            Some((approve, others)) => *approve > 0 && others.iter().all(|votes| approve > votes),
+            None => false,
+        }
+    }
+}
+
+// The full description lives off-chain at `description_uri`; `description_hash`
+// lets voters check the fetched document has not been swapped.
+#[account]
+#[derive(InitSpace)]
+pub struct GovernanceProposal {
+    #[max_len(MAX_TITLE_LEN)]
+    pub title: String,
+    #[max_len(MAX_DESCRIPTION_URI_LEN)]
+    pub description_uri: String,
+    pub description_hash: [u8; 32],
+    pub topic: u8,
+    pub voting_mode: VotingMode,
+    #[max_len(MAX_OPTIONS, MAX_OPTION_LEN)]
+    pub options: Vec<String>,
+    pub status: ProposalStatus,
+    pub vote_counts: [u64; MAX_OPTIONS],
+    // The proposer's own share of `vote_counts`, excluded when settling the deposit
+    pub proposer_votes: [u64; MAX_OPTIONS],
+    // Vote records created so far, delegated ones included
+    pub voters: u32,
+    pub action: Option<ProposalAction>,
+    pub executed: bool,
+    pub proposer: Pubkey,
+    pub deposit_amount: u64,
+    pub deposit_settled: bool,
+    // Voting power is snapshotted here: locks as of the slot, lock time left as of the timestamp
+    pub created_slot: u64,
+    pub created_at: i64,
+    // Copied from the DAO config at submission
+    pub voting_ends_at: i64,
+    pub quorum: u64,
+    pub reputation: ReputationConfig,
+    pub closed_at: i64,
+}
+
+fn add_ballot(counts: &mut [u64; MAX_OPTIONS], option_count: usize, ballot: &Ballot, weight: u64) -> Result<()> {
+    let mut add = |option_index: usize| -> Result<()> {
+        if let Some(count) = counts.get_mut(option_index) {
+            *count = count.checked_add(weight).ok_or(GovernanceError::VoteCountOverflow)?;
+        }
+        Ok(())
+    };
+
+    match ballot {
+        Ballot::Single { option_index } | Ballot::Quadratic { option_index, .. } => add(*option_index as usize),
+        Ballot::Approval { options_mask } => approved_options(*options_mask, option_count).try_for_each(add),
+        Ballot::Ranked { ranking } => match ranking.first() {
+            Some(first) => add(*first as usize),
+            None => Ok(()),
+        },
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct VoteRecord {
+    pub proposal: Pubkey,
+    pub member: Pubkey,
+    pub ballot: Ballot,
+    pub weight: u64,
+    // Member account that cast this vote; differs from `member` for delegated votes
+    pub voted_by: Pubkey,
+    // Last ranked-choice runoff round this record was counted in
+    pub tally_round: u8,
+}
+
+// Treasury `recipient` is the destination token account for the chosen asset;
+// `member` is the Member account to slash; `token_account` is the RWD account to
+// claw back from, either burning or moving the amount into the treasury RWD vault.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Debug, PartialEq)]
+pub enum ProposalAction {
+    Transfer {
+        asset: TreasuryAsset,
+        recipient: Pubkey,
+        amount: u64,
+    },
+    Stream {
+        asset: TreasuryAsset,
+        recipient: Pubkey,
+        amount: u64,
+        start_ts: i64,
+        end_ts: i64,
+    },
+    SlashReputation {
+        member: Pubkey,
+        points: u64,
+    },
+    Clawback {
+        token_account: Pubkey,
+        amount: u64,
+        burn: bool,
+    },
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq)]
+pub enum VotingMode {
+    Single,
+    Approval,
+    RankedChoice,
+    Quadratic,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Debug, PartialEq)]
+pub enum Ballot {
+    Single {
+        option_index: u8,
+    },
+    // Bit `i` approves option `i`; MAX_OPTIONS fits in a u8
+    Approval {
+        options_mask: u8,
+    },
+    // Most preferred option first
+    Ranked {
+        #[max_len(MAX_OPTIONS)]
+        ranking: Vec<u8>,
+    },
+    Quadratic {
+        option_index: u8,
+        votes: u64,
+    },
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, PartialEq)]
+pub enum ProposalStatus {
+    Open,
+    Closed,
+}
+
+#[derive(Accounts)]
+pub struct SubmitProposal<'info> {
+    #[account(
+        init,
+        payer = proposer,
+        space = DISCRIMINATOR + GovernanceProposal::INIT_SPACE,
+    )]
+    pub governance_proposal: Account<'info, GovernanceProposal>,
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [MEMBER_SEED, proposer.key().as_ref()],
+        bump = proposer_member.bump,
+    )]
+    pub proposer_member: Account<'info, Member>,
+    #[account(
+        seeds = [DAO_CONFIG_SEED],
+        bump = dao_config.bump,
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+    #[account(
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    #[account(
+        address = treasury.rwd_mint,
+        mint::token_program = token_program,
+    )]
+    pub rwd_mint: Box<InterfaceAccount<'info, Mint2022>>,
+    #[account(
+        mut,
+        token::mint = rwd_mint,
+        token::authority = proposer,
+        token::token_program = token_program,
+    )]
+    pub proposer_rwd: Box<InterfaceAccount<'info, TokenAccount2022>>,
+    // Escrow for the proposal deposit, released by `settle_deposit`
+    #[account(
+        init,
+        payer = proposer,
+        seeds = [DEPOSIT_SEED, governance_proposal.key().as_ref()],
+        bump,
+        token::mint = rwd_mint,
+        token::authority = treasury,
+        token::token_program = token_program,
+    )]
+    pub deposit_vault: Box<InterfaceAccount<'info, TokenAccount2022>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CastVote<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    #[account(mut)]
+    pub governance_proposal: Account<'info, GovernanceProposal>,
+    #[account(
+        mut,
+        seeds = [MEMBER_SEED, voter.key().as_ref()],
+        bump = member.bump,
+    )]
+    pub member: Account<'info, Member>,
+    #[account(
+        seeds = [DAO_CONFIG_SEED],
+        bump = dao_config.bump,
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+    // One record per member and proposal; a second vote fails on init
+    #[account(
+        init,
+        payer = voter,
+        seeds = [VOTE_SEED, governance_proposal.key().as_ref(), member.key().as_ref()],
+        bump,
+        space = DISCRIMINATOR + VoteRecord::INIT_SPACE,
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    pub system_program: Program<'info, System>,
+
+    // Payment accounts, only needed for quadratic ballots
+    #[account(
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump,
+    )]
+    pub treasury: Option<Account<'info, Treasury>>,
+    pub rwd_mint: Option<Box<InterfaceAccount<'info, Mint2022>>>,
+    #[account(mut)]
+    pub voter_rwd: Option<Box<InterfaceAccount<'info, TokenAccount2022>>>,
+    #[account(mut)]
+    pub treasury_rwd_vault: Option<Box<InterfaceAccount<'info, TokenAccount2022>>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+#[derive(Accounts)]
+pub struct CloseProposal<'info> {
+    #[account(mut)]
+    pub governance_proposal: Account<'info, GovernanceProposal>,
+
+    #[account(
+        seeds = [DAO_CONFIG_SEED],
+        bump = dao_config.bump,
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(mut)]
+    pub dao: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct GetProposalResults<'info> {
+    pub governance_proposal: Account<'info, GovernanceProposal>,
+
+    // Only needed for ranked-choice proposals
+    #[account(
+        seeds = [RANKED_TALLY_SEED, governance_proposal.key().as_ref()],
+        bump = ranked_tally.bump,
+    )]
+    pub ranked_tally: Option<Account<'info, RankedTally>>,
+}
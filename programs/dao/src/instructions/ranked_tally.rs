@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::*;
+use crate::events::*;
+use crate::utils::*;
+use crate::{Ballot, GovernanceProposal, ProposalStatus, VoteRecord, VotingMode};
+
+// Remaining accounts: a batch of the proposal's vote records, writable. A runoff
+// needs every record once per round, so the count is cranked over as many
+// transactions as it takes; records already counted this round are skipped.
+pub fn _tally_ranked_votes<'info>(
+    ctx: Context<'_, '_, '_, 'info, TallyRankedVotes<'info>>,
+) -> Result<()> {
+    let proposal = &ctx.accounts.governance_proposal;
+    require!(proposal.status == ProposalStatus::Closed, GovernanceError::ProposalStillOpen);
+    require!(proposal.voting_mode == VotingMode::RankedChoice, GovernanceError::BallotModeMismatch);
+
+    let tally = &mut ctx.accounts.ranked_tally;
+    if tally.round == 0 {
+        tally.proposal = proposal.key();
+        tally.round = 1;
+        tally.bump = ctx.bumps.ranked_tally;
+    }
+    require!(!tally.finished, GovernanceError::TallyFinished);
+
+    let option_count = proposal.options.len();
+    let eliminated = tally.eliminated_options(option_count);
+
+    for info in ctx.remaining_accounts {
+        require_keys_eq!(*info.owner, crate::ID, GovernanceError::InvalidVoteRecord);
+        let mut record = VoteRecord::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        require_keys_eq!(record.proposal, proposal.key(), GovernanceError::InvalidVoteRecord);
+        if record.tally_round >= tally.round {
+            continue;
+        }
+
+        if let Ballot::Ranked { ranking } = &record.ballot {
+            if let Some(option) = runoff_choice(ranking, &eliminated) {
+                tally.counts[option] = tally.counts[option]
+                    .checked_add(record.weight)
+                    .ok_or(GovernanceError::VoteCountOverflow)?;
+            }
+        }
+        tally.counted += 1;
+
+        record.tally_round = tally.round;
+        record.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+    }
+
+    if tally.counted < proposal.voters {
+        return Ok(());
+    }
+
+    let counts = tally.counts[..option_count].to_vec();
+    let eliminated_option = runoff_elimination(&counts, &eliminated);
+
+    emit!(RankedRoundTalliedEvent {
+        proposal: proposal.key(),
+        round: tally.round,
+        counts,
+        eliminated: eliminated_option.map(|option| option as u8),
+    });
+
+    match eliminated_option {
+        Some(option) => {
+            tally.eliminated |= 1 << option;
+            tally.round += 1;
+            tally.counts = [0; MAX_OPTIONS];
+            tally.counted = 0;
+        }
+        None => tally.finished = true,
+    }
+
+    Ok(())
+}
+
+impl RankedTally {
+    pub fn eliminated_options(&self, option_count: usize) -> Vec<bool> {
+        (0..option_count).map(|option| self.eliminated & (1 << option) != 0).collect()
+    }
+}
+
+#[derive(Accounts)]
+pub struct TallyRankedVotes<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub governance_proposal: Account<'info, GovernanceProposal>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [RANKED_TALLY_SEED, governance_proposal.key().as_ref()],
+        bump,
+        space = DISCRIMINATOR + RankedTally::INIT_SPACE,
+    )]
+    pub ranked_tally: Account<'info, RankedTally>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct RankedTally {
+    pub proposal: Pubkey,
+    // Runoff round being counted, starting at 1
+    pub round: u8,
+    // Bit `i` is set once option `i` has been eliminated
+    pub eliminated: u8,
+    pub counts: [u64; MAX_OPTIONS],
+    // Vote records counted in the current round
+    pub counted: u32,
+    // Set once a round ends without an elimination; `counts` then holds the results
+    pub finished: bool,
+    pub bump: u8,
+}
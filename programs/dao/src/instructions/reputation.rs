@@ -0,0 +1,231 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::*;
+use crate::events::*;
+use crate::{DaoConfig, GovernanceProposal, Member, ProposalAction, ReputationConfig};
+
+// Fixed-point scale used while compounding decay
+const DECAY_PRECISION: u128 = 1_000_000_000_000;
+
+pub fn _award_rewards_activity(ctx: Context<AdminReputation>, units: u64) -> Result<()> {
+    let config = &ctx.accounts.dao_config.reputation;
+    let points = units.saturating_mul(config.activity_points);
+
+    apply_reputation_change(&mut ctx.accounts.member, config, ReputationReason::RewardsActivity, points)
+}
+
+pub fn _slash_reputation(ctx: Context<AdminReputation>, points: u64) -> Result<()> {
+    let config = &ctx.accounts.dao_config.reputation;
+
+    apply_reputation_change(&mut ctx.accounts.member, config, ReputationReason::Slash, points)
+}
+
+pub fn _refresh_reputation(ctx: Context<RefreshReputation>) -> Result<()> {
+    let config = &ctx.accounts.dao_config.reputation;
+
+    apply_reputation_change(&mut ctx.accounts.member, config, ReputationReason::Decay, 0)
+}
+
+pub fn _execute_slash_proposal(ctx: Context<ExecuteSlashProposal>) -> Result<()> {
+    let proposal = &ctx.accounts.governance_proposal;
+    require!(!proposal.executed, GovernanceError::ProposalAlreadyExecuted);
+    require!(proposal.is_approved(), GovernanceError::ProposalNotApproved);
+
+    let points = match proposal.action {
+        Some(ProposalAction::SlashReputation { member, points }) => {
+            require_keys_eq!(ctx.accounts.member.key(), member, GovernanceError::InvalidProposalAction);
+            points
+        }
+        _ => return err!(GovernanceError::InvalidProposalAction),
+    };
+
+    let config = &ctx.accounts.dao_config.reputation;
+    apply_reputation_change(&mut ctx.accounts.member, config, ReputationReason::Slash, points)?;
+
+    ctx.accounts.governance_proposal.executed = true;
+    Ok(())
+}
+
+/// Decays the member's reputation up to now, then applies `points` for `reason`.
+/// Slashes subtract, every other reason adds (capped at `max_points`). Changes are
+/// checkpointed so proposals created later see them and earlier ones don't.
+pub fn apply_reputation_change(
+    member: &mut Account<Member>,
+    config: &ReputationConfig,
+    reason: ReputationReason,
+    points: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let decayed = member.apply_decay(config, clock.unix_timestamp);
+    if decayed > 0 {
+        emit!(ReputationChangedEvent {
+            member: member.key(),
+            authority: member.authority,
+            delta: -(decayed as i64),
+            reputation_points: member.reputation_points,
+            reason: ReputationReason::Decay,
+        });
+    }
+
+    let delta = match reason {
+        ReputationReason::Decay => 0,
+        ReputationReason::Slash => {
+            let slashed = points.min(member.reputation_points);
+            member.reputation_points -= slashed;
+            -(slashed as i64)
+        }
+        _ => {
+            let awarded = points.min(config.max_points.saturating_sub(member.reputation_points));
+            member.reputation_points += awarded;
+            awarded as i64
+        }
+    };
+
+    if decayed > 0 || delta != 0 {
+        member.record_power_checkpoint(clock.slot);
+    }
+
+    if delta != 0 {
+        emit!(ReputationChangedEvent {
+            member: member.key(),
+            authority: member.authority,
+            delta,
+            reputation_points: member.reputation_points,
+            reason,
+        });
+    }
+
+    Ok(())
+}
+
+impl Member {
+    /// Compounds `decay_bps` once per elapsed `decay_period` and returns the points lost.
+    pub fn apply_decay(&mut self, config: &ReputationConfig, now: i64) -> u64 {
+        let (remaining, last_decay_ts) = decay(self.reputation_points, self.last_decay_ts, config, now);
+        let decayed = self.reputation_points - remaining;
+        self.reputation_points = remaining;
+        self.last_decay_ts = last_decay_ts;
+        decayed
+    }
+
+    /// Reputation as of the snapshot: the points checkpointed before `snapshot_slot`,
+    /// decayed up to `snapshot_ts`.
+    pub fn reputation_at(&self, config: &ReputationConfig, snapshot_slot: u64, snapshot_ts: i64) -> u64 {
+        self.checkpoint_before(snapshot_slot).map_or(0, |checkpoint| {
+            decay(checkpoint.reputation_points, checkpoint.last_decay_ts, config, snapshot_ts).0
+        })
+    }
+
+    /// Vote-escrowed power at the snapshot, scaled by the reputation bonus at the same
+    /// snapshot. Reputation only multiplies locked RWD, so members without a lock
+    /// before the snapshot have none.
+    pub fn voting_power(&self, config: &ReputationConfig, snapshot_slot: u64, snapshot_ts: i64) -> u64 {
+        let bonus_bps = self
+            .reputation_at(config, snapshot_slot, snapshot_ts)
+            .saturating_mul(config.multiplier_bps_per_point as u64)
+            .min(config.max_multiplier_bonus_bps as u64);
+
+        let power = self.locked_power_at(snapshot_slot, snapshot_ts) as u128;
+        (power * (BPS_DENOMINATOR + bonus_bps) as u128 / BPS_DENOMINATOR as u128) as u64
+    }
+}
+
+// Compounds `decay_bps` once per `decay_period` elapsed since `last_decay_ts`; returns
+// the points left and the timestamp decay has been applied up to
+fn decay(points: u64, last_decay_ts: i64, config: &ReputationConfig, now: i64) -> (u64, i64) {
+    let elapsed = now.saturating_sub(last_decay_ts);
+    if elapsed < config.decay_period {
+        return (points, last_decay_ts);
+    }
+
+    let periods = (elapsed / config.decay_period) as u64;
+    let last_decay_ts = last_decay_ts + periods as i64 * config.decay_period;
+
+    if config.decay_bps == 0 || points == 0 {
+        return (points, last_decay_ts);
+    }
+
+    let factor = decay_factor(config.decay_bps, periods);
+    ((points as u128 * factor / DECAY_PRECISION) as u64, last_decay_ts)
+}
+
+// (1 - decay_bps / 10_000) ^ periods, by repeated squaring
+fn decay_factor(decay_bps: u16, periods: u64) -> u128 {
+    let mut base = (BPS_DENOMINATOR - decay_bps as u64) as u128 * DECAY_PRECISION / BPS_DENOMINATOR as u128;
+    let mut exponent = periods;
+    let mut result = DECAY_PRECISION;
+
+    while exponent > 0 && result > 0 {
+        if exponent & 1 == 1 {
+            result = result * base / DECAY_PRECISION;
+        }
+        base = base * base / DECAY_PRECISION;
+        exponent >>= 1;
+    }
+
+    result
+}
+
+#[derive(Accounts)]
+pub struct AdminReputation<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [DAO_CONFIG_SEED],
+        bump = dao_config.bump,
+        constraint = dao_config.admin == signer.key() @ GovernanceError::Unauthorized,
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(
+        mut,
+        seeds = [MEMBER_SEED, member.authority.as_ref()],
+        bump = member.bump,
+    )]
+    pub member: Account<'info, Member>,
+}
+
+#[derive(Accounts)]
+pub struct RefreshReputation<'info> {
+    #[account(
+        seeds = [DAO_CONFIG_SEED],
+        bump = dao_config.bump,
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(
+        mut,
+        seeds = [MEMBER_SEED, member.authority.as_ref()],
+        bump = member.bump,
+    )]
+    pub member: Account<'info, Member>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteSlashProposal<'info> {
+    #[account(mut)]
+    pub governance_proposal: Account<'info, GovernanceProposal>,
+
+    #[account(
+        seeds = [DAO_CONFIG_SEED],
+        bump = dao_config.bump,
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(
+        mut,
+        seeds = [MEMBER_SEED, member.authority.as_ref()],
+        bump = member.bump,
+    )]
+    pub member: Account<'info, Member>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum ReputationReason {
+    ProposalCreated,
+    VoteCast,
+    RewardsActivity,
+    Decay,
+    Slash,
+}
@@ -0,0 +1,451 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::*;
+use crate::events::*;
+use crate::utils::*;
+use crate::{PowerCheckpoint, Member};
+
+use anchor_spl::token_interface::{
+    close_account,
+    CloseAccount,
+    Mint as Mint2022,
+    TokenAccount as TokenAccount2022,
+    TokenInterface,
+};
+use rewards::TOKEN_2022_SEED;
+
+// Remaining accounts: transfer hook extra accounts for the deposit into the lock vault
+pub fn _create_lock<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateLock<'info>>,
+    amount: u64,
+    duration: i64,
+) -> Result<()> {
+    require!(ctx.accounts.member.locked_amount == 0, GovernanceError::LockAlreadyExists);
+    require!(amount > 0, GovernanceError::InvalidLockAmount);
+    require!(
+        (MIN_LOCK_DURATION..=MAX_LOCK_DURATION).contains(&duration),
+        GovernanceError::InvalidLockDuration
+    );
+
+    transfer_rwd_signed(
+        &ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.owner_rwd.to_account_info(),
+        ctx.accounts.rwd_mint.to_account_info(),
+        ctx.accounts.lock_vault.to_account_info(),
+        ctx.accounts.owner.to_account_info(),
+        ctx.remaining_accounts,
+        amount,
+        ctx.accounts.rwd_mint.decimals,
+        &[],
+    )?;
+
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+    let member = &mut ctx.accounts.member;
+    member.locked_amount = amount;
+    member.lock_end = now + duration;
+    member.record_power_checkpoint(clock.slot);
+
+    emit!(TokensLockedEvent {
+        member: member.key(),
+        added_amount: amount,
+        locked_amount: member.locked_amount,
+        lock_end: member.lock_end,
+    });
+
+    Ok(())
+}
+
+// Remaining accounts: transfer hook extra accounts for the deposit into the lock vault
+pub fn _increase_lock_amount<'info>(
+    ctx: Context<'_, '_, '_, 'info, IncreaseLockAmount<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+    require!(ctx.accounts.member.has_active_lock(now), GovernanceError::NoActiveLock);
+    require!(amount > 0, GovernanceError::InvalidLockAmount);
+
+    transfer_rwd_signed(
+        &ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.owner_rwd.to_account_info(),
+        ctx.accounts.rwd_mint.to_account_info(),
+        ctx.accounts.lock_vault.to_account_info(),
+        ctx.accounts.owner.to_account_info(),
+        ctx.remaining_accounts,
+        amount,
+        ctx.accounts.rwd_mint.decimals,
+        &[],
+    )?;
+
+    let member = &mut ctx.accounts.member;
+    member.locked_amount += amount;
+    member.record_power_checkpoint(clock.slot);
+
+    emit!(TokensLockedEvent {
+        member: member.key(),
+        added_amount: amount,
+        locked_amount: member.locked_amount,
+        lock_end: member.lock_end,
+    });
+
+    Ok(())
+}
+
+pub fn _extend_lock(ctx: Context<ExtendLock>, duration: i64) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+    let member = &mut ctx.accounts.member;
+    require!(member.has_active_lock(now), GovernanceError::NoActiveLock);
+
+    // The new end is measured from now and must push the current one out
+    let lock_end = now + duration;
+    require!(
+        duration <= MAX_LOCK_DURATION && lock_end > member.lock_end,
+        GovernanceError::InvalidLockDuration
+    );
+
+    member.lock_end = lock_end;
+    member.record_power_checkpoint(clock.slot);
+
+    emit!(TokensLockedEvent {
+        member: member.key(),
+        added_amount: 0,
+        locked_amount: member.locked_amount,
+        lock_end,
+    });
+
+    Ok(())
+}
+
+// Remaining accounts: transfer hook extra accounts for the withdrawal from the lock vault
+pub fn _unlock_tokens<'info>(ctx: Context<'_, '_, '_, 'info, UnlockTokens<'info>>) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+    let member = &ctx.accounts.member;
+    require!(member.locked_amount > 0, GovernanceError::NoActiveLock);
+    require!(now >= member.lock_end, GovernanceError::LockNotExpired);
+
+    let owner_key = ctx.accounts.owner.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[MEMBER_SEED, owner_key.as_ref(), &[member.bump]]];
+    let amount = ctx.accounts.lock_vault.amount;
+
+    transfer_rwd_signed(
+        &ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.lock_vault.to_account_info(),
+        ctx.accounts.rwd_mint.to_account_info(),
+        ctx.accounts.owner_rwd.to_account_info(),
+        member.to_account_info(),
+        ctx.remaining_accounts,
+        amount,
+        ctx.accounts.rwd_mint.decimals,
+        signer_seeds,
+    )?;
+
+    close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.lock_vault.to_account_info(),
+            destination: ctx.accounts.owner.to_account_info(),
+            authority: member.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    let member = &mut ctx.accounts.member;
+    member.locked_amount = 0;
+    member.lock_end = 0;
+    member.record_power_checkpoint(clock.slot);
+
+    emit!(TokensUnlockedEvent {
+        member: member.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+impl Member {
+    pub fn has_active_lock(&self, now: i64) -> bool {
+        self.locked_amount > 0 && self.lock_end > now
+    }
+
+    /// Last checkpoint recorded before `snapshot_slot`. Changes in or after the snapshot
+    /// slot do not count, so neither tokens nor reputation can be gained to sway a vote
+    /// already underway.
+    pub fn checkpoint_before(&self, snapshot_slot: u64) -> Option<&PowerCheckpoint> {
+        self.power_checkpoints.iter().rev().find(|checkpoint| checkpoint.slot < snapshot_slot)
+    }
+
+    /// ve-style power: the lock as it stood before `snapshot_slot`, scaled by the lock
+    /// time left at `snapshot_ts` relative to `MAX_LOCK_DURATION`. Slots before the
+    /// first checkpoint have no power.
+    pub fn locked_power_at(&self, snapshot_slot: u64, snapshot_ts: i64) -> u64 {
+        let Some(checkpoint) = self.checkpoint_before(snapshot_slot) else {
+            return 0;
+        };
+        if checkpoint.lock_end <= snapshot_ts {
+            return 0;
+        }
+
+        let remaining = (checkpoint.lock_end - snapshot_ts) as u128;
+        (checkpoint.locked_amount as u128 * remaining / MAX_LOCK_DURATION as u128) as u64
+    }
+
+    /// Appends the current lock and reputation to the history, replacing an entry from
+    /// the same slot. Once the history is full the two oldest entries are merged, keeping
+    /// the older slot and the lower of every other field, so a snapshot in that range
+    /// may understate the power but never overstates it or loses it entirely.
+    pub fn record_power_checkpoint(&mut self, slot: u64) {
+        let checkpoint = PowerCheckpoint {
+            slot,
+            locked_amount: self.locked_amount,
+            lock_end: self.lock_end,
+            reputation_points: self.reputation_points,
+            last_decay_ts: self.last_decay_ts,
+        };
+
+        match self.power_checkpoints.last_mut() {
+            Some(last) if last.slot == slot => *last = checkpoint,
+            _ => {
+                if self.power_checkpoints.len() == MAX_POWER_CHECKPOINTS {
+                    let newer = self.power_checkpoints.remove(1);
+                    let oldest = &mut self.power_checkpoints[0];
+                    oldest.locked_amount = oldest.locked_amount.min(newer.locked_amount);
+                    oldest.lock_end = oldest.lock_end.min(newer.lock_end);
+                    // Decaying from the earlier timestamp can only lose more points
+                    oldest.reputation_points = oldest.reputation_points.min(newer.reputation_points);
+                    oldest.last_decay_ts = oldest.last_decay_ts.min(newer.last_decay_ts);
+                }
+                self.power_checkpoints.push(checkpoint);
+            }
+        }
+    }
+}
+
+#[derive(Accounts)]
+pub struct CreateLock<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [MEMBER_SEED, owner.key().as_ref()],
+        bump = member.bump,
+    )]
+    pub member: Account<'info, Member>,
+
+    #[account(
+        seeds = [TOKEN_2022_SEED],
+        bump,
+        seeds::program = rewards::ID,
+        mint::token_program = token_program,
+    )]
+    pub rwd_mint: Box<InterfaceAccount<'info, Mint2022>>,
+
+    #[account(
+        mut,
+        token::mint = rwd_mint,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub owner_rwd: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    // Owned by the member PDA; the transfer hook blocks it from sending until `lock_end`
+    #[account(
+        init,
+        payer = owner,
+        seeds = [LOCK_VAULT_SEED, member.key().as_ref()],
+        bump,
+        token::mint = rwd_mint,
+        token::authority = member,
+        token::token_program = token_program,
+    )]
+    pub lock_vault: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct IncreaseLockAmount<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [MEMBER_SEED, owner.key().as_ref()],
+        bump = member.bump,
+    )]
+    pub member: Account<'info, Member>,
+
+    #[account(
+        seeds = [TOKEN_2022_SEED],
+        bump,
+        seeds::program = rewards::ID,
+        mint::token_program = token_program,
+    )]
+    pub rwd_mint: Box<InterfaceAccount<'info, Mint2022>>,
+
+    #[account(
+        mut,
+        token::mint = rwd_mint,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub owner_rwd: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    #[account(
+        mut,
+        seeds = [LOCK_VAULT_SEED, member.key().as_ref()],
+        bump,
+        token::mint = rwd_mint,
+        token::authority = member,
+        token::token_program = token_program,
+    )]
+    pub lock_vault: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct ExtendLock<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [MEMBER_SEED, owner.key().as_ref()],
+        bump = member.bump,
+    )]
+    pub member: Account<'info, Member>,
+}
+
+#[derive(Accounts)]
+pub struct UnlockTokens<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [MEMBER_SEED, owner.key().as_ref()],
+        bump = member.bump,
+    )]
+    pub member: Account<'info, Member>,
+
+    #[account(
+        seeds = [TOKEN_2022_SEED],
+        bump,
+        seeds::program = rewards::ID,
+        mint::token_program = token_program,
+    )]
+    pub rwd_mint: Box<InterfaceAccount<'info, Mint2022>>,
+
+    #[account(
+        mut,
+        token::mint = rwd_mint,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub owner_rwd: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    #[account(
+        mut,
+        seeds = [LOCK_VAULT_SEED, member.key().as_ref()],
+        bump,
+        token::mint = rwd_mint,
+        token::authority = member,
+        token::token_program = token_program,
+    )]
+    pub lock_vault: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ReputationConfig;
+
+    fn member_with_checkpoints(checkpoints: &[(u64, u64, i64)]) -> Member {
+        Member {
+            authority: Pubkey::default(),
+            reputation_points: 0,
+            last_decay_ts: 0,
+            bump: 0,
+            locked_amount: 0,
+            lock_end: 0,
+            power_checkpoints: checkpoints
+                .iter()
+                .map(|&(slot, locked_amount, lock_end)| PowerCheckpoint {
+                    slot,
+                    locked_amount,
+                    lock_end,
+                    reputation_points: 0,
+                    last_decay_ts: 0,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn locked_power_uses_lock_before_snapshot_slot() {
+        let member = member_with_checkpoints(&[(10, 1_000, MAX_LOCK_DURATION), (20, 5_000, MAX_LOCK_DURATION)]);
+
+        assert_eq!(member.locked_power_at(10, 0), 0);
+        assert_eq!(member.locked_power_at(11, 0), 1_000);
+        assert_eq!(member.locked_power_at(20, 0), 1_000);
+        assert_eq!(member.locked_power_at(21, MAX_LOCK_DURATION / 2), 2_500);
+        assert_eq!(member.locked_power_at(21, MAX_LOCK_DURATION), 0);
+    }
+
+    #[test]
+    fn voting_power_uses_reputation_before_snapshot_slot() {
+        let config = ReputationConfig {
+            proposal_points: 0,
+            vote_points: 0,
+            activity_points: 0,
+            max_points: 1_000,
+            decay_bps: 5_000,
+            decay_period: 100,
+            multiplier_bps_per_point: 100,
+            max_multiplier_bonus_bps: 10_000,
+        };
+        let mut member = member_with_checkpoints(&[]);
+        member.locked_amount = 1_000;
+        member.lock_end = MAX_LOCK_DURATION;
+        member.record_power_checkpoint(10);
+        member.reputation_points = 20;
+        member.record_power_checkpoint(20);
+
+        // Reputation gained at or after the snapshot slot does not raise the weight
+        assert_eq!(member.voting_power(&config, 20, 0), 1_000);
+        assert_eq!(member.voting_power(&config, 21, 0), 1_200);
+        // Checkpointed reputation decays up to the snapshot time, not the vote time
+        assert_eq!(member.reputation_at(&config, 21, 100), 10);
+        assert_eq!(member.voting_power(&config, 21, 100), 999 * 11_000 / 10_000);
+    }
+
+    #[test]
+    fn checkpoints_replace_same_slot_and_merge_oldest() {
+        let mut member = member_with_checkpoints(&[]);
+        member.locked_amount = 1;
+        member.record_power_checkpoint(1);
+        member.locked_amount = 2;
+        member.record_power_checkpoint(1);
+        assert_eq!(member.power_checkpoints.len(), 1);
+        assert_eq!(member.power_checkpoints[0].locked_amount, 2);
+
+        member.lock_end = MAX_LOCK_DURATION;
+        for slot in 2..=MAX_POWER_CHECKPOINTS as u64 + 1 {
+            member.locked_amount = slot * 10;
+            member.record_power_checkpoint(slot);
+        }
+        assert_eq!(member.power_checkpoints.len(), MAX_POWER_CHECKPOINTS);
+
+        // The oldest entry is kept, merged with the next one at the lower of both locks
+        let oldest = &member.power_checkpoints[0];
+        assert_eq!((oldest.slot, oldest.locked_amount, oldest.lock_end), (1, 2, 0));
+        assert_eq!(member.power_checkpoints[1].slot, 3);
+        assert_eq!(member.locked_power_at(2, 0), 0);
+        assert_eq!(member.locked_power_at(4, 0), 30);
+    }
+}
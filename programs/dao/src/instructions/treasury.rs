@@ -0,0 +1,492 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::*;
+use crate::events::*;
+use crate::utils::*;
+use crate::{GovernanceProposal, ProposalAction};
+
+use anchor_spl::{
+    token::{Mint, Token, TokenAccount},
+    token_interface::{
+        Mint as Mint2022,
+        TokenAccount as TokenAccount2022,
+        TokenInterface,
+    },
+};
+use rewards::{
+    cpi::accounts::Clawback,
+    program::Rewards,
+    COMPLIANCE_SEED,
+    PERMANENT_DELEGATE_SEED,
+    POINTS_EXPIRY_SEED,
+    TOKEN_2022_SEED,
+    USDC_MINT_ADDRESS,
+};
+
+pub fn _initialize_treasury(ctx: Context<InitializeTreasury>) -> Result<()> {
+    let treasury = &mut ctx.accounts.treasury;
+
+    treasury.rwd_mint = ctx.accounts.rwd_mint.key();
+    treasury.usdc_mint = ctx.accounts.usdc_mint.key();
+    treasury.rwd_vault = ctx.accounts.rwd_vault.key();
+    treasury.usdc_vault = ctx.accounts.usdc_vault.key();
+    treasury.bump = ctx.bumps.treasury;
+
+    emit!(TreasuryInitializedEvent {
+        treasury: treasury.key(),
+        rwd_vault: treasury.rwd_vault,
+        usdc_vault: treasury.usdc_vault,
+    });
+
+    Ok(())
+}
+
+pub fn _execute_proposal<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteProposal<'info>>,
+) -> Result<()> {
+    let proposal = &ctx.accounts.governance_proposal;
+    require!(!proposal.executed, GovernanceError::ProposalAlreadyExecuted);
+    require!(proposal.is_approved(), GovernanceError::ProposalNotApproved);
+
+    let (asset, recipient, amount) = match proposal.action {
+        Some(ProposalAction::Transfer { asset, recipient, amount }) => (asset, recipient, amount),
+        _ => return err!(GovernanceError::InvalidTreasuryAction),
+    };
+    require_keys_eq!(ctx.accounts.recipient.key(), recipient, GovernanceError::InvalidRecipient);
+
+    let treasury = &ctx.accounts.treasury;
+    treasury.check_asset_accounts(asset, &ctx.accounts.mint.key(), &ctx.accounts.vault.key())?;
+
+    _pay_from_treasury(
+        treasury,
+        &ctx.accounts.token_program,
+        ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.mint,
+        ctx.accounts.recipient.to_account_info(),
+        ctx.remaining_accounts,
+        amount,
+    )?;
+
+    ctx.accounts.governance_proposal.executed = true;
+
+    emit!(TreasurySpendEvent {
+        proposal: ctx.accounts.governance_proposal.key(),
+        asset,
+        recipient,
+        amount,
+    });
+
+    Ok(())
+}
+
+pub fn _execute_stream_proposal(ctx: Context<ExecuteStreamProposal>) -> Result<()> {
+    let proposal = &ctx.accounts.governance_proposal;
+    require!(!proposal.executed, GovernanceError::ProposalAlreadyExecuted);
+    require!(proposal.is_approved(), GovernanceError::ProposalNotApproved);
+
+    let (asset, recipient, amount, start_ts, end_ts) = match proposal.action {
+        Some(ProposalAction::Stream { asset, recipient, amount, start_ts, end_ts }) => {
+            (asset, recipient, amount, start_ts, end_ts)
+        }
+        _ => return err!(GovernanceError::InvalidTreasuryAction),
+    };
+
+    let stream = &mut ctx.accounts.payment_stream;
+    stream.proposal = proposal.key();
+    stream.asset = asset;
+    stream.recipient = recipient;
+    stream.total_amount = amount;
+    stream.withdrawn = 0;
+    stream.start_ts = start_ts;
+    stream.end_ts = end_ts;
+    stream.bump = ctx.bumps.payment_stream;
+
+    ctx.accounts.governance_proposal.executed = true;
+
+    emit!(PaymentStreamCreatedEvent {
+        proposal: stream.proposal,
+        stream: stream.key(),
+        asset,
+        recipient,
+        amount,
+        start_ts,
+        end_ts,
+    });
+
+    Ok(())
+}
+
+pub fn _withdraw_stream<'info>(
+    ctx: Context<'_, '_, '_, 'info, WithdrawStream<'info>>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let stream = &ctx.accounts.payment_stream;
+
+    let amount = stream.vested_amount(now) - stream.withdrawn;
+    require!(amount > 0, GovernanceError::NothingToWithdraw);
+
+    let treasury = &ctx.accounts.treasury;
+    treasury.check_asset_accounts(stream.asset, &ctx.accounts.mint.key(), &ctx.accounts.vault.key())?;
+
+    _pay_from_treasury(
+        treasury,
+        &ctx.accounts.token_program,
+        ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.mint,
+        ctx.accounts.recipient.to_account_info(),
+        ctx.remaining_accounts,
+        amount,
+    )?;
+
+    let stream = &mut ctx.accounts.payment_stream;
+    stream.withdrawn += amount;
+
+    emit!(StreamWithdrawnEvent {
+        stream: stream.key(),
+        recipient: stream.recipient,
+        amount,
+        total_withdrawn: stream.withdrawn,
+    });
+
+    Ok(())
+}
+
+// The treasury PDA signs the clawback, so the rewards compliance config must name it
+// as the clawback authority. Clawed back RWD that isn't burned goes to the RWD vault.
+pub fn _execute_clawback_proposal<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteClawbackProposal<'info>>,
+) -> Result<()> {
+    let proposal = &ctx.accounts.governance_proposal;
+    require!(!proposal.executed, GovernanceError::ProposalAlreadyExecuted);
+    require!(proposal.is_approved(), GovernanceError::ProposalNotApproved);
+
+    let (token_account, amount, burn) = match proposal.action {
+        Some(ProposalAction::Clawback { token_account, amount, burn }) => (token_account, amount, burn),
+        _ => return err!(GovernanceError::InvalidProposalAction),
+    };
+    require_keys_eq!(ctx.accounts.source.key(), token_account, GovernanceError::InvalidProposalAction);
+
+    let reason = format!("Proposal {}: {}", proposal.key(), proposal.title);
+    let destination = if burn { None } else { Some(ctx.accounts.rwd_vault.to_account_info()) };
+
+    let signer_seeds: &[&[&[u8]]] = &[&[TREASURY_SEED, &[ctx.accounts.treasury.bump]]];
+    let cpi_accounts = Clawback {
+        authority: ctx.accounts.treasury.to_account_info(),
+        compliance: ctx.accounts.compliance.to_account_info(),
+        permanent_delegate: ctx.accounts.permanent_delegate.to_account_info(),
+        mint: ctx.accounts.rwd_mint.to_account_info(),
+        source: ctx.accounts.source.to_account_info(),
+        destination,
+        points_expiry: ctx.accounts.points_expiry.to_account_info(),
+        lot_ledger: ctx.accounts.lot_ledger.to_account_info(),
+        transfer_hook_program: ctx.accounts.transfer_hook_program.to_account_info(),
+        token_program2022: ctx.accounts.token_program2022.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.rewards_program.to_account_info();
+    rewards::cpi::clawback(
+        CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds)
+            .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        amount,
+        burn,
+        reason,
+    )?;
+
+    ctx.accounts.governance_proposal.executed = true;
+    Ok(())
+}
+
+pub fn _pay_from_treasury<'info>(
+    treasury: &Account<'info, Treasury>,
+    token_program: &Interface<'info, TokenInterface>,
+    vault: AccountInfo<'info>,
+    mint: &InterfaceAccount<'info, Mint2022>,
+    recipient: AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    amount: u64,
+) -> Result<()> {
+    let signer_seeds: &[&[&[u8]]] = &[&[TREASURY_SEED, &[treasury.bump]]];
+
+    transfer_rwd_signed(
+        &token_program.to_account_info(),
+        vault,
+        mint.to_account_info(),
+        recipient,
+        treasury.to_account_info(),
+        remaining_accounts,
+        amount,
+        mint.decimals,
+        signer_seeds,
+    )
+}
+
+impl Treasury {
+    pub fn check_asset_accounts(&self, asset: TreasuryAsset, mint: &Pubkey, vault: &Pubkey) -> Result<()> {
+        let (expected_mint, expected_vault) = match asset {
+            TreasuryAsset::Rwd => (self.rwd_mint, self.rwd_vault),
+            TreasuryAsset::Usdc => (self.usdc_mint, self.usdc_vault),
+        };
+        require_keys_eq!(*mint, expected_mint, GovernanceError::InvalidTreasuryAction);
+        require_keys_eq!(*vault, expected_vault, GovernanceError::InvalidTreasuryAction);
+        Ok(())
+    }
+}
+
+impl PaymentStream {
+    pub fn vested_amount(&self, now: i64) -> u64 {
+        if now <= self.start_ts {
+            return 0;
+        }
+        if now >= self.end_ts {
+            return self.total_amount;
+        }
+
+        let elapsed = (now - self.start_ts) as u128;
+        let duration = (self.end_ts - self.start_ts) as u128;
+        (self.total_amount as u128 * elapsed / duration) as u64
+    }
+}
+
+#[derive(Accounts)]
+pub struct InitializeTreasury<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [TREASURY_SEED],
+        bump,
+        space = DISCRIMINATOR + Treasury::INIT_SPACE,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        seeds = [TOKEN_2022_SEED],
+        bump,
+        seeds::program = rewards::ID,
+        mint::token_program = token_program2022,
+    )]
+    pub rwd_mint: Box<InterfaceAccount<'info, Mint2022>>,
+
+    #[account(
+        address = USDC_MINT_ADDRESS,
+        mint::token_program = token_program,
+    )]
+    pub usdc_mint: Account<'info, Mint>,
+
+    // Set this account as `Fees.fee_collector` in the rewards program to route protocol fees here
+    #[account(
+        init,
+        payer = signer,
+        seeds = [TREASURY_RWD_SEED],
+        bump,
+        token::mint = rwd_mint,
+        token::authority = treasury,
+        token::token_program = token_program2022,
+    )]
+    pub rwd_vault: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [TREASURY_USDC_SEED],
+        bump,
+        token::mint = usdc_mint,
+        token::authority = treasury,
+        token::token_program = token_program,
+    )]
+    pub usdc_vault: Account<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub token_program2022: Interface<'info, TokenInterface>,
+}
+
+// Remaining accounts: transfer hook extra accounts when paying out RWD
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(mut)]
+    pub governance_proposal: Account<'info, GovernanceProposal>,
+
+    #[account(
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint2022>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = treasury,
+        token::token_program = token_program,
+    )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub recipient: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteStreamProposal<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(mut)]
+    pub governance_proposal: Account<'info, GovernanceProposal>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [STREAM_SEED, governance_proposal.key().as_ref()],
+        bump,
+        space = DISCRIMINATOR + PaymentStream::INIT_SPACE,
+    )]
+    pub payment_stream: Account<'info, PaymentStream>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Remaining accounts: transfer hook extra accounts when streaming RWD
+#[derive(Accounts)]
+pub struct WithdrawStream<'info> {
+    #[account(
+        mut,
+        seeds = [STREAM_SEED, payment_stream.proposal.as_ref()],
+        bump = payment_stream.bump,
+    )]
+    pub payment_stream: Account<'info, PaymentStream>,
+
+    #[account(
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint2022>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = treasury,
+        token::token_program = token_program,
+    )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    #[account(
+        mut,
+        address = payment_stream.recipient,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub recipient: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+// Remaining accounts: transfer hook extra accounts when the clawback moves RWD
+#[derive(Accounts)]
+pub struct ExecuteClawbackProposal<'info> {
+    #[account(mut)]
+    pub governance_proposal: Account<'info, GovernanceProposal>,
+
+    #[account(
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    /// CHECK: rewards compliance config, validated by the rewards program
+    #[account(
+        seeds = [COMPLIANCE_SEED],
+        bump,
+        seeds::program = rewards::ID,
+    )]
+    pub compliance: UncheckedAccount<'info>,
+
+    /// CHECK: rewards PDA holding the mint's permanent delegate role
+    #[account(
+        seeds = [PERMANENT_DELEGATE_SEED],
+        bump,
+        seeds::program = rewards::ID,
+    )]
+    pub permanent_delegate: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [TOKEN_2022_SEED],
+        bump,
+        seeds::program = rewards::ID,
+        mint::token_program = token_program2022,
+    )]
+    pub rwd_mint: Box<InterfaceAccount<'info, Mint2022>>,
+
+    #[account(
+        mut,
+        token::mint = rwd_mint,
+        token::token_program = token_program2022,
+    )]
+    pub source: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    #[account(
+        mut,
+        address = treasury.rwd_vault,
+    )]
+    pub rwd_vault: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    /// CHECK: rewards PDA signing for the source owner's lot ledger
+    #[account(
+        seeds = [POINTS_EXPIRY_SEED],
+        bump,
+        seeds::program = rewards::ID,
+    )]
+    pub points_expiry: UncheckedAccount<'info>,
+
+    /// CHECK: source owner's lot ledger, validated by the rewards program
+    #[account(mut)]
+    pub lot_ledger: UncheckedAccount<'info>,
+
+    /// CHECK: transfer hook program, validated by the rewards program
+    pub transfer_hook_program: UncheckedAccount<'info>,
+
+    pub rewards_program: Program<'info, Rewards>,
+    pub token_program2022: Interface<'info, TokenInterface>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Treasury {
+    pub rwd_mint: Pubkey,
+    pub usdc_mint: Pubkey,
+    pub rwd_vault: Pubkey,
+    pub usdc_vault: Pubkey,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PaymentStream {
+    pub proposal: Pubkey,
+    pub asset: TreasuryAsset,
+    pub recipient: Pubkey,
+    pub total_amount: u64,
+    pub withdrawn: u64,
+    pub start_ts: i64,
+    pub end_ts: i64,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq)]
+pub enum TreasuryAsset {
+    Rwd,
+    Usdc,
+}
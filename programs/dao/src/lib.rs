@@ -1,145 +1,168 @@
 use anchor_lang::prelude::*;
+
+pub mod constants;
+pub mod error;
+pub mod instructions;
+pub mod state;
+pub mod utils;
+
+pub use constants::*;
+pub use error::*;
+pub use instructions::*;
+pub use state::*;
+pub use utils::*;
+
 declare_id!("44dqWPQqXs2TJ1DLDUBvpmLQz3NDBVgFsT5nZ5KkPoT5");
 
 #[program]
 mod dao {
     use super::*;
 
+    pub fn initialize_dao_config(
+        ctx: Context<InitializeDaoConfig>,
+        reputation: ReputationConfig,
+        deposit: DepositConfig,
+        governance: GovernanceConfig,
+    ) -> Result<()> {
+        _initialize_dao_config(ctx, reputation, deposit, governance)
+    }
+
+    pub fn update_reputation_config(ctx: Context<UpdateDaoConfig>, reputation: ReputationConfig) -> Result<()> {
+        _update_reputation_config(ctx, reputation)
+    }
+
+    pub fn update_deposit_config(ctx: Context<UpdateDaoConfig>, deposit: DepositConfig) -> Result<()> {
+        _update_deposit_config(ctx, deposit)
+    }
+
+    pub fn update_governance_config(ctx: Context<UpdateDaoConfig>, governance: GovernanceConfig) -> Result<()> {
+        _update_governance_config(ctx, governance)
+    }
+
     pub fn initialize_member(ctx: Context<InitializeMember>) -> Result<()> {
-        let member = &mut ctx.accounts.member;
-        member.reputation_points = 0;
-        Ok(())
+        _initialize_member(ctx)
     }
 
-    pub fn submit_proposal(
-        ctx: Context<SubmitProposal>,
+    pub fn submit_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, SubmitProposal<'info>>,
         title: String,
-        description: String,
+        description_uri: String,
+        description_hash: [u8; 32],
+        topic: u8,
+        voting_mode: VotingMode,
         options: Vec<String>,
     ) -> Result<()> {
-        let proposal = &mut ctx.accounts.governance_proposal;
-        proposal.title = title;
-        proposal.description = description;
-        proposal.options = options;
-        proposal.status = ProposalStatus::Open;
-        proposal.votes = vec![];
-        Ok(())
+        _submit_proposal(ctx, title, description_uri, description_hash, topic, voting_mode, options)
     }
 
-    pub fn cast_vote(ctx: Context<CastVote>, option_index: u8) -> Result<()> {
-        let proposal = &mut ctx.accounts.governance_proposal;
-        let member = &mut ctx.accounts.member;
+    pub fn submit_action_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, SubmitProposal<'info>>,
+        title: String,
+        description_uri: String,
+        description_hash: [u8; 32],
+        topic: u8,
+        action: ProposalAction,
+    ) -> Result<()> {
+        _submit_action_proposal(ctx, title, description_uri, description_hash, topic, action)
+    }
 
-        let vote = Vote {
-            voter: *member.to_account_info().key,
-            option_index,
-        };
-        proposal.votes.push(vote);
+    pub fn cast_vote<'info>(
+        ctx: Context<'_, '_, '_, 'info, CastVote<'info>>,
+        ballot: Ballot,
+    ) -> Result<()> {
+        _cast_vote(ctx, ballot)
+    }
 
-        member.reputation_points += 1;
+    pub fn delegate_votes(ctx: Context<DelegateVotes>, topic: u8) -> Result<()> {
+        _delegate_votes(ctx, topic)
+    }
 
-        Ok(())
+    pub fn revoke_delegation(ctx: Context<RevokeDelegation>, topic: u8) -> Result<()> {
+        _revoke_delegation(ctx, topic)
     }
 
     pub fn close_proposal(ctx: Context<CloseProposal>) -> Result<()> {
-        let proposal = &mut ctx.accounts.governance_proposal;
-        
-        require!(ctx.accounts.dao.is_signer, GovernanceError::UnauthorizedClose);
-
-        proposal.status = ProposalStatus::Closed;
-        Ok(())
+        _close_proposal(ctx)
     }
 
     pub fn get_proposal_results(ctx: Context<GetProposalResults>) -> Result<Vec<u64>> {
-        let proposal = &ctx.accounts.governance_proposal;
+        _get_proposal_results(ctx)
+    }
 
-        require!(
-            proposal.status == ProposalStatus::Closed,
-            GovernanceError::ProposalStillOpen
-        );
+    pub fn tally_ranked_votes<'info>(
+        ctx: Context<'_, '_, '_, 'info, TallyRankedVotes<'info>>,
+    ) -> Result<()> {
+        _tally_ranked_votes(ctx)
+    }
+
+    pub fn settle_deposit<'info>(
+        ctx: Context<'_, '_, '_, 'info, SettleDeposit<'info>>,
+    ) -> Result<()> {
+        _settle_deposit(ctx)
+    }
 
-        let mut results = vec![0; proposal.options.len()];
+    pub fn initialize_treasury(ctx: Context<InitializeTreasury>) -> Result<()> {
+        _initialize_treasury(ctx)
+    }
 
-        for vote in &proposal.votes {
-            if (vote.option_index as usize) < results.len() {
-                results[vote.option_index as usize] += 1;
-            }
-        }
+    pub fn execute_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteProposal<'info>>,
+    ) -> Result<()> {
+        _execute_proposal(ctx)
+    }
 
-        Ok(results)
+    pub fn execute_stream_proposal(ctx: Context<ExecuteStreamProposal>) -> Result<()> {
+        _execute_stream_proposal(ctx)
     }
-}
 
-#[account]
-pub struct GovernanceProposal {
-    pub title: String,
-    pub description: String,
-    pub options: Vec<String>,
-    pub status: ProposalStatus,
-    pub votes: Vec<Vote>,
-}
+    pub fn withdraw_stream<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawStream<'info>>,
+    ) -> Result<()> {
+        _withdraw_stream(ctx)
+    }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
-pub struct Vote {
-    pub voter: Pubkey,
-    pub option_index: u8,
-}
+    pub fn award_rewards_activity(ctx: Context<AdminReputation>, units: u64) -> Result<()> {
+        _award_rewards_activity(ctx, units)
+    }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
-pub enum ProposalStatus {
-    Open,
-    Closed,
-}
+    pub fn slash_reputation(ctx: Context<AdminReputation>, points: u64) -> Result<()> {
+        _slash_reputation(ctx, points)
+    }
 
-#[account]
-pub struct Member {
-    pub reputation_points: u64,
-}
+    pub fn refresh_reputation(ctx: Context<RefreshReputation>) -> Result<()> {
+        _refresh_reputation(ctx)
+    }
 
-#[derive(Accounts)]
-pub struct InitializeMember<'info> {
-    #[account(init, payer = user, space = 8 + 8)]
-    pub member: Account<'info, Member>,
-    #[account(mut)]
-    pub user: Signer<'info>,
-    pub system_program: Program<'info, System>,
-}
+    pub fn execute_slash_proposal(ctx: Context<ExecuteSlashProposal>) -> Result<()> {
+        _execute_slash_proposal(ctx)
+    }
 
-#[derive(Accounts)]
-pub struct SubmitProposal<'info> {
-    #[account(init, payer = proposer, space = 8 + 1024)]
-    pub governance_proposal: Account<'info, GovernanceProposal>,
-    #[account(mut)]
-    pub proposer: Signer<'info>,
-    pub system_program: Program<'info, System>,
-}
+    pub fn create_lock<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateLock<'info>>,
+        amount: u64,
+        duration: i64,
+    ) -> Result<()> {
+        _create_lock(ctx, amount, duration)
+    }
 
-#[derive(Accounts)]
-pub struct CastVote<'info> {
-    #[account(mut)]
-    pub governance_proposal: Account<'info, GovernanceProposal>,
-    #[account(mut)]
-    pub member: Account<'info, Member>,
-}
+    pub fn increase_lock_amount<'info>(
+        ctx: Context<'_, '_, '_, 'info, IncreaseLockAmount<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        _increase_lock_amount(ctx, amount)
+    }
 
-#[derive(Accounts)]
-pub struct CloseProposal<'info> {
-    #[account(mut)]
-    pub governance_proposal: Account<'info, GovernanceProposal>,
-    
-    #[account(mut)]
-    pub dao: Signer<'info>, 
-}
+    pub fn extend_lock(ctx: Context<ExtendLock>, duration: i64) -> Result<()> {
+        _extend_lock(ctx, duration)
+    }
 
-#[derive(Accounts)]
-pub struct GetProposalResults<'info> {
-    pub governance_proposal: Account<'info, GovernanceProposal>,
-}
+    pub fn unlock_tokens<'info>(ctx: Context<'_, '_, '_, 'info, UnlockTokens<'info>>) -> Result<()> {
+        _unlock_tokens(ctx)
+    }
 
-#[error_code]
-pub enum GovernanceError {
-    #[msg("Cannot get results for an open proposal")]
-    ProposalStillOpen,
-    #[msg("Only the DAO admin can close the proposal")]
-    UnauthorizedClose,
+    pub fn execute_clawback_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteClawbackProposal<'info>>,
+    ) -> Result<()> {
+        _execute_clawback_proposal(ctx)
+    }
 }
@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use crate::{Ballot, DepositConfig, GovernanceConfig, ReputationConfig, ReputationReason, TreasuryAsset};
+
+#[event]
+pub struct TreasuryInitializedEvent {
+    pub treasury: Pubkey,
+    pub rwd_vault: Pubkey,
+    pub usdc_vault: Pubkey,
+}
+
+#[event]
+pub struct TreasurySpendEvent {
+    pub proposal: Pubkey,
+    pub asset: TreasuryAsset,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct PaymentStreamCreatedEvent {
+    pub proposal: Pubkey,
+    pub stream: Pubkey,
+    pub asset: TreasuryAsset,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub start_ts: i64,
+    pub end_ts: i64,
+}
+
+#[event]
+pub struct StreamWithdrawnEvent {
+    pub stream: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub total_withdrawn: u64,
+}
+
+#[event]
+pub struct ReputationConfigUpdatedEvent {
+    pub admin: Pubkey,
+    pub reputation: ReputationConfig,
+}
+
+#[event]
+pub struct ReputationChangedEvent {
+    pub member: Pubkey,
+    pub authority: Pubkey,
+    pub delta: i64,
+    pub reputation_points: u64,
+    pub reason: ReputationReason,
+}
+
+#[event]
+pub struct VoteCastEvent {
+    pub proposal: Pubkey,
+    pub member: Pubkey,
+    pub ballot: Ballot,
+    pub weight: u64,
+    pub delegated_weight: u64,
+    pub delegators: u32,
+}
+
+#[event]
+pub struct RankedRoundTalliedEvent {
+    pub proposal: Pubkey,
+    pub round: u8,
+    pub counts: Vec<u64>,
+    pub eliminated: Option<u8>,
+}
+
+#[event]
+pub struct DelegationCreatedEvent {
+    pub delegator: Pubkey,
+    pub delegate: Pubkey,
+    pub topic: u8,
+}
+
+#[event]
+pub struct DelegationRevokedEvent {
+    pub delegator: Pubkey,
+    pub delegate: Pubkey,
+    pub topic: u8,
+}
+
+#[event]
+pub struct DepositConfigUpdatedEvent {
+    pub admin: Pubkey,
+    pub deposit: DepositConfig,
+}
+
+#[event]
+pub struct GovernanceConfigUpdatedEvent {
+    pub admin: Pubkey,
+    pub governance: GovernanceConfig,
+}
+
+#[event]
+pub struct ProposalDepositedEvent {
+    pub proposal: Pubkey,
+    pub proposer: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct DepositSettledEvent {
+    pub proposal: Pubkey,
+    pub proposer: Pubkey,
+    pub amount: u64,
+    pub refunded: bool,
+}
+
+#[event]
+pub struct TokensLockedEvent {
+    pub member: Pubkey,
+    pub added_amount: u64,
+    pub locked_amount: u64,
+    pub lock_end: i64,
+}
+
+#[event]
+pub struct TokensUnlockedEvent {
+    pub member: Pubkey,
+    pub amount: u64,
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer};
+
+/// Creates a program-owned PDA that is not declared in the instruction's account
+/// struct, e.g. accounts passed through `remaining_accounts`.
+///
+/// Mirrors Anchor's `init`: `create_account` fails on an address that already holds
+/// lamports, so anyone could block creation by pre-funding the PDA. In that case the
+/// rent shortfall is topped up and the account is allocated and assigned instead.
+pub fn create_pda_account<'info>(
+    payer: AccountInfo<'info>,
+    account: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let current_lamports = account.lamports();
+
+    if current_lamports == 0 {
+        let cpi_accounts = CreateAccount {
+            from: payer,
+            to: account,
+        };
+        let cpi_context = CpiContext::new_with_signer(system_program, cpi_accounts, signer_seeds);
+
+        return create_account(cpi_context, rent, space as u64, &crate::ID);
+    }
+
+    let shortfall = rent.saturating_sub(current_lamports);
+    if shortfall > 0 {
+        let cpi_accounts = Transfer {
+            from: payer,
+            to: account.clone(),
+        };
+        transfer(CpiContext::new(system_program.clone(), cpi_accounts), shortfall)?;
+    }
+
+    let cpi_accounts = Allocate {
+        account_to_allocate: account.clone(),
+    };
+    allocate(
+        CpiContext::new_with_signer(system_program.clone(), cpi_accounts, signer_seeds),
+        space as u64,
+    )?;
+
+    let cpi_accounts = Assign {
+        account_to_assign: account,
+    };
+    assign(CpiContext::new_with_signer(system_program, cpi_accounts, signer_seeds), &crate::ID)
+}
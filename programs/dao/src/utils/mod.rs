@@ -0,0 +1,7 @@
+pub mod account;
+pub mod tally;
+pub mod token;
+
+pub use account::*;
+pub use tally::*;
+pub use token::*;
@@ -0,0 +1,121 @@
+// Pure tally helpers for the alternative voting modes, kept free of account
+// handling so they can be unit tested off-chain.
+
+/// Options selected by an approval ballot; bit `i` of `mask` approves option `i`.
+pub fn approved_options(mask: u8, option_count: usize) -> impl Iterator<Item = usize> {
+    (0..option_count.min(u8::BITS as usize)).filter(move |index| mask & (1 << index) != 0)
+}
+
+/// Number of credits it costs to cast `votes` quadratic votes.
+pub fn quadratic_cost(votes: u64) -> Option<u64> {
+    votes.checked_mul(votes)
+}
+
+/// Runs an instant-runoff count over weighted ranked ballots and returns the vote
+/// totals of the final round. Each round counts every ballot for its highest ranked
+/// option still standing; when no option holds a strict majority the weakest one is
+/// eliminated (on ties, the one listed last). Eliminated options finish with zero.
+pub fn instant_runoff(ballots: &[(&[u8], u64)], option_count: usize) -> Vec<u64> {
+    let mut eliminated = vec![false; option_count];
+
+    loop {
+        let mut counts = vec![0u64; option_count];
+        for (ranking, weight) in ballots {
+            if let Some(option) = runoff_choice(ranking, &eliminated) {
+                counts[option] += weight;
+            }
+        }
+
+        match runoff_elimination(&counts, &eliminated) {
+            Some(option) => eliminated[option] = true,
+            None => return counts,
+        }
+    }
+}
+
+/// Highest ranked option of a ballot that is still standing.
+pub fn runoff_choice(ranking: &[u8], eliminated: &[bool]) -> Option<usize> {
+    ranking
+        .iter()
+        .map(|option| *option as usize)
+        .find(|option| *option < eliminated.len() && !eliminated[*option])
+}
+
+/// Option to eliminate after a round with these counts, or `None` once the round
+/// is final: someone holds a strict majority, one option is left, or nobody voted.
+pub fn runoff_elimination(counts: &[u64], eliminated: &[bool]) -> Option<usize> {
+    let total: u128 = counts.iter().map(|count| *count as u128).sum();
+    let leader = counts.iter().copied().max().unwrap_or(0) as u128;
+    let standing = eliminated.iter().filter(|out| !**out).count();
+    if total == 0 || leader * 2 > total || standing <= 1 {
+        return None;
+    }
+
+    (0..counts.len())
+        .filter(|option| !eliminated[*option])
+        .rev()
+        .min_by_key(|option| counts[*option])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn approval_mask_selects_options_in_range() {
+        let selected: Vec<usize> = approved_options(0b1000_0101, 4).collect();
+        assert_eq!(selected, vec![0, 2]);
+        assert_eq!(approved_options(0, 8).count(), 0);
+        assert_eq!(approved_options(u8::MAX, 8).count(), 8);
+    }
+
+    #[test]
+    fn quadratic_cost_squares_votes() {
+        assert_eq!(quadratic_cost(0), Some(0));
+        assert_eq!(quadratic_cost(3), Some(9));
+        assert_eq!(quadratic_cost(u64::MAX), None);
+    }
+
+    #[test]
+    fn instant_runoff_returns_first_round_on_majority() {
+        let ballots: Vec<(&[u8], u64)> = vec![(&[0, 1], 3), (&[1, 0], 1), (&[2], 1)];
+        assert_eq!(instant_runoff(&ballots, 3), vec![3, 1, 1]);
+    }
+
+    #[test]
+    fn instant_runoff_transfers_eliminated_votes() {
+        // No majority in round one; option 2 is weakest and its voter prefers option 1 next
+        let ballots: Vec<(&[u8], u64)> = vec![(&[0], 4), (&[1, 0], 3), (&[2, 1], 2)];
+        assert_eq!(instant_runoff(&ballots, 3), vec![4, 5, 0]);
+    }
+
+    #[test]
+    fn instant_runoff_respects_weights() {
+        let ballots: Vec<(&[u8], u64)> = vec![(&[0, 1], 10), (&[1], 6), (&[2, 1], 5)];
+        assert_eq!(instant_runoff(&ballots, 3), vec![10, 11, 0]);
+    }
+
+    #[test]
+    fn instant_runoff_breaks_ties_against_later_options() {
+        let ballots: Vec<(&[u8], u64)> = vec![(&[0], 1), (&[1], 1)];
+        assert_eq!(instant_runoff(&ballots, 2), vec![1, 0]);
+    }
+
+    #[test]
+    fn instant_runoff_drops_exhausted_ballots() {
+        // Once option 2 is out, its only ballot has no further preference
+        let ballots: Vec<(&[u8], u64)> = vec![(&[0], 2), (&[1], 2), (&[2], 1)];
+        assert_eq!(instant_runoff(&ballots, 3), vec![2, 0, 0]);
+    }
+
+    #[test]
+    fn instant_runoff_ignores_out_of_range_choices() {
+        let ballots: Vec<(&[u8], u64)> = vec![(&[7, 1], 2), (&[0], 1)];
+        assert_eq!(instant_runoff(&ballots, 2), vec![1, 2]);
+    }
+
+    #[test]
+    fn instant_runoff_without_ballots_is_empty() {
+        assert_eq!(instant_runoff(&[], 3), vec![0, 0, 0]);
+    }
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::onchain::invoke_transfer_checked;
+
+/// Transfers RWD out of a PDA-owned account. RWD carries a transfer hook, so the
+/// hook program, its extra account meta list and the resolved extra accounts must
+/// be passed in `remaining_accounts`. Pass empty `signer_seeds` when the authority
+/// already signed the transaction.
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_rwd_signed<'info>(
+    token_program: &AccountInfo<'info>,
+    from: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    to: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    decimals: u8,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    invoke_transfer_checked(
+        token_program.key,
+        from,
+        mint,
+        to,
+        authority,
+        remaining_accounts,
+        amount,
+        decimals,
+        signer_seeds,
+    )?;
+    Ok(())
+}
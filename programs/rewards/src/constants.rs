@@ -23,4 +23,71 @@ pub const USDC_DECIMALS: u8 = 6;
 #[constant]
 pub const RWD_PER_USDC: u64 = 100;
 
+#[constant]
+pub const REWARD_POOL_SEED: &[u8] = b"reward-pool";
+
+#[constant]
+pub const STAKE_VAULT_SEED: &[u8] = b"stake-vault";
+
+#[constant]
+pub const REWARD_VAULT_SEED: &[u8] = b"reward-vault";
+
+#[constant]
+pub const STAKE_SEED: &[u8] = b"stake";
+
+#[constant]
+pub const CAMPAIGN_SEED: &[u8] = b"campaign";
+
+#[constant]
+pub const CAMPAIGN_VAULT_SEED: &[u8] = b"campaign-vault";
+
+#[constant]
+pub const CLAIM_SEED: &[u8] = b"claim";
+
+#[constant]
+pub const VESTING_SEED: &[u8] = b"vesting";
+
+#[constant]
+pub const VESTING_ESCROW_SEED: &[u8] = b"vesting-escrow";
+
+#[constant]
+pub const MERCHANT_SEED: &[u8] = b"merchant";
+
+#[constant]
+pub const MERCHANT_BUDGET_SEED: &[u8] = b"merchant-budget";
+
+#[constant]
+pub const POINTS_EXPIRY_SEED: &[u8] = b"points-expiry";
+
+#[constant]
+pub const PERMANENT_DELEGATE_SEED: &[u8] = b"permanent-delegate";
+
+#[constant]
+pub const TRANSFER_POLICY_SEED: &[u8] = b"transfer-policy";
+
+pub const MAX_TRANSFER_WHITELIST: usize = 16;
+
+#[constant]
+pub const FREEZE_AUTHORITY_SEED: &[u8] = b"freeze-authority";
+
+#[constant]
+pub const COMPLIANCE_SEED: &[u8] = b"compliance";
+
+#[constant]
+pub const BLACKLIST_SEED: &[u8] = b"blacklist";
+
+#[constant]
+pub const TRANSFER_LIMITS_SEED: &[u8] = b"transfer-limits";
+
+pub const MAX_TRANSFER_LIMIT_EXEMPTIONS: usize = 16;
+
+pub const MAX_CLAWBACK_REASON_LEN: usize = 200;
+
+pub const MAX_PAUSERS: usize = 8;
+
+pub const DEFAULT_MAX_PAUSE_DURATION: i64 = 7 * 24 * 60 * 60;
+
+// Fixed-point scale of the reward-per-token accumulator
+pub const REWARD_PRECISION: u128 = 1_000_000_000_000;
+
 pub const DISCRIMINATOR: usize = 8;
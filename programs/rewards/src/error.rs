@@ -31,4 +31,85 @@ pub enum RewardTokenError {
     
     #[msg("Burning operations are currently frozen.")]
     BurnFrozen, // 6009
-}
\ No newline at end of file
+
+    #[msg("Amount must be greater than zero.")]
+    InvalidAmount, // 6010
+
+    #[msg("Unstake amount exceeds the staked balance.")]
+    InsufficientStake, // 6011
+
+    #[msg("No rewards to claim.")]
+    NothingToClaim, // 6012
+
+    #[msg("Campaign expiry must be in the future.")]
+    InvalidExpiry, // 6013
+
+    #[msg("Merkle proof does not match the campaign root.")]
+    InvalidMerkleProof, // 6014
+
+    #[msg("Campaign has expired.")]
+    CampaignExpired, // 6015
+
+    #[msg("Campaign has not expired yet.")]
+    CampaignNotExpired, // 6016
+
+    #[msg("Claim exceeds the campaign's remaining funds.")]
+    CampaignOverdrawn, // 6017
+
+    #[msg("Vesting schedule must satisfy start <= cliff <= end with start < end.")]
+    InvalidVestingSchedule, // 6018
+
+    #[msg("Vesting schedule has already been revoked.")]
+    VestingRevoked, // 6019
+
+    #[msg("Merchant is not active.")]
+    MerchantInactive, // 6020
+
+    #[msg("Award exceeds the merchant's remaining budget.")]
+    MerchantBudgetExceeded, // 6021
+
+    #[msg("Points expiry period must be positive.")]
+    InvalidExpiryPeriod, // 6022
+
+    #[msg("Transfer whitelist is full.")]
+    TransferWhitelistFull, // 6023
+
+    #[msg("Entry is already whitelisted or was not found.")]
+    InvalidWhitelistEntry, // 6024
+
+    #[msg("Clawback reason is empty or too long.")]
+    InvalidClawbackReason, // 6025
+
+    #[msg("A destination account is required unless the clawback burns.")]
+    MissingClawbackDestination, // 6026
+
+    #[msg("Pausers must be unique and within the limit, with a threshold between 1 and their count.")]
+    InvalidPauserSet, // 6027
+
+    #[msg("Unfreezing requires approvals from the pausers.")]
+    UnfreezeRequiresApprovals, // 6028
+
+    #[msg("Pauser already approved this unfreeze.")]
+    UnfreezeAlreadyApproved, // 6029
+
+    #[msg("Freeze duration must be positive and within the maximum pause duration.")]
+    InvalidFreezeDuration, // 6030
+
+    #[msg("Epoch limits need a positive epoch duration.")]
+    InvalidTransferLimits, // 6031
+
+    #[msg("Transfer limit exemption list is full.")]
+    TransferLimitExemptionsFull, // 6032
+
+    #[msg("Entry is already exempt from transfer limits or was not found.")]
+    InvalidTransferLimitExemption, // 6033
+
+    #[msg("Redemption amount must convert to whole USDC base units.")]
+    InvalidRedemptionAmount, // 6034
+
+    #[msg("Arithmetic overflow.")]
+    MathOverflow, // 6035
+
+    #[msg("Only an active freeze with a lapse can be extended.")]
+    FreezeNotExtendable, // 6036
+}
@@ -0,0 +1,295 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::*;
+use crate::events::*;
+use crate::utils::*;
+use crate::{check_freeze_state, FreezeState};
+
+use anchor_spl::token_interface::{
+    self,
+    Mint as Mint2022,
+    MintTo,
+    TokenAccount as TokenAccount2022,
+    TokenInterface,
+};
+
+// Mints the whole campaign budget into a vault owned by the campaign. The signer must
+// be the RWD mint authority; `treasury` receives whatever is left after expiry.
+pub fn _create_campaign(
+    ctx: Context<CreateCampaign>,
+    campaign_id: u64,
+    merkle_root: [u8; 32],
+    total_amount: u64,
+    expiry_ts: i64,
+) -> Result<()> {
+    check_freeze_state(&ctx.accounts.freeze_state, "mint")?;
+    require!(total_amount > 0, RewardTokenError::InvalidAmount);
+    require!(expiry_ts > Clock::get()?.unix_timestamp, RewardTokenError::InvalidExpiry);
+
+    let campaign = &mut ctx.accounts.campaign;
+    campaign.authority = ctx.accounts.signer.key();
+    campaign.id = campaign_id;
+    campaign.merkle_root = merkle_root;
+    campaign.total_amount = total_amount;
+    campaign.claimed_amount = 0;
+    campaign.expiry_ts = expiry_ts;
+    campaign.treasury = ctx.accounts.treasury.key();
+    campaign.bump = ctx.bumps.campaign;
+
+    let cpi_accounts = MintTo {
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.campaign_vault.to_account_info(),
+        authority: ctx.accounts.signer.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program2022.to_account_info();
+    token_interface::mint_to(CpiContext::new(cpi_program, cpi_accounts), total_amount)?;
+
+    emit!(CampaignCreatedEvent {
+        campaign: campaign.key(),
+        campaign_id,
+        merkle_root,
+        total_amount,
+        expiry_ts,
+    });
+
+    Ok(())
+}
+
+// Remaining accounts: transfer hook extra accounts for the payout from the campaign vault.
+// The claim status PDA is created here, so a second claim for the same index fails.
+pub fn _claim_airdrop<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimAirdrop<'info>>,
+    index: u64,
+    amount: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let campaign = &ctx.accounts.campaign;
+    require!(Clock::get()?.unix_timestamp < campaign.expiry_ts, RewardTokenError::CampaignExpired);
+
+    let leaf = airdrop_leaf(index, ctx.accounts.claimant.key, amount);
+    require!(
+        verify_merkle_proof(&proof, &campaign.merkle_root, leaf),
+        RewardTokenError::InvalidMerkleProof
+    );
+
+    let claimed_amount = campaign
+        .claimed_amount
+        .checked_add(amount)
+        .filter(|claimed| *claimed <= campaign.total_amount)
+        .ok_or(RewardTokenError::CampaignOverdrawn)?;
+
+    let id_bytes = campaign.id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[CAMPAIGN_SEED, &id_bytes, &[campaign.bump]]];
+
+    transfer_checked_with_hook(
+        ctx.accounts.token_program2022.key,
+        ctx.accounts.campaign_vault.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.claimant_ata.to_account_info(),
+        campaign.to_account_info(),
+        ctx.remaining_accounts,
+        amount,
+        ctx.accounts.mint.decimals,
+        signer_seeds,
+    )?;
+
+    let claim_status = &mut ctx.accounts.claim_status;
+    claim_status.campaign = campaign.key();
+    claim_status.index = index;
+    claim_status.claimant = ctx.accounts.claimant.key();
+    claim_status.amount = amount;
+    claim_status.claimed_at = Clock::get()?.unix_timestamp;
+
+    ctx.accounts.campaign.claimed_amount = claimed_amount;
+
+    emit!(AirdropClaimedEvent {
+        campaign: ctx.accounts.campaign.key(),
+        index,
+        claimant: ctx.accounts.claimant.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+// Remaining accounts: transfer hook extra accounts for the sweep into the treasury.
+// Permissionless once the campaign has expired; the destination is fixed at creation.
+pub fn _reclaim_campaign<'info>(ctx: Context<'_, '_, '_, 'info, ReclaimCampaign<'info>>) -> Result<()> {
+    let campaign = &ctx.accounts.campaign;
+    require!(Clock::get()?.unix_timestamp >= campaign.expiry_ts, RewardTokenError::CampaignNotExpired);
+
+    let amount = ctx.accounts.campaign_vault.amount;
+    require!(amount > 0, RewardTokenError::NothingToClaim);
+
+    let id_bytes = campaign.id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[CAMPAIGN_SEED, &id_bytes, &[campaign.bump]]];
+
+    transfer_checked_with_hook(
+        ctx.accounts.token_program2022.key,
+        ctx.accounts.campaign_vault.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.treasury.to_account_info(),
+        campaign.to_account_info(),
+        ctx.remaining_accounts,
+        amount,
+        ctx.accounts.mint.decimals,
+        signer_seeds,
+    )?;
+
+    emit!(CampaignReclaimedEvent {
+        campaign: campaign.key(),
+        treasury: ctx.accounts.treasury.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u64)]
+pub struct CreateCampaign<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [TOKEN_2022_SEED],
+        bump,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint2022>>,
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [CAMPAIGN_SEED, campaign_id.to_le_bytes().as_ref()],
+        bump,
+        space = DISCRIMINATOR + Campaign::INIT_SPACE,
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [CAMPAIGN_VAULT_SEED, campaign.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = campaign,
+        token::token_program = token_program2022,
+    )]
+    pub campaign_vault: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    #[account(
+        token::mint = mint,
+        token::token_program = token_program2022,
+    )]
+    pub treasury: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    #[account(
+        seeds = [FREEZE_SEED],
+        bump,
+    )]
+    pub freeze_state: Account<'info, FreezeState>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program2022: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+#[instruction(index: u64)]
+pub struct ClaimAirdrop<'info> {
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CAMPAIGN_SEED, campaign.id.to_le_bytes().as_ref()],
+        bump = campaign.bump,
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        init,
+        payer = claimant,
+        seeds = [CLAIM_SEED, campaign.key().as_ref(), index.to_le_bytes().as_ref()],
+        bump,
+        space = DISCRIMINATOR + ClaimStatus::INIT_SPACE,
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+
+    #[account(
+        seeds = [TOKEN_2022_SEED],
+        bump,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint2022>>,
+
+    #[account(
+        mut,
+        seeds = [CAMPAIGN_VAULT_SEED, campaign.key().as_ref()],
+        bump,
+    )]
+    pub campaign_vault: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = claimant,
+        token::token_program = token_program2022,
+    )]
+    pub claimant_ata: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program2022: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct ReclaimCampaign<'info> {
+    #[account(
+        seeds = [CAMPAIGN_SEED, campaign.id.to_le_bytes().as_ref()],
+        bump = campaign.bump,
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        seeds = [TOKEN_2022_SEED],
+        bump,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint2022>>,
+
+    #[account(
+        mut,
+        seeds = [CAMPAIGN_VAULT_SEED, campaign.key().as_ref()],
+        bump,
+    )]
+    pub campaign_vault: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    #[account(
+        mut,
+        address = campaign.treasury,
+    )]
+    pub treasury: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    pub token_program2022: Interface<'info, TokenInterface>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Campaign {
+    pub authority: Pubkey,
+    pub id: u64,
+    pub merkle_root: [u8; 32],
+    pub total_amount: u64,
+    pub claimed_amount: u64,
+    pub expiry_ts: i64,
+    pub treasury: Pubkey,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ClaimStatus {
+    pub campaign: Pubkey,
+    pub index: u64,
+    pub claimant: Pubkey,
+    pub amount: u64,
+    pub claimed_at: i64,
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::constants::*;
-use crate::{FreezeState, Fees, check_freeze_state, BurnEvent};
+use crate::{FreezeState, Fees, check_freeze_state, consume_burned_lots, BurnEvent};
+use transfer_hook::{program::TransferHook, LOT_LEDGER_SEED};
 
 use anchor_spl::{
     associated_token::AssociatedToken,
@@ -79,7 +80,14 @@ pub fn _burn_tokens(ctx: &Context<BurnTokens>, amount: u64) -> Result<()> {
     let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
 
     token_interface::burn(cpi_ctx, amount)?;
-    Ok(())
+
+    consume_burned_lots(
+        ctx.accounts.points_expiry.to_account_info(),
+        ctx.bumps.points_expiry,
+        ctx.accounts.lot_ledger.to_account_info(),
+        ctx.accounts.transfer_hook_program.to_account_info(),
+        amount,
+    )
 }
 
 #[derive(Accounts)]
@@ -140,6 +148,24 @@ pub struct BurnTokens<'info> {
     )]
     pub fee_collector: InterfaceAccount<'info, TokenAccount2022>,
 
+    /// CHECK: PDA the transfer hook accepts as the issuer of point lots
+    #[account(
+        seeds = [POINTS_EXPIRY_SEED],
+        bump,
+    )]
+    pub points_expiry: UncheckedAccount<'info>,
+
+    /// CHECK: burned owner's lot ledger, kept by the transfer hook program; may not exist
+    #[account(
+        mut,
+        seeds = [LOT_LEDGER_SEED, signer.key().as_ref()],
+        bump,
+        seeds::program = transfer_hook_program.key(),
+    )]
+    pub lot_ledger: UncheckedAccount<'info>,
+
+    pub transfer_hook_program: Program<'info, TransferHook>,
+
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
     pub token_program2022: Interface<'info, TokenInterface>,
@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::*;
+use crate::events::*;
+use crate::utils::*;
+use crate::{consume_burned_lots, Compliance};
+
+use anchor_spl::token_interface::{
+    self,
+    Burn,
+    Mint as Mint2022,
+    TokenAccount as TokenAccount2022,
+    TokenInterface,
+};
+use transfer_hook::{program::TransferHook, LOT_LEDGER_SEED};
+
+// Recovers fraudulently earned rewards from any holder through the mint's permanent
+// delegate, either burning them or moving them to `destination`. The transfer hook
+// lets the permanent delegate through blacklist and soulbound checks, so frozen-out
+// wallets can still be cleaned up. Hook accounts go in `remaining_accounts`.
+pub fn _clawback<'info>(
+    ctx: Context<'_, '_, '_, 'info, Clawback<'info>>,
+    amount: u64,
+    burn: bool,
+    reason: String,
+) -> Result<()> {
+    require!(amount > 0, RewardTokenError::InvalidAmount);
+    require!(
+        !reason.is_empty() && reason.len() <= MAX_CLAWBACK_REASON_LEN,
+        RewardTokenError::InvalidClawbackReason
+    );
+    require!(
+        ctx.accounts.compliance.can_claw_back(ctx.accounts.authority.key),
+        RewardTokenError::Unauthorized
+    );
+
+    let signer_seeds: &[&[&[u8]]] = &[&[PERMANENT_DELEGATE_SEED, &[ctx.bumps.permanent_delegate]]];
+
+    let destination = if burn {
+        let cpi_accounts = Burn {
+            authority: ctx.accounts.permanent_delegate.to_account_info(),
+            from: ctx.accounts.source.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program2022.to_account_info();
+        token_interface::burn(CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds), amount)?;
+
+        // Transfers consume lots in the hook; burns have to do it here
+        consume_burned_lots(
+            ctx.accounts.points_expiry.to_account_info(),
+            ctx.bumps.points_expiry,
+            ctx.accounts.lot_ledger.to_account_info(),
+            ctx.accounts.transfer_hook_program.to_account_info(),
+            amount,
+        )?;
+        None
+    } else {
+        let destination = ctx
+            .accounts
+            .destination
+            .as_ref()
+            .ok_or(RewardTokenError::MissingClawbackDestination)?;
+
+        transfer_checked_with_hook(
+            ctx.accounts.token_program2022.key,
+            ctx.accounts.source.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            destination.to_account_info(),
+            ctx.accounts.permanent_delegate.to_account_info(),
+            ctx.remaining_accounts,
+            amount,
+            ctx.accounts.mint.decimals,
+            signer_seeds,
+        )?;
+        Some(destination.key())
+    };
+
+    emit!(ClawbackEvent {
+        authority: ctx.accounts.authority.key(),
+        source: ctx.accounts.source.key(),
+        owner: ctx.accounts.source.owner,
+        destination,
+        amount,
+        burned: burn,
+        reason,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Clawback<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [COMPLIANCE_SEED],
+        bump = compliance.bump,
+    )]
+    pub compliance: Account<'info, Compliance>,
+
+    /// CHECK: PDA holding the mint's permanent delegate role
+    #[account(
+        seeds = [PERMANENT_DELEGATE_SEED],
+        bump,
+    )]
+    pub permanent_delegate: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [TOKEN_2022_SEED],
+        bump,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint2022>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program2022,
+    )]
+    pub source: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program2022,
+    )]
+    pub destination: Option<Box<InterfaceAccount<'info, TokenAccount2022>>>,
+
+    /// CHECK: PDA the transfer hook accepts as the issuer of point lots
+    #[account(
+        seeds = [POINTS_EXPIRY_SEED],
+        bump,
+    )]
+    pub points_expiry: UncheckedAccount<'info>,
+
+    /// CHECK: burned owner's lot ledger, kept by the transfer hook program; may not exist
+    #[account(
+        mut,
+        seeds = [LOT_LEDGER_SEED, source.owner.as_ref()],
+        bump,
+        seeds::program = transfer_hook_program.key(),
+    )]
+    pub lot_ledger: UncheckedAccount<'info>,
+
+    pub transfer_hook_program: Program<'info, TransferHook>,
+
+    pub token_program2022: Interface<'info, TokenInterface>,
+}
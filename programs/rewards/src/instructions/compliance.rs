@@ -0,0 +1,262 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use crate::constants::*;
+use crate::error::*;
+use crate::events::*;
+
+use anchor_spl::token_interface::{
+    self,
+    FreezeAccount,
+    Mint as Mint2022,
+    ThawAccount,
+    TokenAccount as TokenAccount2022,
+    TokenInterface,
+};
+
+// Only the mint authority can set up compliance, so the first caller can't claim it
+pub fn _initialize_compliance(ctx: Context<InitCompliance>, officer: Pubkey) -> Result<()> {
+    ctx.accounts
+        .compliance
+        .initialize(ctx.accounts.signer.key(), officer, ctx.bumps.compliance);
+
+    emit!(ComplianceOfficerUpdatedEvent { officer });
+
+    Ok(())
+}
+
+pub fn _set_compliance_officer(ctx: Context<SetComplianceOfficer>, officer: Pubkey) -> Result<()> {
+    ctx.accounts.compliance.officer = officer;
+
+    emit!(ComplianceOfficerUpdatedEvent { officer });
+
+    Ok(())
+}
+
+// With a clawback authority set (e.g. the DAO treasury PDA, signing from an approved
+// proposal), clawbacks need its signature instead of the officer's.
+pub fn _set_clawback_authority(ctx: Context<SetComplianceOfficer>, clawback_authority: Option<Pubkey>) -> Result<()> {
+    ctx.accounts.compliance.clawback_authority = clawback_authority;
+
+    emit!(ClawbackAuthorityUpdatedEvent { clawback_authority });
+
+    Ok(())
+}
+
+pub fn _freeze_token_account(ctx: Context<FreezeTokenAccount>, reason_code: u16) -> Result<()> {
+    let signer_seeds: &[&[&[u8]]] = &[&[FREEZE_AUTHORITY_SEED, &[ctx.bumps.freeze_authority]]];
+    let cpi_accounts = FreezeAccount {
+        account: ctx.accounts.token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        authority: ctx.accounts.freeze_authority.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program2022.to_account_info();
+    token_interface::freeze_account(CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds))?;
+
+    emit!(TokenAccountFreezeEvent {
+        token_account: ctx.accounts.token_account.key(),
+        owner: ctx.accounts.token_account.owner,
+        frozen: true,
+        reason_code,
+    });
+
+    Ok(())
+}
+
+pub fn _thaw_token_account(ctx: Context<FreezeTokenAccount>, reason_code: u16) -> Result<()> {
+    let signer_seeds: &[&[&[u8]]] = &[&[FREEZE_AUTHORITY_SEED, &[ctx.bumps.freeze_authority]]];
+    let cpi_accounts = ThawAccount {
+        account: ctx.accounts.token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        authority: ctx.accounts.freeze_authority.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program2022.to_account_info();
+    token_interface::thaw_account(CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds))?;
+
+    emit!(TokenAccountFreezeEvent {
+        token_account: ctx.accounts.token_account.key(),
+        owner: ctx.accounts.token_account.owner,
+        frozen: false,
+        reason_code,
+    });
+
+    Ok(())
+}
+
+// The transfer hook rejects any transfer whose source or destination owner has an entry
+pub fn _add_to_blacklist(ctx: Context<AddToBlacklist>, wallet: Pubkey, reason_code: u16) -> Result<()> {
+    let entry = &mut ctx.accounts.blacklist_entry;
+    entry.wallet = wallet;
+    entry.reason_code = reason_code;
+    entry.added_at = Clock::get()?.unix_timestamp;
+    entry.bump = ctx.bumps.blacklist_entry;
+
+    emit!(BlacklistUpdatedEvent {
+        wallet,
+        blacklisted: true,
+        reason_code,
+    });
+
+    Ok(())
+}
+
+pub fn _remove_from_blacklist(ctx: Context<RemoveFromBlacklist>, reason_code: u16) -> Result<()> {
+    emit!(BlacklistUpdatedEvent {
+        wallet: ctx.accounts.blacklist_entry.wallet,
+        blacklisted: false,
+        reason_code,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitCompliance<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [TOKEN_2022_SEED],
+        bump,
+        constraint = mint.mint_authority == COption::Some(signer.key()) @ RewardTokenError::Unauthorized,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint2022>>,
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [COMPLIANCE_SEED],
+        bump,
+        space = DISCRIMINATOR + Compliance::INIT_SPACE,
+    )]
+    pub compliance: Account<'info, Compliance>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetComplianceOfficer<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority @ RewardTokenError::Unauthorized,
+        seeds = [COMPLIANCE_SEED],
+        bump = compliance.bump,
+    )]
+    pub compliance: Account<'info, Compliance>,
+}
+
+#[derive(Accounts)]
+pub struct FreezeTokenAccount<'info> {
+    pub officer: Signer<'info>,
+
+    #[account(
+        has_one = officer @ RewardTokenError::Unauthorized,
+        seeds = [COMPLIANCE_SEED],
+        bump = compliance.bump,
+    )]
+    pub compliance: Account<'info, Compliance>,
+
+    /// CHECK: PDA holding the mint's freeze authority
+    #[account(
+        seeds = [FREEZE_AUTHORITY_SEED],
+        bump,
+    )]
+    pub freeze_authority: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [TOKEN_2022_SEED],
+        bump,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint2022>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program2022,
+    )]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    pub token_program2022: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct AddToBlacklist<'info> {
+    #[account(mut)]
+    pub officer: Signer<'info>,
+
+    #[account(
+        has_one = officer @ RewardTokenError::Unauthorized,
+        seeds = [COMPLIANCE_SEED],
+        bump = compliance.bump,
+    )]
+    pub compliance: Account<'info, Compliance>,
+
+    #[account(
+        init,
+        payer = officer,
+        seeds = [BLACKLIST_SEED, wallet.as_ref()],
+        bump,
+        space = DISCRIMINATOR + BlacklistEntry::INIT_SPACE,
+    )]
+    pub blacklist_entry: Account<'info, BlacklistEntry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveFromBlacklist<'info> {
+    #[account(mut)]
+    pub officer: Signer<'info>,
+
+    #[account(
+        has_one = officer @ RewardTokenError::Unauthorized,
+        seeds = [COMPLIANCE_SEED],
+        bump = compliance.bump,
+    )]
+    pub compliance: Account<'info, Compliance>,
+
+    #[account(
+        mut,
+        close = officer,
+        seeds = [BLACKLIST_SEED, blacklist_entry.wallet.as_ref()],
+        bump = blacklist_entry.bump,
+    )]
+    pub blacklist_entry: Account<'info, BlacklistEntry>,
+}
+
+impl Compliance {
+    pub fn initialize(&mut self, authority: Pubkey, officer: Pubkey, bump: u8) {
+        self.authority = authority;
+        self.officer = officer;
+        self.clawback_authority = None;
+        self.bump = bump;
+    }
+
+    pub fn can_claw_back(&self, signer: &Pubkey) -> bool {
+        match self.clawback_authority {
+            Some(clawback_authority) => clawback_authority == *signer,
+            None => self.officer == *signer,
+        }
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Compliance {
+    pub authority: Pubkey,
+    pub officer: Pubkey,
+    pub clawback_authority: Option<Pubkey>,
+    pub bump: u8,
+}
+
+// Existence of the PDA is what the transfer hook checks
+#[account]
+#[derive(InitSpace)]
+pub struct BlacklistEntry {
+    pub wallet: Pubkey,
+    pub reason_code: u16,
+    pub added_at: i64,
+    pub bump: u8,
+}
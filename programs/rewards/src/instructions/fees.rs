@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 use crate::constants::*;
+use crate::error::*;
 
 use anchor_spl::{
     associated_token::AssociatedToken,
@@ -9,17 +10,6 @@ use anchor_spl::{
     },
 };
 
-pub fn _initialize_fees(ctx: Context<InitializeFees>, args: InitFeesArgs) -> Result<()> {
-    let fees = &mut ctx.accounts.fees;
-
-    fees.mint_fee_bps = args.mint_fee_bps;
-    fees.transfer_fee_bps = args.transfer_fee_bps;
-    fees.redemption_fee_bps = args.redemption_fee_bps;
-    fees.fee_collector = args.fee_collector;
-
-    Ok(())
-}
-
 pub fn _update_fees(ctx: Context<UpdateFees>, args: UpdateFeesArgs) -> Result<()> {
     let fees = &mut ctx.accounts.fees;
 
@@ -40,35 +30,30 @@ pub fn _update_fees(ctx: Context<UpdateFees>, args: UpdateFeesArgs) -> Result<()
 }
 
 #[derive(Accounts)]
-pub struct InitializeFees<'info> {
+pub struct UpdateFees<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
 
     #[account(
-        init,
-        payer = signer,
+        mut,
         seeds = [FEES_SEED],
         bump,
-        space = DISCRIMINATOR + Fees::INIT_SPACE
+        constraint = fees.authority == signer.key() @ RewardTokenError::Unauthorized,
     )]
     pub fees: Account<'info, Fees>,
-
-    pub system_program: Program<'info, System>,
 }
 
-#[derive(Accounts)]
-pub struct UpdateFees<'info> {
-    #[account(mut)]
-    pub signer: Signer<'info>,
-
-    #[account(
-        mut,
-        seeds = [FEES_SEED],
-        bump
-    )]
-    pub fees: Account<'info, Fees>,
+impl Fees {
+    pub fn initialize(&mut self, args: InitFeesArgs, authority: Pubkey) {
+        self.mint_fee_bps = args.mint_fee_bps;
+        self.transfer_fee_bps = args.transfer_fee_bps;
+        self.redemption_fee_bps = args.redemption_fee_bps;
+        self.fee_collector = args.fee_collector;
+        self.authority = authority;
+    }
 }
 
+// The transfer hook reads `transfer_fee_bps` at a fixed offset, so new fields go last
 #[account]
 #[derive(InitSpace)]
 pub struct Fees {
@@ -76,6 +61,8 @@ pub struct Fees {
     pub transfer_fee_bps: u16,
     pub redemption_fee_bps: u16,
     pub fee_collector: Pubkey,
+    // Only the authority that initialized the protocol can update the fees
+    pub authority: Pubkey,
 }
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
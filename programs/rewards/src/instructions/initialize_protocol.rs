@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::*;
+use crate::events::*;
+use crate::{initialize_metadata, Compliance, Fees, FreezeState, InitFeesArgs, InitTokenAccountArgs, TransferPolicy};
+
+use anchor_spl::{
+    token::{
+        Mint,
+        Token,
+        TokenAccount,
+    },
+    token_interface::{
+        Mint as Mint2022,
+        TokenInterface,
+    },
+};
+
+// Creates the mint, metadata, USDC vault, fees, freeze state and compliance roles in
+// one instruction, so the mint never exists without a freeze state. Only the program's
+// upgrade authority can run it, so nobody can race the admin for these roles.
+pub fn _initialize_protocol(ctx: Context<InitProtocol>, args: InitProtocolArgs) -> Result<()> {
+    let authority = ctx.accounts.signer.key();
+
+    initialize_metadata(
+        &ctx.accounts.token_program2022,
+        &mut ctx.accounts.mint,
+        &ctx.accounts.signer,
+        &ctx.accounts.system_program,
+        args.token.name,
+        args.token.symbol,
+        args.token.uri,
+    )?;
+
+    ctx.accounts
+        .transfer_policy
+        .initialize(authority, args.token.soulbound, ctx.bumps.transfer_policy);
+    ctx.accounts.fees.initialize(args.fees, authority);
+    ctx.accounts.freeze_state.initialize(authority);
+    ctx.accounts
+        .compliance
+        .initialize(authority, args.compliance_officer, ctx.bumps.compliance);
+
+    emit!(ProtocolInitializedEvent {
+        authority,
+        mint: ctx.accounts.mint.key(),
+        compliance_officer: args.compliance_officer,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(args: InitProtocolArgs)]
+pub struct InitProtocol<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [TOKEN_2022_SEED],
+        bump,
+        mint::decimals = args.token.decimals,
+        mint::authority = signer,
+        mint::freeze_authority = freeze_authority,
+        mint::token_program = token_program2022,
+        extensions::metadata_pointer::authority = signer,
+        extensions::metadata_pointer::metadata_address = mint,
+        extensions::transfer_hook::authority = signer,
+        extensions::transfer_hook::program_id = args.token.transfer_hook_program_id,
+        extensions::permanent_delegate::delegate = permanent_delegate,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint2022>>,
+
+    /// CHECK: PDA set as the mint's permanent delegate, used to burn expired points
+    #[account(
+        seeds = [PERMANENT_DELEGATE_SEED],
+        bump,
+    )]
+    pub permanent_delegate: UncheckedAccount<'info>,
+
+    /// CHECK: PDA set as the mint's freeze authority, used by the compliance officer
+    #[account(
+        seeds = [FREEZE_AUTHORITY_SEED],
+        bump,
+    )]
+    pub freeze_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [TRANSFER_POLICY_SEED],
+        bump,
+        space = DISCRIMINATOR + TransferPolicy::INIT_SPACE,
+    )]
+    pub transfer_policy: Box<Account<'info, TransferPolicy>>,
+
+    #[account(
+        address = USDC_MINT_ADDRESS,
+        mint::token_program = token_program,
+    )]
+    pub usdc_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        seeds = [USDC_SEED],
+        bump,
+        payer = signer,
+        token::mint = usdc_mint,
+        token::authority = signer,
+        token::token_program = token_program,
+    )]
+    pub usdc_keeper: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [FEES_SEED],
+        bump,
+        space = DISCRIMINATOR + Fees::INIT_SPACE,
+    )]
+    pub fees: Box<Account<'info, Fees>>,
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [FREEZE_SEED],
+        bump,
+        space = DISCRIMINATOR + FreezeState::INIT_SPACE,
+    )]
+    pub freeze_state: Box<Account<'info, FreezeState>>,
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [COMPLIANCE_SEED],
+        bump,
+        space = DISCRIMINATOR + Compliance::INIT_SPACE,
+    )]
+    pub compliance: Box<Account<'info, Compliance>>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, crate::program::Rewards>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(signer.key()) @ RewardTokenError::Unauthorized,
+    )]
+    pub program_data: Box<Account<'info, ProgramData>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub token_program2022: Interface<'info, TokenInterface>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct InitProtocolArgs {
+    pub token: InitTokenAccountArgs,
+    pub fees: InitFeesArgs,
+    pub compliance_officer: Pubkey,
+}
@@ -0,0 +1,483 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::*;
+use crate::events::*;
+use crate::utils::*;
+use crate::{check_freeze_state, consume_burned_lots, FreezeState};
+
+use anchor_spl::{
+    token::{Mint, Token, TokenAccount},
+    token_interface::{
+        self,
+        Burn,
+        Mint as Mint2022,
+        TokenAccount as TokenAccount2022,
+        TokenInterface,
+    },
+};
+use transfer_hook::{program::TransferHook, LOT_LEDGER_SEED};
+
+// Only the USDC vault authority can register merchants, since it is also the one
+// settling their redemptions out of the vault.
+pub fn _register_merchant(ctx: Context<RegisterMerchant>, args: RegisterMerchantArgs) -> Result<()> {
+    require!(args.earn_rate_bps <= 10000, RewardTokenError::BpsOutOfRange);
+
+    let merchant = &mut ctx.accounts.merchant;
+    merchant.authority = ctx.accounts.merchant_authority.key();
+    merchant.earn_rate_bps = args.earn_rate_bps;
+    merchant.settlement_account = ctx.accounts.settlement_account.key();
+    merchant.active = true;
+    merchant.budget = 0;
+    merchant.total_awarded = 0;
+    merchant.total_redeemed = 0;
+    merchant.pending_settlement_usdc = 0;
+    merchant.total_settled_usdc = 0;
+    merchant.bump = ctx.bumps.merchant;
+
+    emit!(MerchantRegisteredEvent {
+        merchant: merchant.key(),
+        authority: merchant.authority,
+        earn_rate_bps: merchant.earn_rate_bps,
+        settlement_account: merchant.settlement_account,
+    });
+
+    Ok(())
+}
+
+pub fn _update_merchant(ctx: Context<UpdateMerchant>, args: UpdateMerchantArgs) -> Result<()> {
+    let merchant = &mut ctx.accounts.merchant;
+
+    if let Some(earn_rate_bps) = args.earn_rate_bps {
+        require!(earn_rate_bps <= 10000, RewardTokenError::BpsOutOfRange);
+        merchant.earn_rate_bps = earn_rate_bps;
+    }
+    if let Some(active) = args.active {
+        merchant.active = active;
+    }
+
+    emit!(MerchantUpdatedEvent {
+        merchant: merchant.key(),
+        earn_rate_bps: merchant.earn_rate_bps,
+        active: merchant.active,
+    });
+
+    Ok(())
+}
+
+// Remaining accounts: transfer hook extra accounts for the deposit into the budget vault
+pub fn _fund_merchant_budget<'info>(
+    ctx: Context<'_, '_, '_, 'info, FundMerchantBudget<'info>>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, RewardTokenError::InvalidAmount);
+
+    transfer_checked_with_hook(
+        ctx.accounts.token_program2022.key,
+        ctx.accounts.funder_ata.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.budget_vault.to_account_info(),
+        ctx.accounts.funder.to_account_info(),
+        ctx.remaining_accounts,
+        amount,
+        ctx.accounts.mint.decimals,
+        &[],
+    )?;
+
+    let merchant = &mut ctx.accounts.merchant;
+    merchant.budget = merchant.budget.checked_add(amount).ok_or(RewardTokenError::MathOverflow)?;
+
+    emit!(MerchantFundedEvent {
+        merchant: merchant.key(),
+        funder: ctx.accounts.funder.key(),
+        amount,
+        budget: merchant.budget,
+    });
+
+    Ok(())
+}
+
+// Remaining accounts: transfer hook extra accounts for the payout from the budget vault.
+// `purchase_amount` is denominated in RWD base units and scaled by the earn rate.
+pub fn _award_points<'info>(
+    ctx: Context<'_, '_, '_, 'info, AwardPoints<'info>>,
+    purchase_amount: u64,
+) -> Result<()> {
+    let merchant = &ctx.accounts.merchant;
+    require!(merchant.active, RewardTokenError::MerchantInactive);
+
+    let amount = (purchase_amount as u128 * merchant.earn_rate_bps as u128 / 10000) as u64;
+    require!(amount > 0, RewardTokenError::InvalidAmount);
+    require!(amount <= merchant.budget, RewardTokenError::MerchantBudgetExceeded);
+
+    let signer_seeds: &[&[&[u8]]] = &[&[MERCHANT_SEED, merchant.authority.as_ref(), &[merchant.bump]]];
+
+    transfer_checked_with_hook(
+        ctx.accounts.token_program2022.key,
+        ctx.accounts.budget_vault.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.customer_ata.to_account_info(),
+        merchant.to_account_info(),
+        ctx.remaining_accounts,
+        amount,
+        ctx.accounts.mint.decimals,
+        signer_seeds,
+    )?;
+
+    let merchant = &mut ctx.accounts.merchant;
+    merchant.budget = merchant.budget.checked_sub(amount).ok_or(RewardTokenError::MerchantBudgetExceeded)?;
+    merchant.total_awarded = merchant.total_awarded.checked_add(amount).ok_or(RewardTokenError::MathOverflow)?;
+
+    emit!(PointsAwardedEvent {
+        merchant: merchant.key(),
+        customer: ctx.accounts.customer_ata.owner,
+        purchase_amount,
+        amount,
+        budget: merchant.budget,
+    });
+
+    Ok(())
+}
+
+// Burns the customer's RWD and books the USDC equivalent for the merchant; the vault
+// authority pays it out with `settle_merchant`. Only whole USDC amounts can be
+// redeemed, so no RWD is burned without being paid for.
+pub fn _redeem_at_merchant(ctx: Context<RedeemAtMerchant>, amount: u64) -> Result<()> {
+    check_freeze_state(&ctx.accounts.freeze_state, "burn")?;
+    require!(ctx.accounts.merchant.active, RewardTokenError::MerchantInactive);
+    require!(amount.is_multiple_of(RWD_PER_USDC), RewardTokenError::InvalidRedemptionAmount);
+
+    let usdc_amount = amount / RWD_PER_USDC;
+    require!(usdc_amount > 0, RewardTokenError::InvalidAmount);
+
+    let cpi_accounts = Burn {
+        authority: ctx.accounts.customer.to_account_info(),
+        from: ctx.accounts.customer_ata.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program2022.to_account_info();
+    token_interface::burn(CpiContext::new(cpi_program, cpi_accounts), amount)?;
+
+    consume_burned_lots(
+        ctx.accounts.points_expiry.to_account_info(),
+        ctx.bumps.points_expiry,
+        ctx.accounts.lot_ledger.to_account_info(),
+        ctx.accounts.transfer_hook_program.to_account_info(),
+        amount,
+    )?;
+
+    let merchant = &mut ctx.accounts.merchant;
+    merchant.total_redeemed = merchant.total_redeemed.checked_add(amount).ok_or(RewardTokenError::MathOverflow)?;
+    merchant.pending_settlement_usdc = merchant
+        .pending_settlement_usdc
+        .checked_add(usdc_amount)
+        .ok_or(RewardTokenError::MathOverflow)?;
+
+    emit!(PointsRedeemedEvent {
+        merchant: merchant.key(),
+        customer: ctx.accounts.customer.key(),
+        amount,
+        usdc_amount,
+    });
+
+    Ok(())
+}
+
+// Pays out as much of the pending settlement as the vault holds; the rest stays
+// pending for a later settlement.
+pub fn _settle_merchant(ctx: Context<SettleMerchant>) -> Result<()> {
+    let pending = ctx.accounts.merchant.pending_settlement_usdc;
+    require!(pending > 0, RewardTokenError::NothingToClaim);
+    let usdc_amount = pending.min(ctx.accounts.usdc_keeper.amount);
+    require!(usdc_amount > 0, RewardTokenError::InsufficientBalance);
+
+    let cpi_accounts = token_interface::TransferChecked {
+        mint: ctx.accounts.usdc_mint.to_account_info(),
+        from: ctx.accounts.usdc_keeper.to_account_info(),
+        to: ctx.accounts.settlement_account.to_account_info(),
+        authority: ctx.accounts.signer.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    token_interface::transfer_checked(CpiContext::new(cpi_program, cpi_accounts), usdc_amount, USDC_DECIMALS)?;
+
+    let merchant = &mut ctx.accounts.merchant;
+    merchant.pending_settlement_usdc = pending - usdc_amount;
+    merchant.total_settled_usdc = merchant
+        .total_settled_usdc
+        .checked_add(usdc_amount)
+        .ok_or(RewardTokenError::MathOverflow)?;
+
+    emit!(MerchantSettledEvent {
+        merchant: merchant.key(),
+        usdc_amount,
+        total_settled_usdc: merchant.total_settled_usdc,
+        pending_settlement_usdc: merchant.pending_settlement_usdc,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RegisterMerchant<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK: only recorded as the merchant's authority
+    pub merchant_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [MERCHANT_SEED, merchant_authority.key().as_ref()],
+        bump,
+        space = DISCRIMINATOR + Merchant::INIT_SPACE,
+    )]
+    pub merchant: Account<'info, Merchant>,
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [MERCHANT_BUDGET_SEED, merchant.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = merchant,
+        token::token_program = token_program2022,
+    )]
+    pub budget_vault: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    #[account(
+        seeds = [TOKEN_2022_SEED],
+        bump,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint2022>>,
+
+    #[account(
+        address = USDC_MINT_ADDRESS,
+        mint::token_program = token_program,
+    )]
+    pub usdc_mint: Account<'info, Mint>,
+
+    #[account(
+        seeds = [USDC_SEED],
+        bump,
+        token::authority = signer,
+        token::token_program = token_program,
+    )]
+    pub usdc_keeper: Account<'info, TokenAccount>,
+
+    #[account(
+        token::mint = usdc_mint,
+        token::token_program = token_program,
+    )]
+    pub settlement_account: Account<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub token_program2022: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateMerchant<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [MERCHANT_SEED, merchant.authority.as_ref()],
+        bump = merchant.bump,
+    )]
+    pub merchant: Account<'info, Merchant>,
+
+    #[account(
+        seeds = [USDC_SEED],
+        bump,
+        token::authority = signer,
+        token::token_program = token_program,
+    )]
+    pub usdc_keeper: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct FundMerchantBudget<'info> {
+    pub funder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [MERCHANT_SEED, merchant.authority.as_ref()],
+        bump = merchant.bump,
+    )]
+    pub merchant: Account<'info, Merchant>,
+
+    #[account(
+        seeds = [TOKEN_2022_SEED],
+        bump,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint2022>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = funder,
+        token::token_program = token_program2022,
+    )]
+    pub funder_ata: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    #[account(
+        mut,
+        seeds = [MERCHANT_BUDGET_SEED, merchant.key().as_ref()],
+        bump,
+    )]
+    pub budget_vault: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    pub token_program2022: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct AwardPoints<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority @ RewardTokenError::Unauthorized,
+        seeds = [MERCHANT_SEED, authority.key().as_ref()],
+        bump = merchant.bump,
+    )]
+    pub merchant: Account<'info, Merchant>,
+
+    #[account(
+        seeds = [TOKEN_2022_SEED],
+        bump,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint2022>>,
+
+    #[account(
+        mut,
+        seeds = [MERCHANT_BUDGET_SEED, merchant.key().as_ref()],
+        bump,
+    )]
+    pub budget_vault: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program2022,
+    )]
+    pub customer_ata: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    pub token_program2022: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct RedeemAtMerchant<'info> {
+    pub customer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [MERCHANT_SEED, merchant.authority.as_ref()],
+        bump = merchant.bump,
+    )]
+    pub merchant: Account<'info, Merchant>,
+
+    #[account(
+        mut,
+        seeds = [TOKEN_2022_SEED],
+        bump,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint2022>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = customer,
+        token::token_program = token_program2022,
+    )]
+    pub customer_ata: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    #[account(
+        seeds = [FREEZE_SEED],
+        bump,
+    )]
+    pub freeze_state: Account<'info, FreezeState>,
+
+    /// CHECK: PDA the transfer hook accepts as the issuer of point lots
+    #[account(
+        seeds = [POINTS_EXPIRY_SEED],
+        bump,
+    )]
+    pub points_expiry: UncheckedAccount<'info>,
+
+    /// CHECK: burned owner's lot ledger, kept by the transfer hook program; may not exist
+    #[account(
+        mut,
+        seeds = [LOT_LEDGER_SEED, customer.key().as_ref()],
+        bump,
+        seeds::program = transfer_hook_program.key(),
+    )]
+    pub lot_ledger: UncheckedAccount<'info>,
+
+    pub transfer_hook_program: Program<'info, TransferHook>,
+
+    pub token_program2022: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct SettleMerchant<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [MERCHANT_SEED, merchant.authority.as_ref()],
+        bump = merchant.bump,
+    )]
+    pub merchant: Account<'info, Merchant>,
+
+    #[account(
+        address = USDC_MINT_ADDRESS,
+        mint::token_program = token_program,
+    )]
+    pub usdc_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [USDC_SEED],
+        bump,
+        token::mint = usdc_mint,
+        token::authority = signer,
+        token::token_program = token_program,
+    )]
+    pub usdc_keeper: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        address = merchant.settlement_account,
+    )]
+    pub settlement_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Merchant {
+    pub authority: Pubkey,
+    pub earn_rate_bps: u16,
+    pub settlement_account: Pubkey,
+    pub active: bool,
+    pub budget: u64,
+    pub total_awarded: u64,
+    pub total_redeemed: u64,
+    pub pending_settlement_usdc: u64,
+    pub total_settled_usdc: u64,
+    pub bump: u8,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct RegisterMerchantArgs {
+    pub earn_rate_bps: u16,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct UpdateMerchantArgs {
+    pub earn_rate_bps: Option<u16>,
+    pub active: Option<bool>,
+}
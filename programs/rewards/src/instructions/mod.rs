@@ -1,12 +1,34 @@
 pub mod initialize_token;
+pub mod initialize_protocol;
 pub mod mint_tokens;
 pub mod burn_tokens;
 pub mod fees;
 pub mod toggle_freeze;
+pub mod staking;
+pub mod airdrop;
+pub mod vesting;
+pub mod merchant;
+pub mod points_expiry;
+pub mod transfer_policy;
+pub mod update_metadata;
+pub mod compliance;
+pub mod clawback;
+pub mod transfer_limits;
 
 
 pub use initialize_token::*;
+pub use initialize_protocol::*;
 pub use toggle_freeze::*;
 pub use mint_tokens::*;
 pub use burn_tokens::*;
-pub use fees::*;
\ No newline at end of file
+pub use fees::*;
+pub use staking::*;
+pub use airdrop::*;
+pub use vesting::*;
+pub use merchant::*;
+pub use points_expiry::*;
+pub use transfer_policy::*;
+pub use update_metadata::*;
+pub use compliance::*;
+pub use clawback::*;
+pub use transfer_limits::*;
\ No newline at end of file
@@ -0,0 +1,316 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use crate::constants::*;
+use crate::error::*;
+use crate::events::*;
+use crate::{check_freeze_state, FreezeState};
+
+use anchor_spl::token_interface::{
+    self,
+    Burn,
+    Mint as Mint2022,
+    MintTo,
+    TokenAccount as TokenAccount2022,
+    TokenInterface,
+};
+use transfer_hook::{
+    cpi::accounts::{ConsumeLots, ExpireLots, RecordLot},
+    program::TransferHook,
+    LotLedger,
+    LOT_LEDGER_SEED,
+};
+
+pub fn _initialize_points_expiry(ctx: Context<InitPointsExpiry>, args: InitPointsExpiryArgs) -> Result<()> {
+    require!(args.expiry_period > 0, RewardTokenError::InvalidExpiryPeriod);
+
+    let expiry_config = &mut ctx.accounts.expiry_config;
+    expiry_config.authority = ctx.accounts.signer.key();
+    expiry_config.enabled = args.enabled;
+    expiry_config.expiry_period = args.expiry_period;
+    expiry_config.bump = ctx.bumps.expiry_config;
+
+    emit!(PointsExpiryUpdatedEvent {
+        enabled: expiry_config.enabled,
+        expiry_period: expiry_config.expiry_period,
+    });
+
+    Ok(())
+}
+
+pub fn _update_points_expiry(ctx: Context<UpdatePointsExpiry>, args: UpdatePointsExpiryArgs) -> Result<()> {
+    let expiry_config = &mut ctx.accounts.expiry_config;
+
+    if let Some(enabled) = args.enabled {
+        expiry_config.enabled = enabled;
+    }
+    if let Some(expiry_period) = args.expiry_period {
+        require!(expiry_period > 0, RewardTokenError::InvalidExpiryPeriod);
+        expiry_config.expiry_period = expiry_period;
+    }
+
+    emit!(PointsExpiryUpdatedEvent {
+        enabled: expiry_config.enabled,
+        expiry_period: expiry_config.expiry_period,
+    });
+
+    Ok(())
+}
+
+// Mints points to `recipient` and, while expiry is enabled, records them as a lot in
+// the recipient's ledger kept by the transfer hook. The signer must be the mint authority.
+pub fn _issue_points(ctx: Context<IssuePoints>, amount: u64) -> Result<()> {
+    check_freeze_state(&ctx.accounts.freeze_state, "mint")?;
+    require!(amount > 0, RewardTokenError::InvalidAmount);
+
+    let cpi_accounts = MintTo {
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.recipient_ata.to_account_info(),
+        authority: ctx.accounts.signer.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program2022.to_account_info();
+    token_interface::mint_to(CpiContext::new(cpi_program, cpi_accounts), amount)?;
+
+    let expiry_config = &ctx.accounts.expiry_config;
+    let issued_at = Clock::get()?.unix_timestamp;
+
+    if expiry_config.enabled {
+        let signer_seeds: &[&[&[u8]]] = &[&[POINTS_EXPIRY_SEED, &[expiry_config.bump]]];
+        let cpi_accounts = RecordLot {
+            issuer: expiry_config.to_account_info(),
+            payer: ctx.accounts.signer.to_account_info(),
+            owner: ctx.accounts.recipient.to_account_info(),
+            lot_ledger: ctx.accounts.lot_ledger.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.transfer_hook_program.to_account_info();
+        transfer_hook::cpi::record_lot(
+            CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds),
+            amount,
+            issued_at,
+        )?;
+    }
+
+    emit!(PointsIssuedEvent {
+        recipient: ctx.accounts.recipient.key(),
+        amount,
+        issued_at,
+        expires: expiry_config.enabled,
+    });
+
+    Ok(())
+}
+
+// Permissionless crank: burns the owner's lots older than the expiry period from
+// `owner_ata` through the permanent delegate. Points already spent or burned
+// elsewhere cannot be burned twice, so the burn is capped at the balance.
+pub fn _expire_points(ctx: Context<ExpirePoints>) -> Result<()> {
+    let expiry_config = &ctx.accounts.expiry_config;
+    require!(expiry_config.enabled, RewardTokenError::OperationNotAllowed);
+
+    let cutoff_ts = Clock::get()?.unix_timestamp - expiry_config.expiry_period;
+    let expired_amount = ctx.accounts.lot_ledger.expired_amount(cutoff_ts);
+    require!(expired_amount > 0, RewardTokenError::NothingToClaim);
+
+    let signer_seeds: &[&[&[u8]]] = &[&[POINTS_EXPIRY_SEED, &[expiry_config.bump]]];
+    let cpi_accounts = ExpireLots {
+        issuer: expiry_config.to_account_info(),
+        lot_ledger: ctx.accounts.lot_ledger.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.transfer_hook_program.to_account_info();
+    transfer_hook::cpi::expire_lots(CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds), cutoff_ts)?;
+
+    let burned_amount = expired_amount.min(ctx.accounts.owner_ata.amount);
+    if burned_amount > 0 {
+        let signer_seeds: &[&[&[u8]]] = &[&[PERMANENT_DELEGATE_SEED, &[ctx.bumps.permanent_delegate]]];
+        let cpi_accounts = Burn {
+            authority: ctx.accounts.permanent_delegate.to_account_info(),
+            from: ctx.accounts.owner_ata.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program2022.to_account_info();
+        token_interface::burn(CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds), burned_amount)?;
+    }
+
+    emit!(PointsExpiredEvent {
+        owner: ctx.accounts.owner_ata.owner,
+        token_account: ctx.accounts.owner_ata.key(),
+        expired_amount,
+        burned_amount,
+    });
+
+    Ok(())
+}
+
+// Burns bypass the transfer hook, so every burn path reports the burned amount to the
+// owner's lot ledger; otherwise expiry would later burn points that were never lots.
+// Wallets that never received expiring points have no ledger and are skipped.
+pub fn consume_burned_lots<'info>(
+    points_expiry: AccountInfo<'info>,
+    points_expiry_bump: u8,
+    lot_ledger: AccountInfo<'info>,
+    transfer_hook_program: AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if lot_ledger.data_is_empty() {
+        return Ok(());
+    }
+
+    let signer_seeds: &[&[&[u8]]] = &[&[POINTS_EXPIRY_SEED, &[points_expiry_bump]]];
+    let cpi_accounts = ConsumeLots {
+        issuer: points_expiry,
+        lot_ledger,
+    };
+    transfer_hook::cpi::consume_lots(
+        CpiContext::new_with_signer(transfer_hook_program, cpi_accounts, signer_seeds),
+        amount,
+    )
+}
+
+#[derive(Accounts)]
+pub struct InitPointsExpiry<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [TOKEN_2022_SEED],
+        bump,
+        constraint = mint.mint_authority == COption::Some(signer.key()) @ RewardTokenError::Unauthorized,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint2022>>,
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [POINTS_EXPIRY_SEED],
+        bump,
+        space = DISCRIMINATOR + PointsExpiryConfig::INIT_SPACE,
+    )]
+    pub expiry_config: Account<'info, PointsExpiryConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePointsExpiry<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority @ RewardTokenError::Unauthorized,
+        seeds = [POINTS_EXPIRY_SEED],
+        bump = expiry_config.bump,
+    )]
+    pub expiry_config: Account<'info, PointsExpiryConfig>,
+}
+
+#[derive(Accounts)]
+pub struct IssuePoints<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [POINTS_EXPIRY_SEED],
+        bump = expiry_config.bump,
+    )]
+    pub expiry_config: Account<'info, PointsExpiryConfig>,
+
+    #[account(
+        mut,
+        seeds = [TOKEN_2022_SEED],
+        bump,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint2022>>,
+
+    /// CHECK: wallet receiving the points
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = recipient,
+        token::token_program = token_program2022,
+    )]
+    pub recipient_ata: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    /// CHECK: recipient's lot ledger, created and validated by the transfer hook program
+    #[account(
+        mut,
+        seeds = [LOT_LEDGER_SEED, recipient.key().as_ref()],
+        bump,
+        seeds::program = transfer_hook_program.key(),
+    )]
+    pub lot_ledger: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [FREEZE_SEED],
+        bump,
+    )]
+    pub freeze_state: Account<'info, FreezeState>,
+
+    pub transfer_hook_program: Program<'info, TransferHook>,
+    pub system_program: Program<'info, System>,
+    pub token_program2022: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct ExpirePoints<'info> {
+    #[account(
+        seeds = [POINTS_EXPIRY_SEED],
+        bump = expiry_config.bump,
+    )]
+    pub expiry_config: Account<'info, PointsExpiryConfig>,
+
+    /// CHECK: PDA holding the mint's permanent delegate role
+    #[account(
+        seeds = [PERMANENT_DELEGATE_SEED],
+        bump,
+    )]
+    pub permanent_delegate: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [TOKEN_2022_SEED],
+        bump,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint2022>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = lot_ledger.owner,
+        token::token_program = token_program2022,
+    )]
+    pub owner_ata: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    #[account(
+        mut,
+        seeds = [LOT_LEDGER_SEED, lot_ledger.owner.as_ref()],
+        bump = lot_ledger.bump,
+        seeds::program = transfer_hook_program.key(),
+    )]
+    pub lot_ledger: Box<Account<'info, LotLedger>>,
+
+    pub transfer_hook_program: Program<'info, TransferHook>,
+    pub token_program2022: Interface<'info, TokenInterface>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PointsExpiryConfig {
+    pub authority: Pubkey,
+    pub enabled: bool,
+    pub expiry_period: i64,
+    pub bump: u8,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct InitPointsExpiryArgs {
+    pub enabled: bool,
+    pub expiry_period: i64,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct UpdatePointsExpiryArgs {
+    pub enabled: Option<bool>,
+    pub expiry_period: Option<i64>,
+}
@@ -0,0 +1,416 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use crate::constants::*;
+use crate::error::*;
+use crate::events::*;
+use crate::utils::*;
+
+use anchor_spl::token_interface::{
+    Mint as Mint2022,
+    TokenAccount as TokenAccount2022,
+    TokenInterface,
+};
+
+pub fn _initialize_reward_pool(ctx: Context<InitRewardPool>) -> Result<()> {
+    let reward_pool = &mut ctx.accounts.reward_pool;
+    reward_pool.total_staked = 0;
+    reward_pool.reward_per_token = 0;
+    reward_pool.undistributed = 0;
+    reward_pool.bump = ctx.bumps.reward_pool;
+
+    Ok(())
+}
+
+// Remaining accounts: transfer hook extra accounts for the funding transfer.
+// Meant to be called by the fee collector's owner to pass collected fees on to stakers.
+pub fn _fund_reward_pool<'info>(
+    ctx: Context<'_, '_, '_, 'info, FundRewardPool<'info>>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, RewardTokenError::InvalidAmount);
+
+    transfer_checked_with_hook(
+        ctx.accounts.token_program2022.key,
+        ctx.accounts.funder_ata.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.reward_vault.to_account_info(),
+        ctx.accounts.funder.to_account_info(),
+        ctx.remaining_accounts,
+        amount,
+        ctx.accounts.mint.decimals,
+        &[],
+    )?;
+
+    let reward_pool = &mut ctx.accounts.reward_pool;
+    reward_pool.distribute(amount);
+
+    emit!(RewardPoolFundedEvent {
+        funder: ctx.accounts.funder.key(),
+        amount,
+        total_staked: reward_pool.total_staked,
+        reward_per_token: reward_pool.reward_per_token,
+    });
+
+    Ok(())
+}
+
+// Remaining accounts: transfer hook extra accounts for the deposit into the stake vault
+pub fn _stake<'info>(ctx: Context<'_, '_, '_, 'info, Stake<'info>>, amount: u64) -> Result<()> {
+    require!(amount > 0, RewardTokenError::InvalidAmount);
+
+    transfer_checked_with_hook(
+        ctx.accounts.token_program2022.key,
+        ctx.accounts.owner_ata.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.stake_vault.to_account_info(),
+        ctx.accounts.owner.to_account_info(),
+        ctx.remaining_accounts,
+        amount,
+        ctx.accounts.mint.decimals,
+        &[],
+    )?;
+
+    let reward_pool = &mut ctx.accounts.reward_pool;
+    let stake_account = &mut ctx.accounts.stake_account;
+    if stake_account.owner == Pubkey::default() {
+        stake_account.owner = ctx.accounts.owner.key();
+        stake_account.bump = ctx.bumps.stake_account;
+    }
+
+    stake_account.accrue(reward_pool.reward_per_token);
+    stake_account.amount += amount;
+    reward_pool.total_staked += amount;
+
+    emit!(StakedEvent {
+        owner: stake_account.owner,
+        amount,
+        staked_amount: stake_account.amount,
+        total_staked: reward_pool.total_staked,
+    });
+
+    Ok(())
+}
+
+// Remaining accounts: transfer hook extra accounts for the withdrawal from the stake vault
+pub fn _unstake<'info>(ctx: Context<'_, '_, '_, 'info, Unstake<'info>>, amount: u64) -> Result<()> {
+    require!(amount > 0, RewardTokenError::InvalidAmount);
+    require!(amount <= ctx.accounts.stake_account.amount, RewardTokenError::InsufficientStake);
+
+    let reward_pool = &ctx.accounts.reward_pool;
+    let signer_seeds: &[&[&[u8]]] = &[&[REWARD_POOL_SEED, &[reward_pool.bump]]];
+
+    transfer_checked_with_hook(
+        ctx.accounts.token_program2022.key,
+        ctx.accounts.stake_vault.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.owner_ata.to_account_info(),
+        reward_pool.to_account_info(),
+        ctx.remaining_accounts,
+        amount,
+        ctx.accounts.mint.decimals,
+        signer_seeds,
+    )?;
+
+    let reward_pool = &mut ctx.accounts.reward_pool;
+    let stake_account = &mut ctx.accounts.stake_account;
+    stake_account.accrue(reward_pool.reward_per_token);
+    stake_account.amount -= amount;
+    reward_pool.total_staked -= amount;
+
+    emit!(UnstakedEvent {
+        owner: stake_account.owner,
+        amount,
+        staked_amount: stake_account.amount,
+        total_staked: reward_pool.total_staked,
+    });
+
+    Ok(())
+}
+
+// Remaining accounts: transfer hook extra accounts for the payout from the reward vault
+pub fn _claim_rewards<'info>(ctx: Context<'_, '_, '_, 'info, ClaimRewards<'info>>) -> Result<()> {
+    let reward_per_token = ctx.accounts.reward_pool.reward_per_token;
+    let stake_account = &mut ctx.accounts.stake_account;
+    stake_account.accrue(reward_per_token);
+
+    let amount = stake_account.pending_rewards;
+    require!(amount > 0, RewardTokenError::NothingToClaim);
+    stake_account.pending_rewards = 0;
+
+    let reward_pool = &ctx.accounts.reward_pool;
+    let signer_seeds: &[&[&[u8]]] = &[&[REWARD_POOL_SEED, &[reward_pool.bump]]];
+
+    transfer_checked_with_hook(
+        ctx.accounts.token_program2022.key,
+        ctx.accounts.reward_vault.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.owner_ata.to_account_info(),
+        reward_pool.to_account_info(),
+        ctx.remaining_accounts,
+        amount,
+        ctx.accounts.mint.decimals,
+        signer_seeds,
+    )?;
+
+    emit!(RewardsClaimedEvent {
+        owner: ctx.accounts.owner.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+impl RewardPool {
+    /// Spreads `amount` over the current stakers. Funding that arrives while nothing is
+    /// staked is held back and added to the next distribution.
+    pub fn distribute(&mut self, amount: u64) {
+        if self.total_staked == 0 {
+            self.undistributed += amount;
+            return;
+        }
+
+        let amount = amount + self.undistributed;
+        self.undistributed = 0;
+        self.reward_per_token += amount as u128 * REWARD_PRECISION / self.total_staked as u128;
+    }
+}
+
+impl StakeAccount {
+    /// Books rewards earned since the last update at the pool's current accumulator.
+    pub fn accrue(&mut self, reward_per_token: u128) {
+        let earned = self.amount as u128 * (reward_per_token - self.reward_per_token_paid) / REWARD_PRECISION;
+        self.pending_rewards += earned as u64;
+        self.reward_per_token_paid = reward_per_token;
+    }
+}
+
+#[derive(Accounts)]
+pub struct InitRewardPool<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [TOKEN_2022_SEED],
+        bump,
+        constraint = mint.mint_authority == COption::Some(signer.key()) @ RewardTokenError::Unauthorized,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint2022>>,
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [REWARD_POOL_SEED],
+        bump,
+        space = DISCRIMINATOR + RewardPool::INIT_SPACE,
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [STAKE_VAULT_SEED],
+        bump,
+        token::mint = mint,
+        token::authority = reward_pool,
+        token::token_program = token_program2022,
+    )]
+    pub stake_vault: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [REWARD_VAULT_SEED],
+        bump,
+        token::mint = mint,
+        token::authority = reward_pool,
+        token::token_program = token_program2022,
+    )]
+    pub reward_vault: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program2022: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct FundRewardPool<'info> {
+    pub funder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [REWARD_POOL_SEED],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(
+        seeds = [TOKEN_2022_SEED],
+        bump,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint2022>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = funder,
+        token::token_program = token_program2022,
+    )]
+    pub funder_ata: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    #[account(
+        mut,
+        seeds = [REWARD_VAULT_SEED],
+        bump,
+    )]
+    pub reward_vault: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    pub token_program2022: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct Stake<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [REWARD_POOL_SEED],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [STAKE_SEED, owner.key().as_ref()],
+        bump,
+        space = DISCRIMINATOR + StakeAccount::INIT_SPACE,
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    #[account(
+        seeds = [TOKEN_2022_SEED],
+        bump,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint2022>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = owner,
+        token::token_program = token_program2022,
+    )]
+    pub owner_ata: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    #[account(
+        mut,
+        seeds = [STAKE_VAULT_SEED],
+        bump,
+    )]
+    pub stake_vault: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program2022: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct Unstake<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [REWARD_POOL_SEED],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(
+        mut,
+        seeds = [STAKE_SEED, owner.key().as_ref()],
+        bump = stake_account.bump,
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    #[account(
+        seeds = [TOKEN_2022_SEED],
+        bump,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint2022>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = owner,
+        token::token_program = token_program2022,
+    )]
+    pub owner_ata: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    #[account(
+        mut,
+        seeds = [STAKE_VAULT_SEED],
+        bump,
+    )]
+    pub stake_vault: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    pub token_program2022: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimRewards<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [REWARD_POOL_SEED],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(
+        mut,
+        seeds = [STAKE_SEED, owner.key().as_ref()],
+        bump = stake_account.bump,
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    #[account(
+        seeds = [TOKEN_2022_SEED],
+        bump,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint2022>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = owner,
+        token::token_program = token_program2022,
+    )]
+    pub owner_ata: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    #[account(
+        mut,
+        seeds = [REWARD_VAULT_SEED],
+        bump,
+    )]
+    pub reward_vault: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    pub token_program2022: Interface<'info, TokenInterface>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct RewardPool {
+    pub total_staked: u64,
+    // Rewards per staked base unit, scaled by REWARD_PRECISION
+    pub reward_per_token: u128,
+    pub undistributed: u64,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct StakeAccount {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub reward_per_token_paid: u128,
+    pub pending_rewards: u64,
+    pub bump: u8,
+}
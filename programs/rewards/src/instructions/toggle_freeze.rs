@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
 use crate::constants::*;
 use crate::error::*;
 use crate::events::*;
@@ -9,79 +10,220 @@ pub fn _initialize_freeze(ctx: Context<InitFreeze>)  -> Result<()> {
 
     // Set Admin as Freeze Authority 
     let freeze_state = &mut ctx.accounts.freeze_state;
-    if freeze_state.authority == Pubkey::default() {
-        freeze_state.authority = ctx.accounts.signer.key();
-    }
-
-    freeze_state.is_frozen = false;
-    freeze_state.freeze_mint = false;
-    freeze_state.freeze_burn = false;
+    freeze_state.initialize(ctx.accounts.signer.key());
 
     msg!("FreezeState initialized: {:?}", freeze_state);
     
     Ok(())
 }
 
-pub fn _toggle_freeze(ctx: Context<Freeze>, target: FreezeTarget, freeze: bool) -> Result<()> {
+// Any pauser can freeze; once pausers are configured, unfreezing goes through
+// `approve_unfreeze` instead of the authority alone. Freezes lapse after `duration`
+// seconds, or last until unfrozen when none is given. While a maximum pause duration
+// is set every freeze needs a duration, and refreezing can't push the lapse past the
+// cap counted from when the freeze started; only `extend_freeze` goes beyond it.
+pub fn _toggle_freeze(ctx: Context<Freeze>, target: FreezeTarget, freeze: bool, duration: Option<i64>) -> Result<()> {
     let freeze_state: &mut Account<'_, FreezeState> = &mut ctx.accounts.freeze_state;
+    let signer = ctx.accounts.signer.key();
 
-    if freeze_state.authority != ctx.accounts.signer.key() {
+    let allowed = if freeze {
+        freeze_state.authority == signer || freeze_state.pausers.contains(&signer)
+    } else {
+        require!(freeze_state.pausers.is_empty(), RewardTokenError::UnfreezeRequiresApprovals);
+        freeze_state.authority == signer
+    };
+    if !allowed {
         msg!("Only the authority can modify freeze state!");
         return Err(RewardTokenError::Unauthorized.into())
     }
 
-   match target {
-        FreezeTarget::All => {
-            freeze_state.is_frozen = freeze;  // Global freeze/unfreeze
-            freeze_state.freeze_mint = freeze; 
-            freeze_state.freeze_burn = freeze;
-            msg!(
-                "All operations are now {}.",
-                if freeze { "frozen" } else { "unfrozen" }
-            );
-        }
-        FreezeTarget::Mint => {
-            freeze_state.freeze_mint = freeze; 
-            msg!(
-                "Minting is now {}.",
-                if freeze { "frozen" } else { "unfrozen" }
-            );
-        }
-        FreezeTarget::Burn => {
-            freeze_state.freeze_burn = freeze; 
-            msg!(
-                "Burning is now {}.",
-                if freeze { "frozen" } else { "unfrozen" }
-            );
+    let frozen_until = if freeze {
+        let now = Clock::get()?.unix_timestamp;
+        let frozen_until = freeze_state.frozen_until(duration, now)?;
+        // Refreezing an active freeze can only extend it, and leaves the approvals
+        // collected to lift it in place; a new freeze invalidates earlier approvals
+        let (newly_frozen, frozen_until) = freeze_state.extend(target.clone(), frozen_until, now);
+        if newly_frozen {
+            freeze_state.clear_unfreeze_approvals();
         }
-    }
+        frozen_until
+    } else {
+        freeze_state.apply(target.clone(), false, 0);
+        0
+    };
 
     emit!(FreezeStateChangedEvent {
-        authority: ctx.accounts.signer.key(),
+        authority: signer,
         is_frozen: freeze,
         target,
+        frozen_until,
+    });
+
+    Ok(())
+}
+
+// First step of a freeze authority transfer; the new authority has to accept
+pub fn _propose_freeze_authority(ctx: Context<UpdateFreezeState>, new_authority: Pubkey) -> Result<()> {
+    let freeze_state = &mut ctx.accounts.freeze_state;
+    require_keys_eq!(freeze_state.authority, ctx.accounts.signer.key(), RewardTokenError::Unauthorized);
+
+    freeze_state.pending_authority = Some(new_authority);
+
+    emit!(FreezeAuthorityProposedEvent {
+        authority: freeze_state.authority,
+        pending_authority: new_authority,
+    });
+
+    Ok(())
+}
+
+pub fn _accept_freeze_authority(ctx: Context<UpdateFreezeState>) -> Result<()> {
+    let freeze_state = &mut ctx.accounts.freeze_state;
+    let signer = ctx.accounts.signer.key();
+    require!(freeze_state.pending_authority == Some(signer), RewardTokenError::Unauthorized);
+
+    let previous_authority = freeze_state.authority;
+    freeze_state.authority = signer;
+    freeze_state.pending_authority = None;
+
+    emit!(FreezeAuthorityTransferredEvent {
+        previous_authority,
+        new_authority: signer,
+    });
+
+    Ok(())
+}
+
+// An empty set removes the pausers and gives unfreezing back to the authority
+pub fn _set_pausers(ctx: Context<UpdateFreezeState>, pausers: Vec<Pubkey>, unfreeze_threshold: u8) -> Result<()> {
+    let freeze_state = &mut ctx.accounts.freeze_state;
+    require_keys_eq!(freeze_state.authority, ctx.accounts.signer.key(), RewardTokenError::Unauthorized);
+    require!(pausers.len() <= MAX_PAUSERS, RewardTokenError::InvalidPauserSet);
+    require!(
+        pausers.iter().enumerate().all(|(index, pauser)| !pausers[..index].contains(pauser)),
+        RewardTokenError::InvalidPauserSet
+    );
+    let valid_threshold = if pausers.is_empty() {
+        unfreeze_threshold == 0
+    } else {
+        unfreeze_threshold > 0 && unfreeze_threshold as usize <= pausers.len()
+    };
+    require!(valid_threshold, RewardTokenError::InvalidPauserSet);
+
+    freeze_state.pausers = pausers.clone();
+    freeze_state.unfreeze_threshold = unfreeze_threshold;
+    freeze_state.clear_unfreeze_approvals();
+
+    emit!(PausersUpdatedEvent {
+        pausers,
+        unfreeze_threshold,
     });
 
     Ok(())
 }
 
+// Approvals count towards one target at a time; approving a different target starts over
+pub fn _approve_unfreeze(ctx: Context<UpdateFreezeState>, target: FreezeTarget) -> Result<()> {
+    let freeze_state = &mut ctx.accounts.freeze_state;
+    let signer = ctx.accounts.signer.key();
+    require!(freeze_state.pausers.contains(&signer), RewardTokenError::Unauthorized);
+
+    if freeze_state.unfreeze_target.as_ref() != Some(&target) {
+        freeze_state.clear_unfreeze_approvals();
+        freeze_state.unfreeze_target = Some(target.clone());
+    }
+    require!(
+        !freeze_state.unfreeze_approvals.contains(&signer),
+        RewardTokenError::UnfreezeAlreadyApproved
+    );
+    freeze_state.unfreeze_approvals.push(signer);
+
+    emit!(UnfreezeApprovedEvent {
+        pauser: signer,
+        target: target.clone(),
+        approvals: freeze_state.unfreeze_approvals.len() as u8,
+        unfreeze_threshold: freeze_state.unfreeze_threshold,
+    });
+
+    if freeze_state.unfreeze_approvals.len() >= freeze_state.unfreeze_threshold as usize {
+        freeze_state.clear_unfreeze_approvals();
+        freeze_state.apply(target.clone(), false, 0);
+
+        emit!(FreezeStateChangedEvent {
+            authority: signer,
+            is_frozen: false,
+            target,
+            frozen_until: 0,
+        });
+    }
+
+    Ok(())
+}
+
+// Governance extension of an active timed freeze past the maximum pause duration.
+// Only the freeze authority can extend, by at most the cap per call, so every push
+// past the cap is an explicit decision rather than a refreeze.
+pub fn _extend_freeze(ctx: Context<UpdateFreezeState>, target: FreezeTarget, extension: i64) -> Result<()> {
+    let freeze_state = &mut ctx.accounts.freeze_state;
+    let signer = ctx.accounts.signer.key();
+    require_keys_eq!(freeze_state.authority, signer, RewardTokenError::Unauthorized);
+
+    let now = Clock::get()?.unix_timestamp;
+    let frozen_until = freeze_state.extend_lapse(target.clone(), extension, now)?;
+
+    emit!(FreezeExtendedEvent {
+        authority: signer,
+        target,
+        extension,
+        frozen_until,
+    });
+
+    Ok(())
+}
+
+// Caps how long any freeze lasts, counted from when it started. Zero removes the cap.
+pub fn _set_max_pause_duration(ctx: Context<UpdateFreezeState>, max_pause_duration: i64) -> Result<()> {
+    let freeze_state = &mut ctx.accounts.freeze_state;
+    require_keys_eq!(freeze_state.authority, ctx.accounts.signer.key(), RewardTokenError::Unauthorized);
+    require!(max_pause_duration >= 0, RewardTokenError::InvalidFreezeDuration);
+
+    freeze_state.max_pause_duration = max_pause_duration;
+
+    emit!(MaxPauseDurationUpdatedEvent { max_pause_duration });
+
+    Ok(())
+}
+
 pub fn check_freeze_state(freeze_state: &FreezeState, operation: &str) -> Result<()> {
+    check_freeze_state_at(freeze_state, operation, Clock::get()?.unix_timestamp)
+}
+
+// Timed freezes lapse on their own once `now` reaches their `frozen_until`
+pub fn check_freeze_state_at(freeze_state: &FreezeState, operation: &str, now: i64) -> Result<()> {
+    let active = |frozen: bool, frozen_until: i64| frozen && (frozen_until == 0 || now < frozen_until);
 
-    if freeze_state.is_frozen {
+    if active(freeze_state.is_frozen, freeze_state.frozen_until) {
         return Err(RewardTokenError::GlobalFrozen.into());
     }
 
     match operation {
-        "mint" if freeze_state.freeze_mint => Err(RewardTokenError::MintFrozen.into()),
-        "burn" if freeze_state.freeze_burn => Err(RewardTokenError::BurnFrozen.into()),
+        "mint" if active(freeze_state.freeze_mint, freeze_state.mint_frozen_until) => Err(RewardTokenError::MintFrozen.into()),
+        "burn" if active(freeze_state.freeze_burn, freeze_state.burn_frozen_until) => Err(RewardTokenError::BurnFrozen.into()),
         _ => Ok(()), // If no freeze, proceed normally
     }
 }
 
+// Only the mint authority can create the freeze state, so the first caller can't
+// make themselves freeze authority
 #[derive(Accounts)]
 pub struct InitFreeze<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
+    #[account(
+        seeds = [TOKEN_2022_SEED],
+        bump,
+        constraint = mint.mint_authority == COption::Some(signer.key()) @ RewardTokenError::Unauthorized,
+    )]
     pub mint: Box<InterfaceAccount<'info, Mint2022>>,
     #[account(
         init,
@@ -109,13 +251,26 @@ pub struct Freeze<'info> {
 }
 
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+#[derive(Accounts)]
+pub struct UpdateFreezeState<'info> {
+    #[account(
+        mut,
+        seeds = [FREEZE_SEED],
+        bump,
+    )]
+    pub freeze_state: Account<'info, FreezeState>,
+    pub signer: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Debug, PartialEq)]
 pub enum FreezeTarget {
     All,        
     Mint,       
     Burn,       
 }
 
+// The transfer hook parses this account to honour global freezes: keep the field
+// order in sync with `rewards_global_freeze` there.
 #[account]
 #[derive(InitSpace, Debug)]
 pub struct FreezeState {
@@ -123,4 +278,279 @@ pub struct FreezeState {
     pub freeze_mint: bool,           
     pub freeze_burn: bool,           
     pub authority: Pubkey,
-}
\ No newline at end of file
+    pub pending_authority: Option<Pubkey>,
+    #[max_len(MAX_PAUSERS)]
+    pub pausers: Vec<Pubkey>,
+    pub unfreeze_threshold: u8,
+    pub unfreeze_target: Option<FreezeTarget>,
+    #[max_len(MAX_PAUSERS)]
+    pub unfreeze_approvals: Vec<Pubkey>,
+    // Unix timestamps at which each freeze lapses; 0 means it lasts until unfrozen
+    pub frozen_until: i64,
+    pub mint_frozen_until: i64,
+    pub burn_frozen_until: i64,
+    pub max_pause_duration: i64,
+    // Unix timestamps at which each freeze started, which the pause cap counts from
+    pub frozen_since: i64,
+    pub mint_frozen_since: i64,
+    pub burn_frozen_since: i64,
+}
+
+impl FreezeState {
+    pub fn initialize(&mut self, authority: Pubkey) {
+        self.authority = authority;
+        self.is_frozen = false;
+        self.freeze_mint = false;
+        self.freeze_burn = false;
+        self.pending_authority = None;
+        self.pausers = vec![];
+        self.unfreeze_threshold = 0;
+        self.clear_unfreeze_approvals();
+        self.frozen_until = 0;
+        self.mint_frozen_until = 0;
+        self.burn_frozen_until = 0;
+        self.max_pause_duration = DEFAULT_MAX_PAUSE_DURATION;
+        self.frozen_since = 0;
+        self.mint_frozen_since = 0;
+        self.burn_frozen_since = 0;
+    }
+
+    /// Lapse timestamp for a freeze starting at `now`; 0 when no duration is given,
+    /// i.e. the freeze lasts until unfrozen. Freezes must lapse within the maximum
+    /// pause duration, so they can't be indefinite while a cap is set.
+    pub fn frozen_until(&self, duration: Option<i64>, now: i64) -> Result<i64> {
+        let capped = self.max_pause_duration > 0;
+        let Some(duration) = duration else {
+            require!(!capped, RewardTokenError::InvalidFreezeDuration);
+            return Ok(0);
+        };
+        require!(
+            duration > 0 && (!capped || duration <= self.max_pause_duration),
+            RewardTokenError::InvalidFreezeDuration
+        );
+        Ok(now + duration)
+    }
+
+    /// Freezes `target` until `frozen_until`, keeping the later lapse of a freeze that
+    /// is still active (0 outlasts any timestamp). While a cap is set the new lapse is
+    /// clamped to the cap past when the freeze started, so refreezing can't roll it
+    /// forward. Returns whether anything went from unfrozen to frozen, and the
+    /// resulting lapse of the target.
+    pub fn extend(&mut self, target: FreezeTarget, frozen_until: i64, now: i64) -> (bool, i64) {
+        let max_pause_duration = self.max_pause_duration;
+        let merge = |frozen: &mut bool, until: &mut i64, since: &mut i64| {
+            let active = *frozen && (*until == 0 || now < *until);
+            if !active {
+                *since = now;
+            }
+            let frozen_until = if max_pause_duration > 0 && frozen_until != 0 {
+                frozen_until.min(since.saturating_add(max_pause_duration))
+            } else {
+                frozen_until
+            };
+            if !active {
+                *until = frozen_until;
+            } else if *until != 0 {
+                *until = if frozen_until == 0 { 0 } else { (*until).max(frozen_until) };
+            }
+            *frozen = true;
+            !active
+        };
+
+        let (newly_frozen, effective_until) = match target {
+            FreezeTarget::All => {
+                let global = merge(&mut self.is_frozen, &mut self.frozen_until, &mut self.frozen_since);
+                let mint = merge(&mut self.freeze_mint, &mut self.mint_frozen_until, &mut self.mint_frozen_since);
+                let burn = merge(&mut self.freeze_burn, &mut self.burn_frozen_until, &mut self.burn_frozen_since);
+                (global || mint || burn, self.frozen_until)
+            }
+            FreezeTarget::Mint => (
+                merge(&mut self.freeze_mint, &mut self.mint_frozen_until, &mut self.mint_frozen_since),
+                self.mint_frozen_until,
+            ),
+            FreezeTarget::Burn => (
+                merge(&mut self.freeze_burn, &mut self.burn_frozen_until, &mut self.burn_frozen_since),
+                self.burn_frozen_until,
+            ),
+        };
+        msg!("{:?} frozen until {}.", target, effective_until);
+
+        (newly_frozen, effective_until)
+    }
+
+    /// Pushes the lapse of an active timed freeze on `target` back by `extension`, at
+    /// most the maximum pause duration. For `All`, mint and burn freezes that are still
+    /// timed move along with the global one. Returns the new lapse of the target.
+    pub fn extend_lapse(&mut self, target: FreezeTarget, extension: i64, now: i64) -> Result<i64> {
+        require!(
+            extension > 0 && (self.max_pause_duration == 0 || extension <= self.max_pause_duration),
+            RewardTokenError::InvalidFreezeDuration
+        );
+        let push = |frozen: bool, until: &mut i64| -> Result<bool> {
+            if !frozen || *until == 0 || now >= *until {
+                return Ok(false);
+            }
+            *until = until.checked_add(extension).ok_or(RewardTokenError::MathOverflow)?;
+            Ok(true)
+        };
+
+        let extended = match target {
+            FreezeTarget::All => {
+                let global = push(self.is_frozen, &mut self.frozen_until)?;
+                if global {
+                    push(self.freeze_mint, &mut self.mint_frozen_until)?;
+                    push(self.freeze_burn, &mut self.burn_frozen_until)?;
+                }
+                global
+            }
+            FreezeTarget::Mint => push(self.freeze_mint, &mut self.mint_frozen_until)?,
+            FreezeTarget::Burn => push(self.freeze_burn, &mut self.burn_frozen_until)?,
+        };
+        require!(extended, RewardTokenError::FreezeNotExtendable);
+
+        let frozen_until = match target {
+            FreezeTarget::All => self.frozen_until,
+            FreezeTarget::Mint => self.mint_frozen_until,
+            FreezeTarget::Burn => self.burn_frozen_until,
+        };
+        msg!("{:?} freeze extended until {}.", target, frozen_until);
+
+        Ok(frozen_until)
+    }
+
+    pub fn apply(&mut self, target: FreezeTarget, freeze: bool, frozen_until: i64) {
+        match target {
+            FreezeTarget::All => {
+                self.is_frozen = freeze;  // Global freeze/unfreeze
+                self.freeze_mint = freeze;
+                self.freeze_burn = freeze;
+                self.frozen_until = frozen_until;
+                self.mint_frozen_until = frozen_until;
+                self.burn_frozen_until = frozen_until;
+                msg!(
+                    "All operations are now {}.",
+                    if freeze { "frozen" } else { "unfrozen" }
+                );
+            }
+            FreezeTarget::Mint => {
+                self.freeze_mint = freeze;
+                self.mint_frozen_until = frozen_until;
+                msg!(
+                    "Minting is now {}.",
+                    if freeze { "frozen" } else { "unfrozen" }
+                );
+            }
+            FreezeTarget::Burn => {
+                self.freeze_burn = freeze;
+                self.burn_frozen_until = frozen_until;
+                msg!(
+                    "Burning is now {}.",
+                    if freeze { "frozen" } else { "unfrozen" }
+                );
+            }
+        }
+    }
+
+    fn clear_unfreeze_approvals(&mut self) {
+        self.unfreeze_target = None;
+        self.unfreeze_approvals = vec![];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn freeze_state(max_pause_duration: i64) -> FreezeState {
+        let mut freeze_state = FreezeState {
+            is_frozen: false,
+            freeze_mint: false,
+            freeze_burn: false,
+            authority: Pubkey::default(),
+            pending_authority: None,
+            pausers: vec![],
+            unfreeze_threshold: 0,
+            unfreeze_target: None,
+            unfreeze_approvals: vec![],
+            frozen_until: 0,
+            mint_frozen_until: 0,
+            burn_frozen_until: 0,
+            max_pause_duration: 0,
+            frozen_since: 0,
+            mint_frozen_since: 0,
+            burn_frozen_since: 0,
+        };
+        freeze_state.initialize(Pubkey::default());
+        freeze_state.max_pause_duration = max_pause_duration;
+        freeze_state
+    }
+
+    #[test]
+    fn timed_freeze_lapses_at_frozen_until() {
+        let mut freeze_state = freeze_state(100);
+        let frozen_until = freeze_state.frozen_until(Some(50), 1_000).unwrap();
+        freeze_state.apply(FreezeTarget::Mint, true, frozen_until);
+
+        assert!(check_freeze_state_at(&freeze_state, "mint", 1_049).is_err());
+        assert!(check_freeze_state_at(&freeze_state, "mint", 1_050).is_ok());
+        assert!(check_freeze_state_at(&freeze_state, "burn", 1_000).is_ok());
+    }
+
+    #[test]
+    fn freezes_are_capped_by_max_pause_duration() {
+        let freeze_state = freeze_state(100);
+        assert_eq!(freeze_state.frozen_until(Some(100), 1_000).unwrap(), 1_100);
+        assert!(freeze_state.frozen_until(None, 1_000).is_err());
+        assert!(freeze_state.frozen_until(Some(101), 1_000).is_err());
+        assert!(freeze_state.frozen_until(Some(0), 1_000).is_err());
+    }
+
+    #[test]
+    fn refreezing_an_active_freeze_keeps_the_later_lapse() {
+        let mut freeze_state = freeze_state(0);
+        assert_eq!(freeze_state.extend(FreezeTarget::Mint, 1_100, 1_000), (true, 1_100));
+        assert_eq!(freeze_state.extend(FreezeTarget::Mint, 1_060, 1_050), (false, 1_100));
+        assert_eq!(freeze_state.extend(FreezeTarget::Mint, 0, 1_050), (false, 0));
+        assert_eq!(freeze_state.extend(FreezeTarget::Mint, 1_150, 1_050), (false, 0));
+
+        freeze_state.extend(FreezeTarget::Burn, 1_100, 1_000);
+        assert_eq!(freeze_state.extend(FreezeTarget::Burn, 1_200, 1_100), (true, 1_200));
+    }
+
+    #[test]
+    fn refreezing_cannot_roll_the_cap_forward() {
+        let mut freeze_state = freeze_state(100);
+        assert_eq!(freeze_state.extend(FreezeTarget::Mint, 1_100, 1_000), (true, 1_100));
+        assert_eq!(freeze_state.extend(FreezeTarget::Mint, 1_199, 1_099), (false, 1_100));
+        assert!(check_freeze_state_at(&freeze_state, "mint", 1_100).is_ok());
+
+        // Once lapsed, a new freeze starts a new cap
+        assert_eq!(freeze_state.extend(FreezeTarget::Mint, 1_200, 1_100), (true, 1_200));
+    }
+
+    #[test]
+    fn governance_extension_is_capped_per_call() {
+        let mut freeze_state = freeze_state(100);
+        freeze_state.extend(FreezeTarget::All, 1_100, 1_000);
+
+        assert!(freeze_state.extend_lapse(FreezeTarget::All, 101, 1_050).is_err());
+        assert!(freeze_state.extend_lapse(FreezeTarget::All, 0, 1_050).is_err());
+        assert_eq!(freeze_state.extend_lapse(FreezeTarget::All, 100, 1_050).unwrap(), 1_200);
+        assert_eq!(freeze_state.mint_frozen_until, 1_200);
+        assert!(check_freeze_state_at(&freeze_state, "burn", 1_199).is_err());
+
+        // Refreezing keeps the extended lapse rather than clamping it back
+        assert_eq!(freeze_state.extend(FreezeTarget::All, 1_150, 1_150), (false, 1_200));
+        assert!(freeze_state.extend_lapse(FreezeTarget::Mint, 50, 1_200).is_err());
+    }
+
+    #[test]
+    fn uncapped_freeze_without_duration_is_indefinite() {
+        let mut freeze_state = freeze_state(0);
+        let frozen_until = freeze_state.frozen_until(None, 1_000).unwrap();
+        assert_eq!(frozen_until, 0);
+
+        freeze_state.apply(FreezeTarget::All, true, frozen_until);
+        assert!(check_freeze_state_at(&freeze_state, "burn", i64::MAX).is_err());
+    }
+}
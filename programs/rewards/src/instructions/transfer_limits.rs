@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use crate::constants::*;
+use crate::error::*;
+use crate::events::*;
+
+use anchor_spl::token_interface::Mint as Mint2022;
+
+// Limits are enforced by the transfer hook. Zero disables a limit; wallets need a
+// transfer counter from the hook program while the epoch limit is active.
+pub fn _initialize_transfer_limits(ctx: Context<InitTransferLimits>, args: TransferLimitsArgs) -> Result<()> {
+    let transfer_limits = &mut ctx.accounts.transfer_limits;
+    transfer_limits.authority = ctx.accounts.signer.key();
+    transfer_limits.per_transfer_max = args.per_transfer_max;
+    transfer_limits.epoch_limit = args.epoch_limit;
+    transfer_limits.epoch_duration = args.epoch_duration;
+    transfer_limits.exemptions = vec![];
+    transfer_limits.bump = ctx.bumps.transfer_limits;
+    transfer_limits.validate()?;
+
+    emit_limits(transfer_limits);
+
+    Ok(())
+}
+
+pub fn _update_transfer_limits(ctx: Context<UpdateTransferLimits>, args: UpdateTransferLimitsArgs) -> Result<()> {
+    let transfer_limits = &mut ctx.accounts.transfer_limits;
+
+    if let Some(per_transfer_max) = args.per_transfer_max {
+        transfer_limits.per_transfer_max = per_transfer_max;
+    }
+    if let Some(epoch_limit) = args.epoch_limit {
+        transfer_limits.epoch_limit = epoch_limit;
+    }
+    if let Some(epoch_duration) = args.epoch_duration {
+        transfer_limits.epoch_duration = epoch_duration;
+    }
+    transfer_limits.validate()?;
+
+    emit_limits(transfer_limits);
+
+    Ok(())
+}
+
+// Each exemption is the authority of the token accounts it exempts, e.g. a wallet or a
+// vault PDA; listing a program id doesn't exempt the accounts that program owns
+pub fn _add_transfer_limit_exemption(ctx: Context<UpdateTransferLimits>, entry: Pubkey) -> Result<()> {
+    let transfer_limits = &mut ctx.accounts.transfer_limits;
+    require!(
+        !transfer_limits.exemptions.contains(&entry),
+        RewardTokenError::InvalidTransferLimitExemption
+    );
+    require!(
+        transfer_limits.exemptions.len() < MAX_TRANSFER_LIMIT_EXEMPTIONS,
+        RewardTokenError::TransferLimitExemptionsFull
+    );
+
+    transfer_limits.exemptions.push(entry);
+
+    emit!(TransferLimitExemptionUpdatedEvent { entry, added: true });
+
+    Ok(())
+}
+
+pub fn _remove_transfer_limit_exemption(ctx: Context<UpdateTransferLimits>, entry: Pubkey) -> Result<()> {
+    let transfer_limits = &mut ctx.accounts.transfer_limits;
+    let position = transfer_limits
+        .exemptions
+        .iter()
+        .position(|exempt| *exempt == entry)
+        .ok_or(RewardTokenError::InvalidTransferLimitExemption)?;
+
+    transfer_limits.exemptions.remove(position);
+
+    emit!(TransferLimitExemptionUpdatedEvent { entry, added: false });
+
+    Ok(())
+}
+
+fn emit_limits(transfer_limits: &TransferLimits) {
+    emit!(TransferLimitsUpdatedEvent {
+        per_transfer_max: transfer_limits.per_transfer_max,
+        epoch_limit: transfer_limits.epoch_limit,
+        epoch_duration: transfer_limits.epoch_duration,
+    });
+}
+
+impl TransferLimits {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.epoch_duration >= 0 && (self.epoch_limit == 0 || self.epoch_duration > 0),
+            RewardTokenError::InvalidTransferLimits
+        );
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct InitTransferLimits<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [TOKEN_2022_SEED],
+        bump,
+        constraint = mint.mint_authority == COption::Some(signer.key()) @ RewardTokenError::Unauthorized,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint2022>>,
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [TRANSFER_LIMITS_SEED],
+        bump,
+        space = DISCRIMINATOR + TransferLimits::INIT_SPACE,
+    )]
+    pub transfer_limits: Account<'info, TransferLimits>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateTransferLimits<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority @ RewardTokenError::Unauthorized,
+        seeds = [TRANSFER_LIMITS_SEED],
+        bump = transfer_limits.bump,
+    )]
+    pub transfer_limits: Account<'info, TransferLimits>,
+}
+
+// The transfer hook reads this account with a fixed layout: keep the limits and
+// `exemptions` right after `authority`.
+#[account]
+#[derive(InitSpace)]
+pub struct TransferLimits {
+    pub authority: Pubkey,
+    pub per_transfer_max: u64,
+    pub epoch_limit: u64,
+    pub epoch_duration: i64,
+    #[max_len(MAX_TRANSFER_LIMIT_EXEMPTIONS)]
+    pub exemptions: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct TransferLimitsArgs {
+    pub per_transfer_max: u64,
+    pub epoch_limit: u64,
+    pub epoch_duration: i64,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct UpdateTransferLimitsArgs {
+    pub per_transfer_max: Option<u64>,
+    pub epoch_limit: Option<u64>,
+    pub epoch_duration: Option<i64>,
+}
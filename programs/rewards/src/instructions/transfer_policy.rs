@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::*;
+use crate::events::*;
+
+// Whitelist entries are matched by the transfer hook against the authority of the
+// source and destination token accounts, and against the program owning the source
+// authority. Whitelisting a program id opens withdrawals from all of its PDA vaults;
+// deposits need the vault authority itself to be listed.
+pub fn _add_transfer_whitelist(ctx: Context<UpdateTransferPolicy>, entry: Pubkey) -> Result<()> {
+    let transfer_policy = &mut ctx.accounts.transfer_policy;
+    require!(!transfer_policy.whitelist.contains(&entry), RewardTokenError::InvalidWhitelistEntry);
+    require!(
+        transfer_policy.whitelist.len() < MAX_TRANSFER_WHITELIST,
+        RewardTokenError::TransferWhitelistFull
+    );
+
+    transfer_policy.whitelist.push(entry);
+
+    emit!(TransferWhitelistUpdatedEvent { entry, added: true });
+
+    Ok(())
+}
+
+pub fn _remove_transfer_whitelist(ctx: Context<UpdateTransferPolicy>, entry: Pubkey) -> Result<()> {
+    let transfer_policy = &mut ctx.accounts.transfer_policy;
+    let position = transfer_policy
+        .whitelist
+        .iter()
+        .position(|whitelisted| *whitelisted == entry)
+        .ok_or(RewardTokenError::InvalidWhitelistEntry)?;
+
+    transfer_policy.whitelist.remove(position);
+
+    emit!(TransferWhitelistUpdatedEvent { entry, added: false });
+
+    Ok(())
+}
+
+impl TransferPolicy {
+    pub fn initialize(&mut self, authority: Pubkey, soulbound: bool, bump: u8) {
+        self.authority = authority;
+        self.soulbound = soulbound;
+        self.whitelist = vec![];
+        self.bump = bump;
+    }
+}
+
+#[derive(Accounts)]
+pub struct UpdateTransferPolicy<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority @ RewardTokenError::Unauthorized,
+        seeds = [TRANSFER_POLICY_SEED],
+        bump = transfer_policy.bump,
+    )]
+    pub transfer_policy: Account<'info, TransferPolicy>,
+}
+
+// The transfer hook reads this account with a fixed layout: keep `soulbound` and
+// `whitelist` right after `authority`.
+#[account]
+#[derive(InitSpace)]
+pub struct TransferPolicy {
+    pub authority: Pubkey,
+    pub soulbound: bool,
+    #[max_len(MAX_TRANSFER_WHITELIST)]
+    pub whitelist: Vec<Pubkey>,
+    pub bump: u8,
+}
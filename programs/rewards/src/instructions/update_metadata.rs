@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
+use crate::constants::*;
+use crate::events::*;
+use crate::utils::*;
+
+use anchor_spl::token_interface::{
+    self,
+    spl_pod::optional_keys::OptionalNonZeroPubkey,
+    spl_token_metadata_interface::{instruction::remove_key, state::Field},
+    Mint as Mint2022,
+    TokenInterface,
+    TokenMetadataUpdateAuthority,
+    TokenMetadataUpdateField,
+};
+
+// Token-2022 rejects every change below unless the signer is the metadata's
+// current update authority, which `_initialize_protocol` sets to the admin.
+pub fn _update_metadata(ctx: Context<UpdateMetadata>, args: UpdateMetadataArgs) -> Result<()> {
+    if let Some(name) = args.name {
+        update_field(&ctx, Field::Name, name)?;
+    }
+    if let Some(symbol) = args.symbol {
+        update_field(&ctx, Field::Symbol, symbol)?;
+    }
+    if let Some(uri) = args.uri {
+        update_field(&ctx, Field::Uri, uri)?;
+    }
+
+    top_up_mint_rent(&ctx)
+}
+
+pub fn _set_metadata_field(ctx: Context<UpdateMetadata>, key: String, value: String) -> Result<()> {
+    update_field(&ctx, Field::Key(key), value)?;
+    top_up_mint_rent(&ctx)
+}
+
+pub fn _remove_metadata_field(ctx: Context<UpdateMetadata>, key: String) -> Result<()> {
+    invoke(
+        &remove_key(
+            ctx.accounts.token_program2022.key,
+            &ctx.accounts.mint.key(),
+            ctx.accounts.signer.key,
+            key.clone(),
+            false,
+        ),
+        &[
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.signer.to_account_info(),
+        ],
+    )?;
+
+    emit!(MetadataFieldRemovedEvent { key });
+
+    Ok(())
+}
+
+// Passing `None` makes the metadata immutable
+pub fn _update_metadata_authority(ctx: Context<UpdateMetadata>, new_authority: Option<Pubkey>) -> Result<()> {
+    let cpi_accounts = TokenMetadataUpdateAuthority {
+        token_program_id: ctx.accounts.token_program2022.to_account_info(),
+        metadata: ctx.accounts.mint.to_account_info(),
+        current_authority: ctx.accounts.signer.to_account_info(),
+        new_authority: ctx.accounts.signer.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program2022.to_account_info();
+    token_interface::token_metadata_update_authority(
+        CpiContext::new(cpi_program, cpi_accounts),
+        OptionalNonZeroPubkey::try_from(new_authority)?,
+    )?;
+
+    emit!(MetadataAuthorityUpdatedEvent {
+        previous_authority: ctx.accounts.signer.key(),
+        new_authority,
+    });
+
+    Ok(())
+}
+
+fn update_field(ctx: &Context<UpdateMetadata>, field: Field, value: String) -> Result<()> {
+    let field_name = match &field {
+        Field::Name => "name".to_string(),
+        Field::Symbol => "symbol".to_string(),
+        Field::Uri => "uri".to_string(),
+        Field::Key(key) => key.clone(),
+    };
+
+    let cpi_accounts = TokenMetadataUpdateField {
+        token_program_id: ctx.accounts.token_program2022.to_account_info(),
+        metadata: ctx.accounts.mint.to_account_info(),
+        update_authority: ctx.accounts.signer.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program2022.to_account_info();
+    token_interface::token_metadata_update_field(CpiContext::new(cpi_program, cpi_accounts), field, value.clone())?;
+
+    emit!(MetadataFieldUpdatedEvent {
+        field: field_name,
+        value,
+    });
+
+    Ok(())
+}
+
+// Longer values grow the mint account, which has to stay rent exempt
+fn top_up_mint_rent(ctx: &Context<UpdateMetadata>) -> Result<()> {
+    update_account_lamports_to_minimum_balance(
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.signer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )
+}
+
+#[derive(Accounts)]
+pub struct UpdateMetadata<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [TOKEN_2022_SEED],
+        bump,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint2022>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program2022: Interface<'info, TokenInterface>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct UpdateMetadataArgs {
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+    pub uri: Option<String>,
+}
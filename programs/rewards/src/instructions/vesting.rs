@@ -0,0 +1,374 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::*;
+use crate::events::*;
+use crate::utils::*;
+
+use anchor_spl::token_interface::{
+    Mint as Mint2022,
+    TokenAccount as TokenAccount2022,
+    TokenInterface,
+};
+
+// Remaining accounts: transfer hook extra accounts for the deposit into the escrow.
+// The signer funds the grant and becomes the authority allowed to revoke it.
+pub fn _create_vesting<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateVesting<'info>>,
+    args: CreateVestingArgs,
+) -> Result<()> {
+    require!(args.total_amount > 0, RewardTokenError::InvalidAmount);
+    require!(
+        args.start_ts <= args.cliff_ts && args.cliff_ts <= args.end_ts && args.start_ts < args.end_ts,
+        RewardTokenError::InvalidVestingSchedule
+    );
+
+    transfer_checked_with_hook(
+        ctx.accounts.token_program2022.key,
+        ctx.accounts.authority_ata.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.escrow.to_account_info(),
+        ctx.accounts.authority.to_account_info(),
+        ctx.remaining_accounts,
+        args.total_amount,
+        ctx.accounts.mint.decimals,
+        &[],
+    )?;
+
+    let schedule = &mut ctx.accounts.schedule;
+    schedule.authority = ctx.accounts.authority.key();
+    schedule.beneficiary = ctx.accounts.beneficiary.key();
+    schedule.id = args.schedule_id;
+    schedule.total_amount = args.total_amount;
+    schedule.released_amount = 0;
+    schedule.start_ts = args.start_ts;
+    schedule.cliff_ts = args.cliff_ts;
+    schedule.end_ts = args.end_ts;
+    schedule.revoked = false;
+    schedule.bump = ctx.bumps.schedule;
+
+    emit!(VestingCreatedEvent {
+        schedule: schedule.key(),
+        authority: schedule.authority,
+        beneficiary: schedule.beneficiary,
+        total_amount: schedule.total_amount,
+        start_ts: schedule.start_ts,
+        cliff_ts: schedule.cliff_ts,
+        end_ts: schedule.end_ts,
+    });
+
+    Ok(())
+}
+
+// Remaining accounts: transfer hook extra accounts for the release from the escrow
+pub fn _claim_vested<'info>(ctx: Context<'_, '_, '_, 'info, ClaimVested<'info>>) -> Result<()> {
+    let schedule = &ctx.accounts.schedule;
+    let vested = schedule.vested_amount(Clock::get()?.unix_timestamp);
+    let amount = vested - schedule.released_amount;
+    require!(amount > 0, RewardTokenError::NothingToClaim);
+
+    transfer_from_escrow(
+        schedule,
+        ctx.accounts.token_program2022.key,
+        ctx.accounts.escrow.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.beneficiary_ata.to_account_info(),
+        ctx.remaining_accounts,
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    let schedule = &mut ctx.accounts.schedule;
+    schedule.released_amount = vested;
+
+    emit!(VestingClaimedEvent {
+        schedule: schedule.key(),
+        beneficiary: schedule.beneficiary,
+        amount,
+        released_amount: schedule.released_amount,
+    });
+
+    Ok(())
+}
+
+// Remaining accounts: transfer hook extra accounts for the return of unvested RWD.
+// What has vested so far stays claimable by the beneficiary.
+pub fn _revoke_vesting<'info>(ctx: Context<'_, '_, '_, 'info, RevokeVesting<'info>>) -> Result<()> {
+    let schedule = &ctx.accounts.schedule;
+    require!(!schedule.revoked, RewardTokenError::VestingRevoked);
+
+    let vested = schedule.vested_amount(Clock::get()?.unix_timestamp);
+    let unvested = schedule.total_amount - vested;
+
+    if unvested > 0 {
+        transfer_from_escrow(
+            schedule,
+            ctx.accounts.token_program2022.key,
+            ctx.accounts.escrow.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.authority_ata.to_account_info(),
+            ctx.remaining_accounts,
+            unvested,
+            ctx.accounts.mint.decimals,
+        )?;
+    }
+
+    let schedule = &mut ctx.accounts.schedule;
+    schedule.total_amount = vested;
+    schedule.end_ts = schedule.end_ts.min(Clock::get()?.unix_timestamp);
+    schedule.revoked = true;
+
+    emit!(VestingRevokedEvent {
+        schedule: schedule.key(),
+        beneficiary: schedule.beneficiary,
+        vested_amount: vested,
+        unvested_amount: unvested,
+    });
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn transfer_from_escrow<'info>(
+    schedule: &Account<'info, VestingSchedule>,
+    token_program: &Pubkey,
+    escrow: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    to: AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    decimals: u8,
+) -> Result<()> {
+    let id_bytes = schedule.id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        VESTING_SEED,
+        schedule.beneficiary.as_ref(),
+        &id_bytes,
+        &[schedule.bump],
+    ]];
+
+    transfer_checked_with_hook(
+        token_program,
+        escrow,
+        mint,
+        to,
+        schedule.to_account_info(),
+        remaining_accounts,
+        amount,
+        decimals,
+        signer_seeds,
+    )
+}
+
+impl VestingSchedule {
+    /// Amount vested at `now`: nothing before the cliff, then linear from `start_ts`
+    /// so the cliff releases everything accrued up to it, and all of it from `end_ts`.
+    pub fn vested_amount(&self, now: i64) -> u64 {
+        if now < self.cliff_ts {
+            return 0;
+        }
+        if now >= self.end_ts {
+            return self.total_amount;
+        }
+
+        let elapsed = (now - self.start_ts) as u128;
+        let duration = (self.end_ts - self.start_ts) as u128;
+        (self.total_amount as u128 * elapsed / duration) as u64
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(args: CreateVestingArgs)]
+pub struct CreateVesting<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: only recorded as the schedule's beneficiary
+    pub beneficiary: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [VESTING_SEED, beneficiary.key().as_ref(), args.schedule_id.to_le_bytes().as_ref()],
+        bump,
+        space = DISCRIMINATOR + VestingSchedule::INIT_SPACE,
+    )]
+    pub schedule: Account<'info, VestingSchedule>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [VESTING_ESCROW_SEED, schedule.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = schedule,
+        token::token_program = token_program2022,
+    )]
+    pub escrow: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    #[account(
+        seeds = [TOKEN_2022_SEED],
+        bump,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint2022>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = authority,
+        token::token_program = token_program2022,
+    )]
+    pub authority_ata: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program2022: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimVested<'info> {
+    pub beneficiary: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [VESTING_SEED, beneficiary.key().as_ref(), schedule.id.to_le_bytes().as_ref()],
+        bump = schedule.bump,
+    )]
+    pub schedule: Account<'info, VestingSchedule>,
+
+    #[account(
+        mut,
+        seeds = [VESTING_ESCROW_SEED, schedule.key().as_ref()],
+        bump,
+    )]
+    pub escrow: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    #[account(
+        seeds = [TOKEN_2022_SEED],
+        bump,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint2022>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = beneficiary,
+        token::token_program = token_program2022,
+    )]
+    pub beneficiary_ata: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    pub token_program2022: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeVesting<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority @ RewardTokenError::Unauthorized,
+        seeds = [VESTING_SEED, schedule.beneficiary.as_ref(), schedule.id.to_le_bytes().as_ref()],
+        bump = schedule.bump,
+    )]
+    pub schedule: Account<'info, VestingSchedule>,
+
+    #[account(
+        mut,
+        seeds = [VESTING_ESCROW_SEED, schedule.key().as_ref()],
+        bump,
+    )]
+    pub escrow: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    #[account(
+        seeds = [TOKEN_2022_SEED],
+        bump,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint2022>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = authority,
+        token::token_program = token_program2022,
+    )]
+    pub authority_ata: Box<InterfaceAccount<'info, TokenAccount2022>>,
+
+    pub token_program2022: Interface<'info, TokenInterface>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct CreateVestingArgs {
+    pub schedule_id: u64,
+    pub total_amount: u64,
+    pub start_ts: i64,
+    pub cliff_ts: i64,
+    pub end_ts: i64,
+}
+
+// `authority` and `beneficiary` lead the layout: the transfer hook reads them at fixed
+// offsets to recognise escrow vaults.
+#[account]
+#[derive(InitSpace)]
+pub struct VestingSchedule {
+    pub authority: Pubkey,
+    pub beneficiary: Pubkey,
+    pub id: u64,
+    pub total_amount: u64,
+    pub released_amount: u64,
+    pub start_ts: i64,
+    pub cliff_ts: i64,
+    pub end_ts: i64,
+    pub revoked: bool,
+    pub bump: u8,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule() -> VestingSchedule {
+        VestingSchedule {
+            authority: Pubkey::default(),
+            beneficiary: Pubkey::default(),
+            id: 0,
+            total_amount: 1_200,
+            released_amount: 0,
+            start_ts: 1_000,
+            cliff_ts: 1_300,
+            end_ts: 2_200,
+            revoked: false,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn nothing_vests_before_the_cliff() {
+        let schedule = schedule();
+        assert_eq!(schedule.vested_amount(0), 0);
+        assert_eq!(schedule.vested_amount(1_299), 0);
+    }
+
+    #[test]
+    fn cliff_releases_linear_accrual() {
+        let schedule = schedule();
+        assert_eq!(schedule.vested_amount(1_300), 300);
+        assert_eq!(schedule.vested_amount(1_600), 600);
+        assert_eq!(schedule.vested_amount(2_199), 1_199);
+    }
+
+    #[test]
+    fn everything_vests_at_the_end() {
+        let schedule = schedule();
+        assert_eq!(schedule.vested_amount(2_200), 1_200);
+        assert_eq!(schedule.vested_amount(i64::MAX), 1_200);
+    }
+
+    #[test]
+    fn revocation_freezes_the_vested_amount() {
+        // Mirrors `_revoke_vesting`, which caps the total and end at the revocation time
+        let mut schedule = schedule();
+        let vested = schedule.vested_amount(1_600);
+        schedule.total_amount = vested;
+        schedule.end_ts = 1_600;
+        assert_eq!(schedule.vested_amount(1_600), 600);
+        assert_eq!(schedule.vested_amount(5_000), 600);
+    }
+}
@@ -19,18 +19,19 @@ pub mod rewards {
     use anchor_spl::token_interface;
     use super::*;
 
-    pub fn initialize_token(ctx: Context<InitToken>, args: InitTokenAccountArgs) -> Result<()> {
-        _initialize_token(ctx, args)
-    }
-
-    pub fn initialize_fees(ctx: Context<InitializeFees>, args: InitFeesArgs) -> Result<()> {
-        _initialize_fees(ctx, args)
+    pub fn initialize_protocol(ctx: Context<InitProtocol>, args: InitProtocolArgs) -> Result<()> {
+        _initialize_protocol(ctx, args)
     }
 
     pub fn update_fees(ctx: Context<UpdateFees>, args: UpdateFeesArgs) -> Result<()> {
         _update_fees(ctx, args)
     }
 
+    pub fn initialize_freeze(ctx: Context<InitFreeze>) -> Result<()> {
+        _initialize_freeze(ctx)
+    }
+
+    /// Deprecated: misspelled alias of `initialize_freeze`, kept for existing clients.
     pub fn intialize_freeze(ctx: Context<InitFreeze>) -> Result<()> {
         _initialize_freeze(ctx)
     }
@@ -43,10 +44,215 @@ pub mod rewards {
         _burn_tokens_with_fees(&ctx, amount)
     }
 
-    pub fn freeze(ctx: Context<Freeze>, target: FreezeTarget) -> Result<()> {
-        _toggle_freeze(ctx, target, true)
+    pub fn freeze(ctx: Context<Freeze>, target: FreezeTarget, duration: Option<i64>) -> Result<()> {
+        _toggle_freeze(ctx, target, true, duration)
     }
     pub fn unfreeze(ctx: Context<Freeze>, target: FreezeTarget) -> Result<()> {
-        _toggle_freeze(ctx, target, false)
+        _toggle_freeze(ctx, target, false, None)
+    }
+
+    pub fn propose_freeze_authority(ctx: Context<UpdateFreezeState>, new_authority: Pubkey) -> Result<()> {
+        _propose_freeze_authority(ctx, new_authority)
+    }
+
+    pub fn accept_freeze_authority(ctx: Context<UpdateFreezeState>) -> Result<()> {
+        _accept_freeze_authority(ctx)
+    }
+
+    pub fn set_pausers(ctx: Context<UpdateFreezeState>, pausers: Vec<Pubkey>, unfreeze_threshold: u8) -> Result<()> {
+        _set_pausers(ctx, pausers, unfreeze_threshold)
+    }
+
+    pub fn approve_unfreeze(ctx: Context<UpdateFreezeState>, target: FreezeTarget) -> Result<()> {
+        _approve_unfreeze(ctx, target)
+    }
+
+    pub fn extend_freeze(ctx: Context<UpdateFreezeState>, target: FreezeTarget, extension: i64) -> Result<()> {
+        _extend_freeze(ctx, target, extension)
+    }
+
+    pub fn set_max_pause_duration(ctx: Context<UpdateFreezeState>, max_pause_duration: i64) -> Result<()> {
+        _set_max_pause_duration(ctx, max_pause_duration)
+    }
+
+    pub fn initialize_reward_pool(ctx: Context<InitRewardPool>) -> Result<()> {
+        _initialize_reward_pool(ctx)
+    }
+
+    pub fn fund_reward_pool<'info>(ctx: Context<'_, '_, '_, 'info, FundRewardPool<'info>>, amount: u64) -> Result<()> {
+        _fund_reward_pool(ctx, amount)
+    }
+
+    pub fn stake<'info>(ctx: Context<'_, '_, '_, 'info, Stake<'info>>, amount: u64) -> Result<()> {
+        _stake(ctx, amount)
+    }
+
+    pub fn unstake<'info>(ctx: Context<'_, '_, '_, 'info, Unstake<'info>>, amount: u64) -> Result<()> {
+        _unstake(ctx, amount)
+    }
+
+    pub fn claim_rewards<'info>(ctx: Context<'_, '_, '_, 'info, ClaimRewards<'info>>) -> Result<()> {
+        _claim_rewards(ctx)
+    }
+
+    pub fn create_campaign(
+        ctx: Context<CreateCampaign>,
+        campaign_id: u64,
+        merkle_root: [u8; 32],
+        total_amount: u64,
+        expiry_ts: i64,
+    ) -> Result<()> {
+        _create_campaign(ctx, campaign_id, merkle_root, total_amount, expiry_ts)
+    }
+
+    pub fn claim_airdrop<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimAirdrop<'info>>,
+        index: u64,
+        amount: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        _claim_airdrop(ctx, index, amount, proof)
+    }
+
+    pub fn reclaim_campaign<'info>(ctx: Context<'_, '_, '_, 'info, ReclaimCampaign<'info>>) -> Result<()> {
+        _reclaim_campaign(ctx)
+    }
+
+    pub fn create_vesting<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateVesting<'info>>,
+        args: CreateVestingArgs,
+    ) -> Result<()> {
+        _create_vesting(ctx, args)
+    }
+
+    pub fn claim_vested<'info>(ctx: Context<'_, '_, '_, 'info, ClaimVested<'info>>) -> Result<()> {
+        _claim_vested(ctx)
+    }
+
+    pub fn revoke_vesting<'info>(ctx: Context<'_, '_, '_, 'info, RevokeVesting<'info>>) -> Result<()> {
+        _revoke_vesting(ctx)
+    }
+
+    pub fn register_merchant(ctx: Context<RegisterMerchant>, args: RegisterMerchantArgs) -> Result<()> {
+        _register_merchant(ctx, args)
+    }
+
+    pub fn update_merchant(ctx: Context<UpdateMerchant>, args: UpdateMerchantArgs) -> Result<()> {
+        _update_merchant(ctx, args)
+    }
+
+    pub fn fund_merchant_budget<'info>(
+        ctx: Context<'_, '_, '_, 'info, FundMerchantBudget<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        _fund_merchant_budget(ctx, amount)
+    }
+
+    pub fn award_points<'info>(
+        ctx: Context<'_, '_, '_, 'info, AwardPoints<'info>>,
+        purchase_amount: u64,
+    ) -> Result<()> {
+        _award_points(ctx, purchase_amount)
+    }
+
+    pub fn redeem_at_merchant(ctx: Context<RedeemAtMerchant>, amount: u64) -> Result<()> {
+        _redeem_at_merchant(ctx, amount)
+    }
+
+    pub fn settle_merchant(ctx: Context<SettleMerchant>) -> Result<()> {
+        _settle_merchant(ctx)
+    }
+
+    pub fn initialize_points_expiry(ctx: Context<InitPointsExpiry>, args: InitPointsExpiryArgs) -> Result<()> {
+        _initialize_points_expiry(ctx, args)
+    }
+
+    pub fn update_points_expiry(ctx: Context<UpdatePointsExpiry>, args: UpdatePointsExpiryArgs) -> Result<()> {
+        _update_points_expiry(ctx, args)
+    }
+
+    pub fn issue_points(ctx: Context<IssuePoints>, amount: u64) -> Result<()> {
+        _issue_points(ctx, amount)
+    }
+
+    pub fn expire_points(ctx: Context<ExpirePoints>) -> Result<()> {
+        _expire_points(ctx)
+    }
+
+    pub fn add_transfer_whitelist(ctx: Context<UpdateTransferPolicy>, entry: Pubkey) -> Result<()> {
+        _add_transfer_whitelist(ctx, entry)
+    }
+
+    pub fn remove_transfer_whitelist(ctx: Context<UpdateTransferPolicy>, entry: Pubkey) -> Result<()> {
+        _remove_transfer_whitelist(ctx, entry)
+    }
+
+    pub fn update_metadata(ctx: Context<UpdateMetadata>, args: UpdateMetadataArgs) -> Result<()> {
+        _update_metadata(ctx, args)
+    }
+
+    pub fn set_metadata_field(ctx: Context<UpdateMetadata>, key: String, value: String) -> Result<()> {
+        _set_metadata_field(ctx, key, value)
+    }
+
+    pub fn remove_metadata_field(ctx: Context<UpdateMetadata>, key: String) -> Result<()> {
+        _remove_metadata_field(ctx, key)
+    }
+
+    pub fn update_metadata_authority(ctx: Context<UpdateMetadata>, new_authority: Option<Pubkey>) -> Result<()> {
+        _update_metadata_authority(ctx, new_authority)
+    }
+
+    pub fn initialize_compliance(ctx: Context<InitCompliance>, officer: Pubkey) -> Result<()> {
+        _initialize_compliance(ctx, officer)
+    }
+
+    pub fn set_compliance_officer(ctx: Context<SetComplianceOfficer>, officer: Pubkey) -> Result<()> {
+        _set_compliance_officer(ctx, officer)
+    }
+
+    pub fn freeze_token_account(ctx: Context<FreezeTokenAccount>, reason_code: u16) -> Result<()> {
+        _freeze_token_account(ctx, reason_code)
+    }
+
+    pub fn thaw_token_account(ctx: Context<FreezeTokenAccount>, reason_code: u16) -> Result<()> {
+        _thaw_token_account(ctx, reason_code)
+    }
+
+    pub fn add_to_blacklist(ctx: Context<AddToBlacklist>, wallet: Pubkey, reason_code: u16) -> Result<()> {
+        _add_to_blacklist(ctx, wallet, reason_code)
+    }
+
+    pub fn remove_from_blacklist(ctx: Context<RemoveFromBlacklist>, reason_code: u16) -> Result<()> {
+        _remove_from_blacklist(ctx, reason_code)
+    }
+
+    pub fn set_clawback_authority(ctx: Context<SetComplianceOfficer>, clawback_authority: Option<Pubkey>) -> Result<()> {
+        _set_clawback_authority(ctx, clawback_authority)
+    }
+
+    pub fn clawback<'info>(
+        ctx: Context<'_, '_, '_, 'info, Clawback<'info>>,
+        amount: u64,
+        burn: bool,
+        reason: String,
+    ) -> Result<()> {
+        _clawback(ctx, amount, burn, reason)
+    }
+
+    pub fn initialize_transfer_limits(ctx: Context<InitTransferLimits>, args: TransferLimitsArgs) -> Result<()> {
+        _initialize_transfer_limits(ctx, args)
+    }
+
+    pub fn update_transfer_limits(ctx: Context<UpdateTransferLimits>, args: UpdateTransferLimitsArgs) -> Result<()> {
+        _update_transfer_limits(ctx, args)
+    }
+
+    pub fn add_transfer_limit_exemption(ctx: Context<UpdateTransferLimits>, entry: Pubkey) -> Result<()> {
+        _add_transfer_limit_exemption(ctx, entry)
+    }
+
+    pub fn remove_transfer_limit_exemption(ctx: Context<UpdateTransferLimits>, entry: Pubkey) -> Result<()> {
+        _remove_transfer_limit_exemption(ctx, entry)
     }
 }
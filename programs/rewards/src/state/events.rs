@@ -32,4 +32,274 @@ pub struct FreezeStateChangedEvent {
     pub authority: Pubkey,
     pub target: FreezeTarget,
     pub is_frozen: bool,
+    pub frozen_until: i64,
+}
+
+#[event]
+pub struct FreezeExtendedEvent {
+    pub authority: Pubkey,
+    pub target: FreezeTarget,
+    pub extension: i64,
+    pub frozen_until: i64,
+}
+
+#[event]
+pub struct MaxPauseDurationUpdatedEvent {
+    pub max_pause_duration: i64,
+}
+
+#[event]
+pub struct FreezeAuthorityProposedEvent {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct FreezeAuthorityTransferredEvent {
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct PausersUpdatedEvent {
+    pub pausers: Vec<Pubkey>,
+    pub unfreeze_threshold: u8,
+}
+
+#[event]
+pub struct UnfreezeApprovedEvent {
+    pub pauser: Pubkey,
+    pub target: FreezeTarget,
+    pub approvals: u8,
+    pub unfreeze_threshold: u8,
+}
+
+#[event]
+pub struct RewardPoolFundedEvent {
+    pub funder: Pubkey,
+    pub amount: u64,
+    pub total_staked: u64,
+    pub reward_per_token: u128,
+}
+
+#[event]
+pub struct StakedEvent {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub staked_amount: u64,
+    pub total_staked: u64,
+}
+
+#[event]
+pub struct UnstakedEvent {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub staked_amount: u64,
+    pub total_staked: u64,
+}
+
+#[event]
+pub struct RewardsClaimedEvent {
+    pub owner: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct CampaignCreatedEvent {
+    pub campaign: Pubkey,
+    pub campaign_id: u64,
+    pub merkle_root: [u8; 32],
+    pub total_amount: u64,
+    pub expiry_ts: i64,
+}
+
+#[event]
+pub struct AirdropClaimedEvent {
+    pub campaign: Pubkey,
+    pub index: u64,
+    pub claimant: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct CampaignReclaimedEvent {
+    pub campaign: Pubkey,
+    pub treasury: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct VestingCreatedEvent {
+    pub schedule: Pubkey,
+    pub authority: Pubkey,
+    pub beneficiary: Pubkey,
+    pub total_amount: u64,
+    pub start_ts: i64,
+    pub cliff_ts: i64,
+    pub end_ts: i64,
+}
+
+#[event]
+pub struct VestingClaimedEvent {
+    pub schedule: Pubkey,
+    pub beneficiary: Pubkey,
+    pub amount: u64,
+    pub released_amount: u64,
+}
+
+#[event]
+pub struct VestingRevokedEvent {
+    pub schedule: Pubkey,
+    pub beneficiary: Pubkey,
+    pub vested_amount: u64,
+    pub unvested_amount: u64,
+}
+
+#[event]
+pub struct MerchantRegisteredEvent {
+    pub merchant: Pubkey,
+    pub authority: Pubkey,
+    pub earn_rate_bps: u16,
+    pub settlement_account: Pubkey,
+}
+
+#[event]
+pub struct MerchantUpdatedEvent {
+    pub merchant: Pubkey,
+    pub earn_rate_bps: u16,
+    pub active: bool,
+}
+
+#[event]
+pub struct MerchantFundedEvent {
+    pub merchant: Pubkey,
+    pub funder: Pubkey,
+    pub amount: u64,
+    pub budget: u64,
+}
+
+#[event]
+pub struct PointsAwardedEvent {
+    pub merchant: Pubkey,
+    pub customer: Pubkey,
+    pub purchase_amount: u64,
+    pub amount: u64,
+    pub budget: u64,
+}
+
+#[event]
+pub struct PointsRedeemedEvent {
+    pub merchant: Pubkey,
+    pub customer: Pubkey,
+    pub amount: u64,
+    pub usdc_amount: u64,
+}
+
+#[event]
+pub struct MerchantSettledEvent {
+    pub merchant: Pubkey,
+    pub usdc_amount: u64,
+    pub total_settled_usdc: u64,
+    pub pending_settlement_usdc: u64,
+}
+
+#[event]
+pub struct PointsExpiryUpdatedEvent {
+    pub enabled: bool,
+    pub expiry_period: i64,
+}
+
+#[event]
+pub struct PointsIssuedEvent {
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub issued_at: i64,
+    pub expires: bool,
+}
+
+#[event]
+pub struct PointsExpiredEvent {
+    pub owner: Pubkey,
+    pub token_account: Pubkey,
+    pub expired_amount: u64,
+    pub burned_amount: u64,
+}
+
+#[event]
+pub struct TransferWhitelistUpdatedEvent {
+    pub entry: Pubkey,
+    pub added: bool,
+}
+
+#[event]
+pub struct MetadataFieldUpdatedEvent {
+    pub field: String,
+    pub value: String,
+}
+
+#[event]
+pub struct MetadataFieldRemovedEvent {
+    pub key: String,
+}
+
+#[event]
+pub struct MetadataAuthorityUpdatedEvent {
+    pub previous_authority: Pubkey,
+    pub new_authority: Option<Pubkey>,
+}
+
+#[event]
+pub struct ComplianceOfficerUpdatedEvent {
+    pub officer: Pubkey,
+}
+
+#[event]
+pub struct TokenAccountFreezeEvent {
+    pub token_account: Pubkey,
+    pub owner: Pubkey,
+    pub frozen: bool,
+    pub reason_code: u16,
+}
+
+#[event]
+pub struct BlacklistUpdatedEvent {
+    pub wallet: Pubkey,
+    pub blacklisted: bool,
+    pub reason_code: u16,
+}
+
+#[event]
+pub struct ClawbackAuthorityUpdatedEvent {
+    pub clawback_authority: Option<Pubkey>,
+}
+
+#[event]
+pub struct ClawbackEvent {
+    pub authority: Pubkey,
+    pub source: Pubkey,
+    pub owner: Pubkey,
+    pub destination: Option<Pubkey>,
+    pub amount: u64,
+    pub burned: bool,
+    pub reason: String,
+}
+
+#[event]
+pub struct ProtocolInitializedEvent {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub compliance_officer: Pubkey,
+}
+
+#[event]
+pub struct TransferLimitsUpdatedEvent {
+    pub per_transfer_max: u64,
+    pub epoch_limit: u64,
+    pub epoch_duration: i64,
+}
+
+#[event]
+pub struct TransferLimitExemptionUpdatedEvent {
+    pub entry: Pubkey,
+    pub added: bool,
 }
@@ -0,0 +1,71 @@
+use anchor_lang::solana_program::{hash::hashv, pubkey::Pubkey};
+
+// Leaves and inner nodes are domain-separated so a node can never be replayed as a leaf
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+/// Leaf committed to by an airdrop campaign root: sha256(0x00 || index || claimant || amount),
+/// integers little-endian.
+pub fn airdrop_leaf(index: u64, claimant: &Pubkey, amount: u64) -> [u8; 32] {
+    hashv(&[
+        LEAF_PREFIX,
+        &index.to_le_bytes(),
+        claimant.as_ref(),
+        &amount.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// Hashes two sibling nodes in sorted order, so proofs need no left/right flags.
+pub fn merkle_parent(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (low, high) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[NODE_PREFIX, low, high]).to_bytes()
+}
+
+/// Returns true when folding `leaf` with `proof` reproduces `root`.
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| merkle_parent(&node, sibling));
+    computed == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaves() -> Vec<[u8; 32]> {
+        (0..4u64)
+            .map(|index| airdrop_leaf(index, &Pubkey::new_from_array([index as u8 + 1; 32]), 100 * (index + 1)))
+            .collect()
+    }
+
+    #[test]
+    fn verifies_every_leaf_of_a_balanced_tree() {
+        let leaves = leaves();
+        let left = merkle_parent(&leaves[0], &leaves[1]);
+        let right = merkle_parent(&leaves[2], &leaves[3]);
+        let root = merkle_parent(&left, &right);
+
+        assert!(verify_merkle_proof(&[leaves[1], right], &root, leaves[0]));
+        assert!(verify_merkle_proof(&[leaves[0], right], &root, leaves[1]));
+        assert!(verify_merkle_proof(&[leaves[3], left], &root, leaves[2]));
+        assert!(verify_merkle_proof(&[leaves[2], left], &root, leaves[3]));
+    }
+
+    #[test]
+    fn single_leaf_tree_needs_no_proof() {
+        let leaf = leaves()[0];
+        assert!(verify_merkle_proof(&[], &leaf, leaf));
+    }
+
+    #[test]
+    fn rejects_tampered_claims() {
+        let leaves = leaves();
+        let root = merkle_parent(&leaves[0], &leaves[1]);
+        let claimant = Pubkey::new_from_array([1; 32]);
+
+        assert!(verify_merkle_proof(&[leaves[1]], &root, airdrop_leaf(0, &claimant, 100)));
+        assert!(!verify_merkle_proof(&[leaves[1]], &root, airdrop_leaf(0, &claimant, 101)));
+        assert!(!verify_merkle_proof(&[leaves[1]], &root, airdrop_leaf(1, &claimant, 100)));
+        assert!(!verify_merkle_proof(&[], &root, leaves[0]));
+    }
+}
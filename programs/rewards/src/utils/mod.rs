@@ -1,2 +1,4 @@
+pub mod merkle;
 pub mod token2022;
+pub use merkle::*;
 pub use token2022::*;
\ No newline at end of file
@@ -3,12 +3,14 @@ use anchor_lang::{
     solana_program::{
         account_info::AccountInfo,
         program::invoke,
+        pubkey::Pubkey,
         rent::Rent,
         system_instruction::transfer,
         sysvar::Sysvar,
     },
     Lamports,
 };
+use anchor_spl::token_2022::spl_token_2022::onchain::invoke_transfer_checked;
 
 
 pub fn update_account_lamports_to_minimum_balance<'info>(
@@ -16,7 +18,9 @@ pub fn update_account_lamports_to_minimum_balance<'info>(
     payer: AccountInfo<'info>,
     system_program: AccountInfo<'info>,
 ) -> Result<()> {
-    let extra_lamports = Rent::get()?.minimum_balance(account.data_len()) - account.get_lamports();
+    let extra_lamports = Rent::get()?
+        .minimum_balance(account.data_len())
+        .saturating_sub(account.get_lamports());
     if extra_lamports > 0 {
         invoke(
             &transfer(payer.key, account.key, extra_lamports),
@@ -24,4 +28,33 @@ pub fn update_account_lamports_to_minimum_balance<'info>(
         )?;
     }
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Moves RWD with `transfer_checked`, forwarding the transfer hook program, its
+/// extra account meta list and resolved extra accounts from `remaining_accounts`.
+/// Pass empty `signer_seeds` when the authority signed the transaction.
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_checked_with_hook<'info>(
+    token_program: &Pubkey,
+    from: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    to: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    decimals: u8,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    invoke_transfer_checked(
+        token_program,
+        from,
+        mint,
+        to,
+        authority,
+        remaining_accounts,
+        amount,
+        decimals,
+        signer_seeds,
+    )?;
+    Ok(())
+}
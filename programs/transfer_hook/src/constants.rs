@@ -1,4 +1,104 @@
 use anchor_lang::prelude::*; 
 
 #[constant]
-pub const META_LIST_ACCOUNT_SEED: &[u8] = b"extra-account-metas";
\ No newline at end of file
+pub const META_LIST_ACCOUNT_SEED: &[u8] = b"extra-account-metas";
+
+#[constant]
+pub const LOT_LEDGER_SEED: &[u8] = b"lot-ledger";
+
+pub const MAX_POINT_LOTS: usize = 32;
+
+#[constant]
+pub const TRANSFER_COUNTER_SEED: &[u8] = b"transfer-counter";
+
+#[constant]
+pub const HOOK_CONFIG_SEED: &[u8] = b"hook-config";
+
+#[constant]
+pub const TRANSFER_STATS_SEED: &[u8] = b"transfer-stats";
+
+#[constant]
+pub const OWNER_STATS_SEED: &[u8] = b"owner-stats";
+
+// Offset of the owner field in an spl token account
+pub const TOKEN_ACCOUNT_OWNER_OFFSET: u8 = 32;
+
+// The dao program escrows vote-locked RWD in token accounts owned by its Member
+// PDAs. The hook cannot depend on the dao crate, so the parts of the Member
+// layout it needs are mirrored here.
+pub const DAO_PROGRAM_ID: Pubkey = pubkey!("44dqWPQqXs2TJ1DLDUBvpmLQz3NDBVgFsT5nZ5KkPoT5");
+
+pub const DAO_MEMBER_DISCRIMINATOR: [u8; 8] = account_discriminator(b"account:Member");
+
+// discriminator, authority, reputation_points, last_decay_ts, bump, locked_amount
+pub const DAO_MEMBER_LOCK_END_OFFSET: usize = 8 + 32 + 8 + 8 + 1 + 8;
+
+// The rewards program holds vesting grants in token accounts owned by its
+// VestingSchedule PDAs; escrowed RWD may only move to the beneficiary or back
+// to the granting authority.
+pub const REWARDS_PROGRAM_ID: Pubkey = pubkey!("6NYSjPnBM6zH4VSxcMqUgGohHt9ggQpinetq1zi89dvw");
+
+pub const REWARDS_VESTING_DISCRIMINATOR: [u8; 8] = account_discriminator(b"account:VestingSchedule");
+
+// discriminator, then authority and beneficiary
+pub const REWARDS_VESTING_AUTHORITY_OFFSET: usize = 8;
+pub const REWARDS_VESTING_BENEFICIARY_OFFSET: usize = 8 + 32;
+
+// The only mint this hook serves: the rewards Token-2022 mint PDA
+pub const REWARDS_MINT_SEED: &[u8] = b"token-2022";
+
+// Point lots are recorded and expired by the rewards program, signing with the
+// PDA at this seed
+pub const REWARDS_POINTS_EXPIRY_SEED: &[u8] = b"points-expiry";
+
+// Soulbound mode lives in the rewards TransferPolicy PDA:
+// discriminator, authority, soulbound, whitelist (u32 length + pubkeys)
+pub const REWARDS_TRANSFER_POLICY_SEED: &[u8] = b"transfer-policy";
+
+pub const REWARDS_TRANSFER_POLICY_DISCRIMINATOR: [u8; 8] = account_discriminator(b"account:TransferPolicy");
+
+pub const REWARDS_TRANSFER_POLICY_SOULBOUND_OFFSET: usize = 8 + 32;
+pub const REWARDS_TRANSFER_POLICY_WHITELIST_OFFSET: usize = 8 + 32 + 1;
+
+// Sanctioned wallets have a rewards BlacklistEntry PDA at [BLACKLIST_SEED, wallet]
+pub const REWARDS_BLACKLIST_SEED: &[u8] = b"blacklist";
+
+pub const REWARDS_BLACKLIST_DISCRIMINATOR: [u8; 8] = account_discriminator(b"account:BlacklistEntry");
+
+// Clawbacks move RWD out of any holder's account with the permanent delegate PDA
+// as the transfer authority
+pub const REWARDS_PERMANENT_DELEGATE_SEED: &[u8] = b"permanent-delegate";
+
+// Protocol fees, reported in TransferEvent: discriminator, mint_fee_bps, transfer_fee_bps
+pub const REWARDS_FEES_SEED: &[u8] = b"fees";
+
+pub const REWARDS_FEES_DISCRIMINATOR: [u8; 8] = account_discriminator(b"account:Fees");
+
+pub const REWARDS_FEES_TRANSFER_FEE_BPS_OFFSET: usize = 8 + 2;
+
+// A global rewards freeze also stops transfers. FreezeState layout: discriminator,
+// is_frozen, freeze_mint, freeze_burn, authority, then variable-length fields
+// before `frozen_until` (see `rewards_global_freeze`)
+pub const REWARDS_FREEZE_SEED: &[u8] = b"freeze";
+
+pub const REWARDS_FREEZE_STATE_DISCRIMINATOR: [u8; 8] = account_discriminator(b"account:FreezeState");
+
+pub const REWARDS_FREEZE_STATE_IS_FROZEN_OFFSET: usize = 8;
+pub const REWARDS_FREEZE_STATE_PENDING_AUTHORITY_OFFSET: usize = 8 + 3 + 32;
+
+// Per-transfer and per-epoch limits in the rewards TransferLimits PDA:
+// discriminator, authority, per_transfer_max, epoch_limit, epoch_duration,
+// exemptions (u32 length + pubkeys)
+pub const REWARDS_TRANSFER_LIMITS_SEED: &[u8] = b"transfer-limits";
+
+pub const REWARDS_TRANSFER_LIMITS_DISCRIMINATOR: [u8; 8] = account_discriminator(b"account:TransferLimits");
+
+pub const REWARDS_TRANSFER_LIMITS_PER_TRANSFER_MAX_OFFSET: usize = 8 + 32;
+pub const REWARDS_TRANSFER_LIMITS_EPOCH_LIMIT_OFFSET: usize = 8 + 32 + 8;
+pub const REWARDS_TRANSFER_LIMITS_EPOCH_DURATION_OFFSET: usize = 8 + 32 + 16;
+pub const REWARDS_TRANSFER_LIMITS_EXEMPTIONS_OFFSET: usize = 8 + 32 + 24;
+
+const fn account_discriminator(preimage: &[u8]) -> [u8; 8] {
+    let hash = sha2_const::Sha256::new().update(preimage).finalize();
+    [hash[0], hash[1], hash[2], hash[3], hash[4], hash[5], hash[6], hash[7]]
+}
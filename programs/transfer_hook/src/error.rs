@@ -13,4 +13,32 @@ pub enum TokenError {
 
     #[msg("The token is not currently transferring")]
     IsNotCurrentlyTransferring, // 6003
-}
\ No newline at end of file
+
+    #[msg("RWD is vote-locked until the lock ends")]
+    TokensLocked, // 6004
+
+    #[msg("Vesting escrow can only release to its beneficiary or authority")]
+    InvalidVestingRecipient, // 6005
+
+    #[msg("Lot ledger is full; expire old points first")]
+    LotLedgerFull, // 6006
+
+    #[msg("RWD is soulbound; transfers are limited to whitelisted program vaults")]
+    Soulbound, // 6007
+
+    #[msg("Source or destination owner is blacklisted")]
+    Blacklisted, // 6008
+
+    #[msg("RWD transfers are frozen")]
+    GloballyFrozen, // 6009
+
+    #[msg("Transfer exceeds the per-transfer or per-epoch limit")]
+    TransferLimitExceeded, // 6010
+
+    // No longer raised: senders without a counter start from zero usage
+    #[msg("Sender needs a transfer counter while epoch limits are active")]
+    TransferCounterMissing, // 6011
+
+    #[msg("Mint is not registered with the transfer hook")]
+    MintNotRegistered, // 6012
+}
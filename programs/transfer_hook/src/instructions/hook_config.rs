@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+
+use anchor_spl::token_interface::Mint as Mint2022;
+
+use crate::constants::*;
+use crate::error::*;
+
+    // Binds the hook to the rewards mint. Only that mint's authority can do this,
+    // and it becomes the authority allowed to register the mint's meta list.
+    pub fn _initialize_hook_config(ctx: Context<InitializeHookConfig>) -> Result<()> {
+        let hook_config = &mut ctx.accounts.hook_config;
+        hook_config.authority = ctx.accounts.signer.key();
+        hook_config.mint = ctx.accounts.mint.key();
+        hook_config.bump = ctx.bumps.hook_config;
+        Ok(())
+    }
+
+#[derive(Accounts)]
+pub struct InitializeHookConfig<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [REWARDS_MINT_SEED],
+        bump,
+        seeds::program = REWARDS_PROGRAM_ID,
+        constraint = mint.mint_authority == COption::Some(signer.key()) @ TokenError::Unauthorized,
+    )]
+    pub mint: InterfaceAccount<'info, Mint2022>,
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [HOOK_CONFIG_SEED],
+        bump,
+        space = 8 + HookConfig::INIT_SPACE,
+    )]
+    pub hook_config: Account<'info, HookConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// The mint this hook serves and who may register its extra account metas
+#[account]
+#[derive(InitSpace)]
+pub struct HookConfig {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub bump: u8,
+}
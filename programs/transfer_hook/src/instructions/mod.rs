@@ -1,3 +1,10 @@
-
 pub mod transfer_hook;
-pub use transfer_hook::*;
\ No newline at end of file
+pub mod point_lots;
+pub mod transfer_limits;
+pub mod transfer_stats;
+pub mod hook_config;
+pub use transfer_hook::*;
+pub use point_lots::*;
+pub use transfer_limits::*;
+pub use transfer_stats::*;
+pub use hook_config::*;
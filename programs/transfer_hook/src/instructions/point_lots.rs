@@ -0,0 +1,210 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+
+    // Appends a lot for points issued to `owner`. Only the rewards program can issue,
+    // by signing with its points-expiry config PDA.
+    pub fn _record_lot(ctx: Context<RecordLot>, amount: u64, issued_at: i64) -> Result<()> {
+        let lot_ledger = &mut ctx.accounts.lot_ledger;
+        if lot_ledger.owner == Pubkey::default() {
+            lot_ledger.owner = ctx.accounts.owner.key();
+            lot_ledger.bump = ctx.bumps.lot_ledger;
+        }
+
+        lot_ledger.push(amount, issued_at)
+    }
+
+    // Drops every lot issued at or before `cutoff_ts`; the rewards program burns them
+    pub fn _expire_lots(ctx: Context<ExpireLots>, cutoff_ts: i64) -> Result<()> {
+        ctx.accounts.lot_ledger.expire(cutoff_ts);
+        Ok(())
+    }
+
+    // Burns bypass the hook, so the rewards program reports them here to keep the
+    // ledger from expiring points the owner no longer holds
+    pub fn _consume_lots(ctx: Context<ConsumeLots>, amount: u64) -> Result<()> {
+        ctx.accounts.lot_ledger.consume(amount);
+        Ok(())
+    }
+
+    impl LotLedger {
+        /// Appends a lot, merging it into the last one when issued at the same time. A
+        /// full ledger folds its two oldest lots into one under the later timestamp, so
+        /// issuance never fails and merged points never expire early.
+        pub fn push(&mut self, amount: u64, issued_at: i64) -> Result<()> {
+            match self.lots.last_mut() {
+                Some(last) if last.issued_at == issued_at => last.amount += amount,
+                _ => {
+                    if self.lots.len() >= MAX_POINT_LOTS {
+                        let oldest = self.lots.remove(0);
+                        self.lots[0].amount += oldest.amount;
+                    }
+                    self.lots.push(PointLot { amount, issued_at });
+                }
+            }
+            Ok(())
+        }
+
+        /// Spends `amount` from the oldest lots first. Anything beyond the tracked lots
+        /// came from untracked sources and is ignored.
+        pub fn consume(&mut self, mut amount: u64) {
+            let mut spent = 0;
+            for lot in self.lots.iter_mut() {
+                if amount == 0 {
+                    break;
+                }
+                let used = lot.amount.min(amount);
+                lot.amount -= used;
+                amount -= used;
+                if lot.amount == 0 {
+                    spent += 1;
+                }
+            }
+            self.lots.drain(..spent);
+        }
+
+        /// Total amount held in lots issued at or before `cutoff_ts`.
+        pub fn expired_amount(&self, cutoff_ts: i64) -> u64 {
+            self.lots
+                .iter()
+                .take_while(|lot| lot.issued_at <= cutoff_ts)
+                .map(|lot| lot.amount)
+                .sum()
+        }
+
+        pub fn expire(&mut self, cutoff_ts: i64) {
+            let expired = self.lots.iter().take_while(|lot| lot.issued_at <= cutoff_ts).count();
+            self.lots.drain(..expired);
+        }
+    }
+
+#[derive(Accounts)]
+pub struct RecordLot<'info> {
+    #[account(
+        seeds = [REWARDS_POINTS_EXPIRY_SEED],
+        bump,
+        seeds::program = REWARDS_PROGRAM_ID,
+    )]
+    pub issuer: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: wallet the points were issued to, only used to derive the ledger
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [LOT_LEDGER_SEED, owner.key().as_ref()],
+        bump,
+        space = 8 + LotLedger::INIT_SPACE,
+    )]
+    pub lot_ledger: Account<'info, LotLedger>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExpireLots<'info> {
+    #[account(
+        seeds = [REWARDS_POINTS_EXPIRY_SEED],
+        bump,
+        seeds::program = REWARDS_PROGRAM_ID,
+    )]
+    pub issuer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOT_LEDGER_SEED, lot_ledger.owner.as_ref()],
+        bump = lot_ledger.bump,
+    )]
+    pub lot_ledger: Account<'info, LotLedger>,
+}
+
+#[derive(Accounts)]
+pub struct ConsumeLots<'info> {
+    #[account(
+        seeds = [REWARDS_POINTS_EXPIRY_SEED],
+        bump,
+        seeds::program = REWARDS_PROGRAM_ID,
+    )]
+    pub issuer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOT_LEDGER_SEED, lot_ledger.owner.as_ref()],
+        bump = lot_ledger.bump,
+    )]
+    pub lot_ledger: Account<'info, LotLedger>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, Debug, PartialEq)]
+pub struct PointLot {
+    pub amount: u64,
+    pub issued_at: i64,
+}
+
+// Oldest lot first; transfers out of `owner`'s token accounts consume from the front
+#[account]
+#[derive(InitSpace)]
+pub struct LotLedger {
+    pub owner: Pubkey,
+    pub bump: u8,
+    #[max_len(MAX_POINT_LOTS)]
+    pub lots: Vec<PointLot>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ledger(lots: &[(u64, i64)]) -> LotLedger {
+        LotLedger {
+            owner: Pubkey::default(),
+            bump: 0,
+            lots: lots.iter().map(|(amount, issued_at)| PointLot { amount: *amount, issued_at: *issued_at }).collect(),
+        }
+    }
+
+    #[test]
+    fn consume_spends_oldest_lots_first() {
+        let mut ledger = ledger(&[(10, 1), (20, 2), (30, 3)]);
+        ledger.consume(25);
+        assert_eq!(ledger.lots, vec![PointLot { amount: 5, issued_at: 2 }, PointLot { amount: 30, issued_at: 3 }]);
+
+        ledger.consume(100);
+        assert!(ledger.lots.is_empty());
+    }
+
+    #[test]
+    fn push_merges_lots_from_the_same_timestamp() {
+        let mut ledger = ledger(&[(10, 1)]);
+        ledger.push(5, 1).unwrap();
+        ledger.push(7, 2).unwrap();
+        assert_eq!(ledger.lots, vec![PointLot { amount: 15, issued_at: 1 }, PointLot { amount: 7, issued_at: 2 }]);
+    }
+
+    #[test]
+    fn push_merges_the_oldest_lots_of_a_full_ledger() {
+        let mut ledger = ledger(&[]);
+        for issued_at in 0..MAX_POINT_LOTS as i64 {
+            ledger.push(1, issued_at).unwrap();
+        }
+        ledger.push(1, MAX_POINT_LOTS as i64).unwrap();
+        assert_eq!(ledger.lots.len(), MAX_POINT_LOTS);
+        assert_eq!(ledger.lots[0], PointLot { amount: 2, issued_at: 1 });
+        assert_eq!(ledger.lots.last(), Some(&PointLot { amount: 1, issued_at: MAX_POINT_LOTS as i64 }));
+        assert_eq!(ledger.expired_amount(MAX_POINT_LOTS as i64), MAX_POINT_LOTS as u64 + 1);
+    }
+
+    #[test]
+    fn expire_drops_lots_up_to_the_cutoff() {
+        let mut ledger = ledger(&[(10, 1), (20, 2), (30, 3)]);
+        assert_eq!(ledger.expired_amount(0), 0);
+        assert_eq!(ledger.expired_amount(2), 30);
+
+        ledger.expire(2);
+        assert_eq!(ledger.lots, vec![PointLot { amount: 30, issued_at: 3 }]);
+    }
+}
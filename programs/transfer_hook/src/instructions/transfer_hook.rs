@@ -1,15 +1,20 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
 use std::cell::RefMut;
 
 use anchor_spl::{
     associated_token::AssociatedToken,
     token_2022::spl_token_2022::{
         extension::{
+            transfer_fee::TransferFeeConfig,
             transfer_hook::TransferHookAccount,
+            BaseStateWithExtensions,
             BaseStateWithExtensionsMut,
             PodStateWithExtensionsMut,
+            StateWithExtensions,
         },
         pod::PodAccount,
+        state::Mint,
     },
     token_interface::{
         Mint as Mint2022,
@@ -23,6 +28,7 @@ use spl_transfer_hook_interface::instruction::ExecuteInstruction;
 use crate::constants::*;
 use crate::error::*;
 use crate::events::*;
+use crate::instructions::{HookConfig, LotLedger, OwnerStats, TransferCounter, TransferLimits, TransferStats};
 
 
     #[interface(spl_transfer_hook_interface::initialize_extra_account_meta_list)]
@@ -42,17 +48,81 @@ use crate::events::*;
         Ok(())
     }
 
+    // Rewrites the list with the current `extra_account_metas`, growing or shrinking
+    // the account as needed, so hook accounts can change without a new mint
+    pub fn _update_extra_account_meta_list(ctx: Context<UpdateExtraAccountMetaList>) -> Result<()> {
+        let extra_account_metas = InitializeExtraAccountMetaList::extra_account_metas()?;
+        let meta_list = &ctx.accounts.extra_account_meta_list;
+
+        let new_len = ExtraAccountMetaList::size_of(extra_account_metas.len())?;
+        let extra_lamports = Rent::get()?.minimum_balance(new_len).saturating_sub(meta_list.lamports());
+        if extra_lamports > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.authority.to_account_info(),
+                        to: meta_list.to_account_info(),
+                    },
+                ),
+                extra_lamports,
+            )?;
+        }
+        meta_list.realloc(new_len, false)?;
+
+        ExtraAccountMetaList::update::<ExecuteInstruction>(
+            &mut ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?,
+            &extra_account_metas,
+        )?;
+
+        Ok(())
+    }
+
     #[interface(spl_transfer_hook_interface::execute)]
     pub fn _transfer_hook(ctx: Context<TransferHook>, amount: u64) -> Result<()> {
 
         // Fail this instruction if it is not called from within a transfer hook
         check_is_transferring(&ctx)?;
+
+        // Only the rewards permanent delegate may sign for an account it doesn't own.
+        // Clawbacks skip the holder-side restrictions so sanctioned or soulbound
+        // balances can still be recovered.
+        let clawback = ctx.accounts.source_token.owner != ctx.accounts.owner.key();
+        if !clawback {
+            check_not_vote_locked(&ctx.accounts.owner)?;
+            check_vesting_recipient(&ctx.accounts.owner, &ctx.accounts.destination_token.owner)?;
+            check_not_blacklisted(&ctx.accounts.source_blacklist)?;
+            check_not_globally_frozen(&ctx.accounts.freeze_state)?;
+            check_transfer_limits(
+                &ctx.accounts.transfer_limits,
+                &ctx.accounts.transfer_counter,
+                &ctx.accounts.owner,
+                amount,
+            )?;
+            check_soulbound(
+                &ctx.accounts.transfer_policy,
+                &ctx.accounts.owner,
+                &ctx.accounts.destination_token.owner,
+            )?;
+        }
+        check_not_blacklisted(&ctx.accounts.destination_blacklist)?;
+        consume_point_lots(&ctx.accounts.lot_ledger, amount)?;
         msg!("Transfer Hook invoked. Destination: {:?}", ctx.accounts.destination_token.key());
 
+        let fee_amount = transfer_fee(&ctx.accounts.fees, amount)?;
+        let withheld_fee = withheld_fee(&ctx.accounts.mint.to_account_info(), amount)?;
+        record_transfer_stats(
+            &ctx.accounts.transfer_stats,
+            &ctx.accounts.source_owner_stats,
+            &ctx.accounts.destination_owner_stats,
+            amount,
+            withheld_fee,
+        )?;
+
         emit!(TransferEvent {
             source: ctx.accounts.source_token.key(),
             destination: ctx.accounts.destination_token.key(),
-            fee_amount: 0, //TODO: add value when task complete
+            fee_amount,
             amount,
         });
     
@@ -74,9 +144,398 @@ use crate::events::*;
         Ok(())
     }
 
+    // Sources owned by a dao Member PDA are vote-lock vaults and stay put until `lock_end`
+    fn check_not_vote_locked(owner: &AccountInfo) -> Result<()> {
+        if *owner.owner != DAO_PROGRAM_ID {
+            return Ok(());
+        }
+
+        let data = owner.try_borrow_data()?;
+        let lock_end_bytes = match data.get(DAO_MEMBER_LOCK_END_OFFSET..DAO_MEMBER_LOCK_END_OFFSET + 8) {
+            Some(bytes) if data[..8] == DAO_MEMBER_DISCRIMINATOR => bytes,
+            _ => return Ok(()),
+        };
+
+        let lock_end = i64::from_le_bytes(lock_end_bytes.try_into().unwrap());
+        if Clock::get()?.unix_timestamp < lock_end {
+            msg!("Transfer operation not allowed: the source is vote-locked.");
+            return Err(TokenError::TokensLocked.into());
+        }
+
+        Ok(())
+    }
+
+    // Sources owned by a rewards VestingSchedule PDA are vesting escrows
+    fn check_vesting_recipient(owner: &AccountInfo, recipient: &Pubkey) -> Result<()> {
+        if *owner.owner != REWARDS_PROGRAM_ID {
+            return Ok(());
+        }
+
+        let data = owner.try_borrow_data()?;
+        if data.len() < REWARDS_VESTING_BENEFICIARY_OFFSET + 32 || data[..8] != REWARDS_VESTING_DISCRIMINATOR {
+            return Ok(());
+        }
+
+        let authority = &data[REWARDS_VESTING_AUTHORITY_OFFSET..REWARDS_VESTING_AUTHORITY_OFFSET + 32];
+        let beneficiary = &data[REWARDS_VESTING_BENEFICIARY_OFFSET..REWARDS_VESTING_BENEFICIARY_OFFSET + 32];
+        if recipient.as_ref() != authority && recipient.as_ref() != beneficiary {
+            msg!("Transfer operation not allowed: vesting escrow recipient is not the beneficiary or authority.");
+            return Err(TokenError::InvalidVestingRecipient.into());
+        }
+
+        Ok(())
+    }
+
+    fn check_not_blacklisted(blacklist_entry: &AccountInfo) -> Result<()> {
+        if *blacklist_entry.owner != REWARDS_PROGRAM_ID {
+            return Ok(());
+        }
+
+        let data = blacklist_entry.try_borrow_data()?;
+        if data.get(..8) == Some(&REWARDS_BLACKLIST_DISCRIMINATOR[..]) {
+            msg!("Transfer operation not allowed: {} is blacklisted.", blacklist_entry.key);
+            return Err(TokenError::Blacklisted.into());
+        }
+
+        Ok(())
+    }
+
+    // In soulbound mode RWD only moves out of or into whitelisted program vaults
+    fn check_soulbound(transfer_policy: &AccountInfo, owner: &AccountInfo, recipient: &Pubkey) -> Result<()> {
+        if *transfer_policy.owner != REWARDS_PROGRAM_ID {
+            return Ok(());
+        }
+
+        let data = transfer_policy.try_borrow_data()?;
+        let whitelist = match soulbound_whitelist(&data) {
+            Some(whitelist) => whitelist,
+            None => return Ok(()),
+        };
+
+        if !transfer_whitelisted(&whitelist, owner.key, owner.owner, recipient) {
+            msg!("Transfer operation not allowed: RWD is soulbound.");
+            return Err(TokenError::Soulbound.into());
+        }
+
+        Ok(())
+    }
+
+    /// Whitelist of a soulbound TransferPolicy, or None when transfers are unrestricted.
+    pub fn soulbound_whitelist(data: &[u8]) -> Option<Vec<Pubkey>> {
+        if data.get(..8)? != REWARDS_TRANSFER_POLICY_DISCRIMINATOR
+            || *data.get(REWARDS_TRANSFER_POLICY_SOULBOUND_OFFSET)? == 0
+        {
+            return None;
+        }
+
+        let len_bytes = data.get(REWARDS_TRANSFER_POLICY_WHITELIST_OFFSET..REWARDS_TRANSFER_POLICY_WHITELIST_OFFSET + 4)?;
+        let len = u32::from_le_bytes(len_bytes.try_into().unwrap()) as usize;
+        let start = REWARDS_TRANSFER_POLICY_WHITELIST_OFFSET + 4;
+        let entries = data.get(start..start + len * 32).unwrap_or(&[]);
+
+        Some(entries.chunks_exact(32).map(|entry| Pubkey::try_from(entry).unwrap()).collect())
+    }
+
+    /// A soulbound transfer passes when the source authority, or the program owning it,
+    /// is whitelisted, or when the destination authority is.
+    pub fn transfer_whitelisted(
+        whitelist: &[Pubkey],
+        source_authority: &Pubkey,
+        source_program: &Pubkey,
+        destination_authority: &Pubkey,
+    ) -> bool {
+        whitelist.contains(source_authority)
+            || whitelist.contains(source_program)
+            || whitelist.contains(destination_authority)
+    }
+
+    fn check_not_globally_frozen(freeze_state: &AccountInfo) -> Result<()> {
+        if *freeze_state.owner != REWARDS_PROGRAM_ID {
+            return Ok(());
+        }
+
+        let frozen_until = match rewards_global_freeze(&freeze_state.try_borrow_data()?) {
+            Some(frozen_until) => frozen_until,
+            None => return Ok(()),
+        };
+        if frozen_until == 0 || Clock::get()?.unix_timestamp < frozen_until {
+            msg!("Transfer operation not allowed: RWD is frozen.");
+            return Err(TokenError::GloballyFrozen.into());
+        }
+
+        Ok(())
+    }
+
+    /// Lapse timestamp of a global rewards freeze (0 while it lasts until unfrozen),
+    /// or None when transfers aren't frozen. A freeze whose timestamp can't be read
+    /// is treated as indefinite.
+    pub fn rewards_global_freeze(data: &[u8]) -> Option<i64> {
+        if data.get(..8)? != REWARDS_FREEZE_STATE_DISCRIMINATOR
+            || *data.get(REWARDS_FREEZE_STATE_IS_FROZEN_OFFSET)? == 0
+        {
+            return None;
+        }
+
+        let read_len = |offset: usize| -> Option<usize> {
+            Some(u32::from_le_bytes(data.get(offset..offset + 4)?.try_into().ok()?) as usize)
+        };
+        let frozen_until = || -> Option<i64> {
+            let mut offset = REWARDS_FREEZE_STATE_PENDING_AUTHORITY_OFFSET;
+            offset += if *data.get(offset)? == 1 { 1 + 32 } else { 1 }; // pending_authority
+            offset += 4 + 32 * read_len(offset)?; // pausers
+            offset += 1; // unfreeze_threshold
+            offset += if *data.get(offset)? == 1 { 2 } else { 1 }; // unfreeze_target
+            offset += 4 + 32 * read_len(offset)?; // unfreeze_approvals
+            Some(i64::from_le_bytes(data.get(offset..offset + 8)?.try_into().ok()?))
+        };
+
+        Some(frozen_until().unwrap_or(0))
+    }
+
+    fn check_transfer_limits(
+        transfer_limits: &AccountInfo,
+        transfer_counter: &AccountInfo,
+        owner: &AccountInfo,
+        amount: u64,
+    ) -> Result<()> {
+        if *transfer_limits.owner != REWARDS_PROGRAM_ID {
+            return Ok(());
+        }
+
+        let limits = match TransferLimits::parse(&transfer_limits.try_borrow_data()?) {
+            Some(limits) => limits,
+            None => return Ok(()),
+        };
+        if limits.is_exempt(owner.key) {
+            return Ok(());
+        }
+
+        limits.check_per_transfer(amount)?;
+        if limits.epoch_limit == 0 {
+            return Ok(());
+        }
+
+        // The hook has no payer to create a counter with, so a sender without one is
+        // checked as if nothing had been sent this epoch
+        let now = Clock::get()?.unix_timestamp;
+        match load_hook_account::<TransferCounter>(transfer_counter)? {
+            Some(mut counter) => {
+                counter.record(&limits, amount, now)?;
+                counter.try_serialize(&mut &mut transfer_counter.try_borrow_mut_data()?[..])?;
+            }
+            None => TransferCounter::default().record(&limits, amount, now)?,
+        }
+
+        Ok(())
+    }
+
+    // Protocol transfer fee owed on `amount`, 0 while fees aren't configured
+    fn transfer_fee(fees: &AccountInfo, amount: u64) -> Result<u64> {
+        if *fees.owner != REWARDS_PROGRAM_ID {
+            return Ok(0);
+        }
+
+        let data = fees.try_borrow_data()?;
+        if data.get(..8) != Some(&REWARDS_FEES_DISCRIMINATOR[..]) {
+            return Ok(0);
+        }
+        let fee_bps = data
+            .get(REWARDS_FEES_TRANSFER_FEE_BPS_OFFSET..REWARDS_FEES_TRANSFER_FEE_BPS_OFFSET + 2)
+            .map(|bytes| u16::from_le_bytes(bytes.try_into().unwrap()))
+            .unwrap_or(0);
+
+        Ok((amount as u128 * fee_bps as u128 / 10_000) as u64)
+    }
+
+    // Fee Token-2022 withheld from this transfer, 0 unless the mint has the transfer
+    // fee extension. Unlike `transfer_fee`, this is what was actually collected.
+    fn withheld_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+        let data = mint.try_borrow_data()?;
+        let mint = StateWithExtensions::<Mint>::unpack(&data)?;
+        let fee = match mint.get_extension::<TransferFeeConfig>() {
+            Ok(config) => config.calculate_epoch_fee(Clock::get()?.epoch, amount).unwrap_or(0),
+            Err(_) => 0,
+        };
+
+        Ok(fee)
+    }
+
+    pub fn is_rewards_permanent_delegate(authority: &Pubkey) -> bool {
+        let (permanent_delegate, _) =
+            Pubkey::find_program_address(&[REWARDS_PERMANENT_DELEGATE_SEED], &REWARDS_PROGRAM_ID);
+        *authority == permanent_delegate
+    }
+
+    // Transfers spend the sender's oldest point lots; senders without a ledger have
+    // nothing that expires
+    fn consume_point_lots(lot_ledger: &AccountInfo, amount: u64) -> Result<()> {
+        if lot_ledger.owner != &crate::ID || lot_ledger.data_is_empty() {
+            return Ok(());
+        }
+
+        let mut ledger = LotLedger::try_deserialize(&mut &lot_ledger.try_borrow_data()?[..])?;
+        ledger.consume(amount);
+        ledger.try_serialize(&mut &mut lot_ledger.try_borrow_mut_data()?[..])?;
+
+        Ok(())
+    }
+
+    // Updates whichever stats accounts exist; a missing account is skipped, so stats
+    // never block a transfer. Senders only count as unique through their OwnerStats.
+    fn record_transfer_stats(
+        transfer_stats: &AccountInfo,
+        source_owner_stats: &AccountInfo,
+        destination_owner_stats: &AccountInfo,
+        amount: u64,
+        withheld_fee: u64,
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+
+        let mut new_sender = false;
+        if let Some(mut stats) = load_hook_account::<OwnerStats>(source_owner_stats)? {
+            new_sender = stats.record_sent(amount, clock.slot, clock.epoch);
+            stats.try_serialize(&mut &mut source_owner_stats.try_borrow_mut_data()?[..])?;
+        }
+        // Self-transfers pass the same account twice; reload it after the write above
+        if let Some(mut stats) = load_hook_account::<OwnerStats>(destination_owner_stats)? {
+            stats.record_received(amount, clock.slot);
+            stats.try_serialize(&mut &mut destination_owner_stats.try_borrow_mut_data()?[..])?;
+        }
+        if let Some(mut stats) = load_hook_account::<TransferStats>(transfer_stats)? {
+            stats.record(amount, withheld_fee, clock.epoch, new_sender);
+            stats.try_serialize(&mut &mut transfer_stats.try_borrow_mut_data()?[..])?;
+        }
+
+        Ok(())
+    }
+
+    fn load_hook_account<T: AccountDeserialize>(account: &AccountInfo) -> Result<Option<T>> {
+        if account.owner != &crate::ID || account.data_is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(T::try_deserialize(&mut &account.try_borrow_data()?[..])?))
+    }
+
     impl<'info> InitializeExtraAccountMetaList<'info> {
         pub fn extra_account_metas() -> Result<Vec<ExtraAccountMeta>> {
-            Ok(vec![]) //TODO: Add any extra accounts
+            Ok(vec![
+                // Lot ledger of the source token account's owner
+                ExtraAccountMeta::new_with_seeds(
+                    &[
+                        Seed::Literal { bytes: LOT_LEDGER_SEED.to_vec() },
+                        Seed::AccountData {
+                            account_index: 0,
+                            data_index: TOKEN_ACCOUNT_OWNER_OFFSET,
+                            length: 32,
+                        },
+                    ],
+                    false,
+                    true,
+                )?,
+                // Rewards transfer policy, for soulbound mode
+                ExtraAccountMeta::new_with_pubkey(
+                    &Pubkey::find_program_address(&[REWARDS_TRANSFER_POLICY_SEED], &REWARDS_PROGRAM_ID).0,
+                    false,
+                    false,
+                )?,
+                // Rewards program, so its blacklist PDAs can be derived below (index 7)
+                ExtraAccountMeta::new_with_pubkey(&REWARDS_PROGRAM_ID, false, false)?,
+                // Blacklist entries of the source and destination owners
+                ExtraAccountMeta::new_external_pda_with_seeds(
+                    7,
+                    &[
+                        Seed::Literal { bytes: REWARDS_BLACKLIST_SEED.to_vec() },
+                        Seed::AccountData {
+                            account_index: 0,
+                            data_index: TOKEN_ACCOUNT_OWNER_OFFSET,
+                            length: 32,
+                        },
+                    ],
+                    false,
+                    false,
+                )?,
+                ExtraAccountMeta::new_external_pda_with_seeds(
+                    7,
+                    &[
+                        Seed::Literal { bytes: REWARDS_BLACKLIST_SEED.to_vec() },
+                        Seed::AccountData {
+                            account_index: 2,
+                            data_index: TOKEN_ACCOUNT_OWNER_OFFSET,
+                            length: 32,
+                        },
+                    ],
+                    false,
+                    false,
+                )?,
+                // Rewards fees and freeze state
+                ExtraAccountMeta::new_external_pda_with_seeds(
+                    7,
+                    &[Seed::Literal { bytes: REWARDS_FEES_SEED.to_vec() }],
+                    false,
+                    false,
+                )?,
+                ExtraAccountMeta::new_external_pda_with_seeds(
+                    7,
+                    &[Seed::Literal { bytes: REWARDS_FREEZE_SEED.to_vec() }],
+                    false,
+                    false,
+                )?,
+                // Rewards transfer limits and the source owner's transfer counter
+                ExtraAccountMeta::new_external_pda_with_seeds(
+                    7,
+                    &[Seed::Literal { bytes: REWARDS_TRANSFER_LIMITS_SEED.to_vec() }],
+                    false,
+                    false,
+                )?,
+                ExtraAccountMeta::new_with_seeds(
+                    &[
+                        Seed::Literal { bytes: TRANSFER_COUNTER_SEED.to_vec() },
+                        Seed::AccountData {
+                            account_index: 0,
+                            data_index: TOKEN_ACCOUNT_OWNER_OFFSET,
+                            length: 32,
+                        },
+                    ],
+                    false,
+                    true,
+                )?,
+                // Global transfer stats and the source and destination owners' stats
+                ExtraAccountMeta::new_with_seeds(
+                    &[Seed::Literal { bytes: TRANSFER_STATS_SEED.to_vec() }],
+                    false,
+                    true,
+                )?,
+                ExtraAccountMeta::new_with_seeds(
+                    &[
+                        Seed::Literal { bytes: OWNER_STATS_SEED.to_vec() },
+                        Seed::AccountData {
+                            account_index: 0,
+                            data_index: TOKEN_ACCOUNT_OWNER_OFFSET,
+                            length: 32,
+                        },
+                    ],
+                    false,
+                    true,
+                )?,
+                ExtraAccountMeta::new_with_seeds(
+                    &[
+                        Seed::Literal { bytes: OWNER_STATS_SEED.to_vec() },
+                        Seed::AccountData {
+                            account_index: 2,
+                            data_index: TOKEN_ACCOUNT_OWNER_OFFSET,
+                            length: 32,
+                        },
+                    ],
+                    false,
+                    true,
+                )?,
+                // Hook config, binding the hook to the rewards mint
+                ExtraAccountMeta::new_with_seeds(
+                    &[Seed::Literal { bytes: HOOK_CONFIG_SEED.to_vec() }],
+                    false,
+                    false,
+                )?,
+            ])
         }
     }
     
@@ -102,6 +561,38 @@ pub struct InitializeExtraAccountMetaList<'info> {
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 
+    #[account(
+        seeds = [HOOK_CONFIG_SEED],
+        bump = hook_config.bump,
+        constraint = hook_config.mint == mint.key() @ TokenError::MintNotRegistered,
+        constraint = hook_config.authority == signer.key() @ TokenError::Unauthorized,
+    )]
+    pub hook_config: Account<'info, HookConfig>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateExtraAccountMetaList<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint2022>,
+
+    /// CHECK: ExtraAccountMetaList Account, must use these seeds
+    #[account(
+        mut,
+        seeds = [META_LIST_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_account_meta_list: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        seeds = [HOOK_CONFIG_SEED],
+        bump = hook_config.bump,
+        constraint = hook_config.mint == mint.key() @ TokenError::MintNotRegistered,
+        has_one = authority @ TokenError::Unauthorized,
+    )]
+    pub hook_config: Account<'info, HookConfig>,
 }
 
 // Order of accounts matters for this struct.
@@ -112,7 +603,8 @@ pub struct InitializeExtraAccountMetaList<'info> {
 pub struct TransferHook<'info> {
     #[account(
         token::mint = mint, 
-        token::authority = owner,
+        constraint = source_token.owner == owner.key()
+            || is_rewards_permanent_delegate(owner.key) @ anchor_lang::error::ErrorCode::ConstraintTokenOwner,
     )]
     pub source_token: InterfaceAccount<'info, TokenAccount2022>,
     pub mint: InterfaceAccount<'info, Mint2022>,
@@ -128,4 +620,166 @@ pub struct TransferHook<'info> {
         bump
     )]
     pub extra_account_meta_list: UncheckedAccount<'info>,
-}
\ No newline at end of file
+    /// CHECK: LotLedger of the source owner; may not exist
+    #[account(
+        mut,
+        seeds = [LOT_LEDGER_SEED, source_token.owner.as_ref()],
+        bump
+    )]
+    pub lot_ledger: UncheckedAccount<'info>,
+    /// CHECK: rewards TransferPolicy; may not exist
+    #[account(
+        seeds = [REWARDS_TRANSFER_POLICY_SEED],
+        bump,
+        seeds::program = REWARDS_PROGRAM_ID,
+    )]
+    pub transfer_policy: UncheckedAccount<'info>,
+    /// CHECK: rewards program, only used to derive the blacklist PDAs
+    #[account(address = REWARDS_PROGRAM_ID)]
+    pub rewards_program: UncheckedAccount<'info>,
+    /// CHECK: rewards BlacklistEntry of the source owner; may not exist
+    #[account(
+        seeds = [REWARDS_BLACKLIST_SEED, source_token.owner.as_ref()],
+        bump,
+        seeds::program = REWARDS_PROGRAM_ID,
+    )]
+    pub source_blacklist: UncheckedAccount<'info>,
+    /// CHECK: rewards BlacklistEntry of the destination owner; may not exist
+    #[account(
+        seeds = [REWARDS_BLACKLIST_SEED, destination_token.owner.as_ref()],
+        bump,
+        seeds::program = REWARDS_PROGRAM_ID,
+    )]
+    pub destination_blacklist: UncheckedAccount<'info>,
+    /// CHECK: rewards Fees; may not exist
+    #[account(
+        seeds = [REWARDS_FEES_SEED],
+        bump,
+        seeds::program = REWARDS_PROGRAM_ID,
+    )]
+    pub fees: UncheckedAccount<'info>,
+    /// CHECK: rewards FreezeState; may not exist
+    #[account(
+        seeds = [REWARDS_FREEZE_SEED],
+        bump,
+        seeds::program = REWARDS_PROGRAM_ID,
+    )]
+    pub freeze_state: UncheckedAccount<'info>,
+    /// CHECK: rewards TransferLimits; may not exist
+    #[account(
+        seeds = [REWARDS_TRANSFER_LIMITS_SEED],
+        bump,
+        seeds::program = REWARDS_PROGRAM_ID,
+    )]
+    pub transfer_limits: UncheckedAccount<'info>,
+    /// CHECK: TransferCounter of the source owner; may not exist
+    #[account(
+        mut,
+        seeds = [TRANSFER_COUNTER_SEED, source_token.owner.as_ref()],
+        bump
+    )]
+    pub transfer_counter: UncheckedAccount<'info>,
+    /// CHECK: TransferStats; may not exist
+    #[account(
+        mut,
+        seeds = [TRANSFER_STATS_SEED],
+        bump
+    )]
+    pub transfer_stats: UncheckedAccount<'info>,
+    /// CHECK: OwnerStats of the source owner; may not exist
+    #[account(
+        mut,
+        seeds = [OWNER_STATS_SEED, source_token.owner.as_ref()],
+        bump
+    )]
+    pub source_owner_stats: UncheckedAccount<'info>,
+    /// CHECK: OwnerStats of the destination owner; may not exist
+    #[account(
+        mut,
+        seeds = [OWNER_STATS_SEED, destination_token.owner.as_ref()],
+        bump
+    )]
+    pub destination_owner_stats: UncheckedAccount<'info>,
+    #[account(
+        seeds = [HOOK_CONFIG_SEED],
+        bump = hook_config.bump,
+        constraint = hook_config.mint == mint.key() @ TokenError::MintNotRegistered,
+    )]
+    pub hook_config: Account<'info, HookConfig>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy_data(soulbound: bool, whitelist: &[Pubkey]) -> Vec<u8> {
+        let mut data = REWARDS_TRANSFER_POLICY_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&[7; 32]);
+        data.push(soulbound as u8);
+        data.extend_from_slice(&(whitelist.len() as u32).to_le_bytes());
+        whitelist.iter().for_each(|entry| data.extend_from_slice(entry.as_ref()));
+        data.push(255);
+        data
+    }
+
+    #[test]
+    fn policy_without_soulbound_is_unrestricted() {
+        assert_eq!(soulbound_whitelist(&policy_data(false, &[Pubkey::new_unique()])), None);
+        assert_eq!(soulbound_whitelist(&[0; 8]), None);
+        assert_eq!(soulbound_whitelist(&[]), None);
+    }
+
+    #[test]
+    fn soulbound_policy_exposes_its_whitelist() {
+        let whitelist = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+        assert_eq!(soulbound_whitelist(&policy_data(true, &whitelist)), Some(whitelist));
+        assert_eq!(soulbound_whitelist(&policy_data(true, &[])), Some(vec![]));
+    }
+
+    #[test]
+    fn whitelist_matches_vault_authorities_and_programs() {
+        let program = Pubkey::new_unique();
+        let vault_authority = Pubkey::new_unique();
+        let (user, other) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let whitelist = [program, vault_authority];
+
+        assert!(transfer_whitelisted(&whitelist, &Pubkey::new_unique(), &program, &user));
+        assert!(transfer_whitelisted(&whitelist, &vault_authority, &System::id(), &user));
+        assert!(transfer_whitelisted(&whitelist, &user, &System::id(), &vault_authority));
+        assert!(!transfer_whitelisted(&whitelist, &user, &System::id(), &other));
+    }
+
+    fn freeze_state_data(is_frozen: bool, pending_authority: bool, pausers: usize, frozen_until: i64) -> Vec<u8> {
+        let mut data = REWARDS_FREEZE_STATE_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&[is_frozen as u8, 0, 0]);
+        data.extend_from_slice(&[7; 32]);
+        if pending_authority {
+            data.push(1);
+            data.extend_from_slice(&[8; 32]);
+        } else {
+            data.push(0);
+        }
+        data.extend_from_slice(&(pausers as u32).to_le_bytes());
+        (0..pausers).for_each(|_| data.extend_from_slice(&[9; 32]));
+        data.push(1); // unfreeze_threshold
+        data.extend_from_slice(&[1, 0]); // unfreeze_target: Some(All)
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&[9; 32]);
+        data.extend_from_slice(&frozen_until.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn global_freeze_reads_frozen_until_past_variable_fields() {
+        assert_eq!(rewards_global_freeze(&freeze_state_data(true, true, 3, 1_234)), Some(1_234));
+        assert_eq!(rewards_global_freeze(&freeze_state_data(true, false, 0, 0)), Some(0));
+        assert_eq!(rewards_global_freeze(&freeze_state_data(false, true, 3, 1_234)), None);
+    }
+
+    #[test]
+    fn truncated_global_freeze_is_indefinite() {
+        let data = freeze_state_data(true, false, 2, 1_234);
+        assert_eq!(rewards_global_freeze(&data[..data.len() - 4]), Some(0));
+        assert_eq!(rewards_global_freeze(&[0; 8]), None);
+    }
+}
@@ -0,0 +1,167 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::error::*;
+
+    // Permissionless: anyone can pay for a wallet's counter. Until it exists the
+    // wallet's transfers are checked against the epoch limit as if none came before.
+    pub fn _init_transfer_counter(ctx: Context<InitTransferCounter>) -> Result<()> {
+        let transfer_counter = &mut ctx.accounts.transfer_counter;
+        transfer_counter.owner = ctx.accounts.owner.key();
+        transfer_counter.epoch_start = 0;
+        transfer_counter.transferred = 0;
+        transfer_counter.bump = ctx.bumps.transfer_counter;
+        Ok(())
+    }
+
+    /// Limits mirrored from the rewards TransferLimits account.
+    #[derive(Debug, PartialEq)]
+    pub struct TransferLimits {
+        pub per_transfer_max: u64,
+        pub epoch_limit: u64,
+        pub epoch_duration: i64,
+        pub exemptions: Vec<Pubkey>,
+    }
+
+    impl TransferLimits {
+        /// Parses the rewards TransferLimits account, or None when it isn't one.
+        pub fn parse(data: &[u8]) -> Option<Self> {
+            if data.get(..8)? != REWARDS_TRANSFER_LIMITS_DISCRIMINATOR {
+                return None;
+            }
+
+            let read_u64 = |offset: usize| -> Option<u64> {
+                Some(u64::from_le_bytes(data.get(offset..offset + 8)?.try_into().ok()?))
+            };
+            let offset = REWARDS_TRANSFER_LIMITS_EXEMPTIONS_OFFSET;
+            let len = u32::from_le_bytes(data.get(offset..offset + 4)?.try_into().ok()?) as usize;
+            let exemptions = data.get(offset + 4..offset + 4 + len * 32)?;
+
+            Some(Self {
+                per_transfer_max: read_u64(REWARDS_TRANSFER_LIMITS_PER_TRANSFER_MAX_OFFSET)?,
+                epoch_limit: read_u64(REWARDS_TRANSFER_LIMITS_EPOCH_LIMIT_OFFSET)?,
+                epoch_duration: read_u64(REWARDS_TRANSFER_LIMITS_EPOCH_DURATION_OFFSET)? as i64,
+                exemptions: exemptions.chunks_exact(32).map(|entry| Pubkey::try_from(entry).unwrap()).collect(),
+            })
+        }
+
+        /// Only authorities on the exemption list are exempt; the program owning an
+        /// authority doesn't count, so listing a program exempts none of its PDAs.
+        pub fn is_exempt(&self, authority: &Pubkey) -> bool {
+            self.exemptions.contains(authority)
+        }
+
+        pub fn check_per_transfer(&self, amount: u64) -> Result<()> {
+            require!(
+                self.per_transfer_max == 0 || amount <= self.per_transfer_max,
+                TokenError::TransferLimitExceeded
+            );
+            Ok(())
+        }
+    }
+
+    impl TransferCounter {
+        /// Adds `amount` to the current epoch, starting a new epoch once the previous
+        /// one has run for `epoch_duration`.
+        pub fn record(&mut self, limits: &TransferLimits, amount: u64, now: i64) -> Result<()> {
+            if now >= self.epoch_start.saturating_add(limits.epoch_duration) {
+                self.epoch_start = now;
+                self.transferred = 0;
+            }
+
+            let transferred = self.transferred.saturating_add(amount);
+            require!(transferred <= limits.epoch_limit, TokenError::TransferLimitExceeded);
+            self.transferred = transferred;
+            Ok(())
+        }
+    }
+
+#[derive(Accounts)]
+pub struct InitTransferCounter<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: wallet whose outgoing transfers are counted
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [TRANSFER_COUNTER_SEED, owner.key().as_ref()],
+        bump,
+        space = 8 + TransferCounter::INIT_SPACE,
+    )]
+    pub transfer_counter: Account<'info, TransferCounter>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// RWD sent from `owner`'s token accounts since `epoch_start`
+#[account]
+#[derive(InitSpace, Default)]
+pub struct TransferCounter {
+    pub owner: Pubkey,
+    pub epoch_start: i64,
+    pub transferred: u64,
+    pub bump: u8,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limits(per_transfer_max: u64, epoch_limit: u64, epoch_duration: i64) -> TransferLimits {
+        TransferLimits { per_transfer_max, epoch_limit, epoch_duration, exemptions: vec![] }
+    }
+
+    fn counter() -> TransferCounter {
+        TransferCounter { owner: Pubkey::default(), epoch_start: 0, transferred: 0, bump: 0 }
+    }
+
+    #[test]
+    fn parses_rewards_transfer_limits() {
+        let exempt = Pubkey::new_unique();
+        let mut data = REWARDS_TRANSFER_LIMITS_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&[7; 32]);
+        data.extend_from_slice(&10u64.to_le_bytes());
+        data.extend_from_slice(&100u64.to_le_bytes());
+        data.extend_from_slice(&86_400i64.to_le_bytes());
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(exempt.as_ref());
+        data.push(255);
+
+        let parsed = TransferLimits::parse(&data).unwrap();
+        assert_eq!(parsed, TransferLimits { exemptions: vec![exempt], ..limits(10, 100, 86_400) });
+        assert!(parsed.is_exempt(&exempt));
+        assert!(!parsed.is_exempt(&Pubkey::new_unique()));
+        assert_eq!(TransferLimits::parse(&[0; 8]), None);
+    }
+
+    #[test]
+    fn per_transfer_max_of_zero_is_unlimited() {
+        assert!(limits(0, 0, 0).check_per_transfer(u64::MAX).is_ok());
+        assert!(limits(10, 0, 0).check_per_transfer(10).is_ok());
+        assert!(limits(10, 0, 0).check_per_transfer(11).is_err());
+    }
+
+    #[test]
+    fn counter_resets_after_the_epoch() {
+        let limits = limits(0, 100, 1_000);
+        let mut counter = counter();
+
+        counter.record(&limits, 60, 5_000).unwrap();
+        counter.record(&limits, 40, 5_999).unwrap();
+        assert!(counter.record(&limits, 1, 5_999).is_err());
+        assert_eq!(counter.transferred, 100);
+
+        counter.record(&limits, 70, 6_000).unwrap();
+        assert_eq!((counter.epoch_start, counter.transferred), (6_000, 70));
+    }
+
+    #[test]
+    fn missing_counter_counts_as_no_usage() {
+        let limits = limits(0, 100, 1_000);
+        assert!(counter().record(&limits, 100, 5_000).is_ok());
+        assert!(counter().record(&limits, 101, 5_000).is_err());
+    }
+}
@@ -0,0 +1,183 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+
+    // Permissionless: the stats account has no authority and only the hook writes to it
+    pub fn _init_transfer_stats(ctx: Context<InitTransferStats>) -> Result<()> {
+        ctx.accounts.transfer_stats.bump = ctx.bumps.transfer_stats;
+        Ok(())
+    }
+
+    // Permissionless: anyone can pay for a wallet's stats. Transfers by wallets
+    // without one still count towards the global totals, but not as unique senders.
+    pub fn _init_owner_stats(ctx: Context<InitOwnerStats>) -> Result<()> {
+        let owner_stats = &mut ctx.accounts.owner_stats;
+        owner_stats.owner = ctx.accounts.owner.key();
+        owner_stats.bump = ctx.bumps.owner_stats;
+        Ok(())
+    }
+
+    impl TransferStats {
+        /// Adds a transfer to the totals, starting new epoch counters when `epoch`
+        /// moves on. `fee` is what Token-2022 withheld from the transfer, and
+        /// `new_sender` is set for the first transfer this epoch of a sender with an
+        /// OwnerStats account.
+        pub fn record(&mut self, amount: u64, fee: u64, epoch: u64, new_sender: bool) {
+            if epoch != self.epoch {
+                self.epoch = epoch;
+                self.epoch_transfers = 0;
+                self.epoch_volume = 0;
+                self.epoch_unique_senders = 0;
+            }
+
+            self.total_transfers = self.total_transfers.saturating_add(1);
+            self.total_volume = self.total_volume.saturating_add(amount as u128);
+            self.total_fees = self.total_fees.saturating_add(fee as u128);
+            self.epoch_transfers = self.epoch_transfers.saturating_add(1);
+            self.epoch_volume = self.epoch_volume.saturating_add(amount as u128);
+            if new_sender {
+                self.epoch_unique_senders = self.epoch_unique_senders.saturating_add(1);
+            }
+        }
+    }
+
+    impl OwnerStats {
+        /// Records an outgoing transfer, returning whether it is the owner's first
+        /// one in `epoch`.
+        pub fn record_sent(&mut self, amount: u64, slot: u64, epoch: u64) -> bool {
+            let new_sender = self.sent_count == 0 || self.last_sent_epoch != epoch;
+            self.sent_count = self.sent_count.saturating_add(1);
+            self.sent_amount = self.sent_amount.saturating_add(amount as u128);
+            self.last_sent_epoch = epoch;
+            self.last_activity_slot = slot;
+            new_sender
+        }
+
+        pub fn record_received(&mut self, amount: u64, slot: u64) {
+            self.received_count = self.received_count.saturating_add(1);
+            self.received_amount = self.received_amount.saturating_add(amount as u128);
+            self.last_activity_slot = slot;
+        }
+    }
+
+#[derive(Accounts)]
+pub struct InitTransferStats<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [TRANSFER_STATS_SEED],
+        bump,
+        space = 8 + TransferStats::INIT_SPACE,
+    )]
+    pub transfer_stats: Account<'info, TransferStats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitOwnerStats<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: wallet whose transfers are tracked
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [OWNER_STATS_SEED, owner.key().as_ref()],
+        bump,
+        space = 8 + OwnerStats::INIT_SPACE,
+    )]
+    pub owner_stats: Account<'info, OwnerStats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// RWD transfer activity across all holders; `epoch` is the Solana epoch the
+// epoch_* counters cover. `total_fees` only counts fees withheld by the Token-2022
+// transfer fee extension, and `epoch_unique_senders` only senders with OwnerStats.
+#[account]
+#[derive(InitSpace)]
+pub struct TransferStats {
+    pub total_transfers: u64,
+    pub total_volume: u128,
+    pub total_fees: u128,
+    pub epoch: u64,
+    pub epoch_transfers: u64,
+    pub epoch_volume: u128,
+    pub epoch_unique_senders: u64,
+    pub bump: u8,
+}
+
+// RWD transfer activity of a single wallet, as sender and as recipient
+#[account]
+#[derive(InitSpace)]
+pub struct OwnerStats {
+    pub owner: Pubkey,
+    pub sent_count: u64,
+    pub sent_amount: u128,
+    pub received_count: u64,
+    pub received_amount: u128,
+    pub last_sent_epoch: u64,
+    pub last_activity_slot: u64,
+    pub bump: u8,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats() -> TransferStats {
+        TransferStats {
+            total_transfers: 0,
+            total_volume: 0,
+            total_fees: 0,
+            epoch: 0,
+            epoch_transfers: 0,
+            epoch_volume: 0,
+            epoch_unique_senders: 0,
+            bump: 0,
+        }
+    }
+
+    fn owner_stats() -> OwnerStats {
+        OwnerStats {
+            owner: Pubkey::default(),
+            sent_count: 0,
+            sent_amount: 0,
+            received_count: 0,
+            received_amount: 0,
+            last_sent_epoch: 0,
+            last_activity_slot: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn epoch_counters_reset_but_totals_accumulate() {
+        let mut stats = stats();
+        stats.record(100, 1, 3, true);
+        stats.record(50, 0, 3, false);
+        assert_eq!((stats.epoch_transfers, stats.epoch_volume, stats.epoch_unique_senders), (2, 150, 1));
+
+        stats.record(10, 0, 4, true);
+        assert_eq!((stats.total_transfers, stats.total_volume, stats.total_fees), (3, 160, 1));
+        assert_eq!((stats.epoch, stats.epoch_transfers, stats.epoch_volume, stats.epoch_unique_senders), (4, 1, 10, 1));
+    }
+
+    #[test]
+    fn sender_is_new_once_per_epoch() {
+        let mut sender = owner_stats();
+        assert!(sender.record_sent(10, 100, 0));
+        assert!(!sender.record_sent(10, 101, 0));
+        assert!(sender.record_sent(10, 102, 1));
+        assert_eq!((sender.sent_count, sender.sent_amount, sender.last_activity_slot), (3, 30, 102));
+
+        sender.record_received(5, 103);
+        assert_eq!((sender.received_count, sender.received_amount, sender.last_activity_slot), (1, 5, 103));
+    }
+}
@@ -16,13 +16,46 @@ declare_id!("bwBXEXnTs8NaQhhWgoK3c3DC1nEx6kaRMyi3R3VSjnm");
 pub mod transfer_hook {
     use super::*;
 
+    pub fn initialize_hook_config(ctx: Context<InitializeHookConfig>) -> Result<()> {
+        _initialize_hook_config(ctx)
+    }
+
     pub fn initialize_extra_account_meta_list(ctx: Context<InitializeExtraAccountMetaList>,) -> Result<()> {
         _initialize_extra_account_meta_list(ctx)
     }
     
+    pub fn update_extra_account_meta_list(ctx: Context<UpdateExtraAccountMetaList>) -> Result<()> {
+        _update_extra_account_meta_list(ctx)
+    }
+
     pub fn transfer_hook(ctx: Context<TransferHook>, amount: u64) -> Result<()> {
         _transfer_hook(ctx, amount)
     }
+
+    pub fn record_lot(ctx: Context<RecordLot>, amount: u64, issued_at: i64) -> Result<()> {
+        _record_lot(ctx, amount, issued_at)
+    }
+
+    pub fn expire_lots(ctx: Context<ExpireLots>, cutoff_ts: i64) -> Result<()> {
+        _expire_lots(ctx, cutoff_ts)
+    }
+
+    pub fn consume_lots(ctx: Context<ConsumeLots>, amount: u64) -> Result<()> {
+        _consume_lots(ctx, amount)
+    }
+
+    pub fn init_transfer_counter(ctx: Context<InitTransferCounter>) -> Result<()> {
+        _init_transfer_counter(ctx)
+    }
+
+    pub fn init_transfer_stats(ctx: Context<InitTransferStats>) -> Result<()> {
+        _init_transfer_stats(ctx)
+    }
+
+    pub fn init_owner_stats(ctx: Context<InitOwnerStats>) -> Result<()> {
+        _init_owner_stats(ctx)
+    }
+
     // Required for transfer_hook
     // fallback instruction handler as workaround to anchor instruction discriminator check
     pub fn fallback<'info>(
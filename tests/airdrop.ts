@@ -0,0 +1,182 @@
+import assert from "assert";
+import { createHash } from "crypto";
+
+import * as anchor from "@coral-xyz/anchor";
+import { TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { makeKeypairs } from "@solana-developers/helpers";
+
+import {
+  CAMPAIGN_SEED,
+  CAMPAIGN_VAULT_SEED,
+  CLAIM_SEED,
+  EXTRA_ACCOUNT_METAS,
+  FEES_SEED,
+  FREEZE_SEED,
+  RWD_DECIMALS,
+  TOKEN_2022_SEED,
+  USDC_MINT_ADDRESS,
+  USDC_SEED,
+} from "../utils/constants";
+import { findATAs, findPDAs, getProvider, getTokenBalance, toBN } from "../utils/setup";
+import { initializeAll } from "../utils/initialization";
+import { mintTokens } from "../utils/mint";
+import { getTransferHookRemainingAccounts } from "../utils/transfer";
+import type { Rewards } from "../target/types/rewards";
+import type { TransferHook } from "../target/types/transfer_hook";
+
+const u64Le = (value: anchor.BN) => value.toArrayLike(Buffer, "le", 8);
+const sha256 = (...parts: Buffer[]) => createHash("sha256").update(Buffer.concat(parts)).digest();
+
+// Mirrors utils/merkle.rs: domain-separated leaves, sorted sibling pairs
+const airdropLeaf = (index: anchor.BN, claimant: anchor.web3.PublicKey, amount: anchor.BN) =>
+  sha256(Buffer.from([0]), u64Le(index), claimant.toBuffer(), u64Le(amount));
+const merkleParent = (a: Buffer, b: Buffer) =>
+  Buffer.compare(a, b) <= 0 ? sha256(Buffer.from([1]), a, b) : sha256(Buffer.from([1]), b, a);
+
+describe("airdrop campaigns", () => {
+  const provider = getProvider();
+  const program = anchor.workspace.Rewards as anchor.Program<Rewards>;
+  const transfer_program = anchor.workspace.TransferHook as anchor.Program<TransferHook>;
+  const connection = provider.connection;
+  const wallet = provider.wallet as anchor.Wallet;
+
+  const [feeCollector, otherClaimant] = makeKeypairs(2);
+
+  const campaignId = new anchor.BN(1);
+  const claimIndex = new anchor.BN(0);
+  const claimAmount = toBN(3, RWD_DECIMALS);
+  const otherAmount = toBN(2, RWD_DECIMALS);
+  const totalAmount = claimAmount.add(otherAmount);
+
+  const leaves = [
+    airdropLeaf(claimIndex, wallet.publicKey, claimAmount),
+    airdropLeaf(new anchor.BN(1), otherClaimant.publicKey, otherAmount),
+  ];
+  const merkleRoot = merkleParent(leaves[0], leaves[1]);
+
+  let pdaMap = findPDAs(program, {
+    mint: [Buffer.from(TOKEN_2022_SEED)],
+    usdcKeeper: [Buffer.from(USDC_SEED)],
+    fees: [Buffer.from(FEES_SEED)],
+    freezeState: [Buffer.from(FREEZE_SEED)],
+    campaign: [Buffer.from(CAMPAIGN_SEED), u64Le(campaignId)],
+  });
+
+  pdaMap = {
+    ...pdaMap,
+    ...findPDAs(program, {
+      campaignVault: [Buffer.from(CAMPAIGN_VAULT_SEED), pdaMap.campaign.toBuffer()],
+      claimStatus: [Buffer.from(CLAIM_SEED), pdaMap.campaign.toBuffer(), u64Le(claimIndex)],
+    }),
+    ...findPDAs(transfer_program, {
+      extraAccountMetaList: [Buffer.from(EXTRA_ACCOUNT_METAS), pdaMap.mint.toBuffer()],
+    }),
+  };
+
+  const usdcMint = new anchor.web3.PublicKey(USDC_MINT_ADDRESS);
+  const ataMap = findATAs(pdaMap.mint, {
+    payer: wallet.publicKey,
+    feeCollector: feeCollector.publicKey,
+  });
+  const payerATA = ataMap.payer;
+
+  const initFeesArgs = {
+    mintFeeBps: 100,
+    transferFeeBps: 100,
+    redemptionFeeBps: 100,
+    feeCollector: ataMap.feeCollector,
+  };
+
+  const claimAccounts = {
+    claimant: wallet.publicKey,
+    campaign: pdaMap.campaign,
+    claimStatus: pdaMap.claimStatus,
+    mint: pdaMap.mint,
+    campaignVault: pdaMap.campaignVault,
+    claimantAta: payerATA,
+    systemProgram: anchor.web3.SystemProgram.programId,
+    tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+  };
+
+  it("Creates a campaign funded with freshly minted RWD", async () => {
+    await initializeAll(program, transfer_program, wallet, usdcMint, initFeesArgs, pdaMap);
+    await mintTokens(program, wallet, toBN(1, RWD_DECIMALS), payerATA, pdaMap, feeCollector.publicKey, ataMap.feeCollector, usdcMint);
+
+    const expiryTs = new anchor.BN(Math.floor(Date.now() / 1000) + 24 * 60 * 60);
+
+    await program.methods
+      .createCampaign(campaignId, Array.from(merkleRoot), totalAmount, expiryTs)
+      .accountsStrict({
+        signer: wallet.publicKey,
+        mint: pdaMap.mint,
+        campaign: pdaMap.campaign,
+        campaignVault: pdaMap.campaignVault,
+        treasury: payerATA,
+        freezeState: pdaMap.freezeState,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+    const campaign = await program.account.campaign.fetch(pdaMap.campaign);
+    assert.ok(campaign.totalAmount.eq(totalAmount), "Campaign should record its budget");
+    assert.ok((await getTokenBalance(connection, pdaMap.campaignVault)).eq(totalAmount), "Vault should hold the budget");
+  });
+
+  it("Rejects a claim with the wrong amount", async () => {
+    try {
+      await program.methods
+        .claimAirdrop(claimIndex, claimAmount.addn(1), [Array.from(leaves[1])])
+        .accountsStrict(claimAccounts)
+        .rpc();
+      assert.fail("Should not claim more than the allocation");
+    } catch (err) {
+      assert.ok(err.toString().includes("InvalidMerkleProof"));
+    }
+  });
+
+  it("Claims an allocation exactly once", async () => {
+    const balanceBefore = await getTokenBalance(connection, payerATA);
+
+    const claim = async () => program.methods
+      .claimAirdrop(claimIndex, claimAmount, [Array.from(leaves[1])])
+      .accountsStrict(claimAccounts)
+      .remainingAccounts(await getTransferHookRemainingAccounts(
+        connection, pdaMap.campaignVault, pdaMap.mint, payerATA, pdaMap.campaign, claimAmount
+      ))
+      .rpc();
+
+    await claim();
+
+    const balanceAfter = await getTokenBalance(connection, payerATA);
+    assert.ok(balanceAfter.eq(balanceBefore.add(claimAmount)), "Claimant should receive the allocation");
+
+    const campaign = await program.account.campaign.fetch(pdaMap.campaign);
+    assert.ok(campaign.claimedAmount.eq(claimAmount), "Campaign should track claimed funds");
+
+    try {
+      await claim();
+      assert.fail("Should not claim twice");
+    } catch (err) {
+      assert.ok(err.toString().includes("already in use"));
+    }
+  });
+
+  it("Keeps unclaimed funds until the campaign expires", async () => {
+    try {
+      await program.methods
+        .reclaimCampaign()
+        .accountsStrict({
+          campaign: pdaMap.campaign,
+          mint: pdaMap.mint,
+          campaignVault: pdaMap.campaignVault,
+          treasury: payerATA,
+          tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+        })
+        .rpc();
+      assert.fail("Should not reclaim before expiry");
+    } catch (err) {
+      assert.ok(err.toString().includes("CampaignNotExpired"));
+    }
+  });
+});
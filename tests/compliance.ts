@@ -0,0 +1,196 @@
+import assert from "assert";
+
+import * as anchor from "@coral-xyz/anchor";
+import { getAccount, TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { makeKeypairs } from "@solana-developers/helpers";
+
+import {
+  BLACKLIST_SEED,
+  COMPLIANCE_SEED,
+  EXTRA_ACCOUNT_METAS,
+  FEES_SEED,
+  FREEZE_AUTHORITY_SEED,
+  FREEZE_SEED,
+  PERMANENT_DELEGATE_SEED,
+  RWD_DECIMALS,
+  TOKEN_2022_SEED,
+  USDC_MINT_ADDRESS,
+  USDC_SEED,
+} from "../utils/constants";
+import { findATAs, findBurnLotAccounts, findPDAs, getProvider, getTokenBalance, toBN } from "../utils/setup";
+import { initializeAll } from "../utils/initialization";
+import { mintTokens } from "../utils/mint";
+import { getTransferHookRemainingAccounts, transferTokens } from "../utils/transfer";
+import type { Rewards } from "../target/types/rewards";
+import type { TransferHook } from "../target/types/transfer_hook";
+
+describe("compliance", () => {
+  const provider = getProvider();
+  const program = anchor.workspace.Rewards as anchor.Program<Rewards>;
+  const transfer_program = anchor.workspace.TransferHook as anchor.Program<TransferHook>;
+  const connection = provider.connection;
+  const wallet = provider.wallet as anchor.Wallet;
+
+  const [feeCollector] = makeKeypairs(1);
+
+  let pdaMap = findPDAs(program, {
+    mint: [Buffer.from(TOKEN_2022_SEED)],
+    usdcKeeper: [Buffer.from(USDC_SEED)],
+    fees: [Buffer.from(FEES_SEED)],
+    freezeState: [Buffer.from(FREEZE_SEED)],
+    freezeAuthority: [Buffer.from(FREEZE_AUTHORITY_SEED)],
+    compliance: [Buffer.from(COMPLIANCE_SEED)],
+    permanentDelegate: [Buffer.from(PERMANENT_DELEGATE_SEED)],
+    blacklistEntry: [Buffer.from(BLACKLIST_SEED), feeCollector.publicKey.toBuffer()],
+  });
+
+  pdaMap = {
+    ...pdaMap,
+    ...findPDAs(transfer_program, {
+      extraAccountMetaList: [Buffer.from(EXTRA_ACCOUNT_METAS), pdaMap.mint.toBuffer()],
+    }),
+  };
+
+  const usdcMint = new anchor.web3.PublicKey(USDC_MINT_ADDRESS);
+  const ataMap = findATAs(pdaMap.mint, {
+    payer: wallet.publicKey,
+    feeCollector: feeCollector.publicKey,
+  });
+  const payerATA = ataMap.payer;
+
+  const initFeesArgs = {
+    mintFeeBps: 100,
+    transferFeeBps: 100,
+    redemptionFeeBps: 100,
+    feeCollector: ataMap.feeCollector,
+  };
+
+  const reasonCode = 7;
+  const transferAmount = toBN(1, RWD_DECIMALS);
+
+  it("Freezes and thaws a token account", async () => {
+    await initializeAll(program, transfer_program, wallet, usdcMint, initFeesArgs, pdaMap);
+    await mintTokens(program, wallet, toBN(5, RWD_DECIMALS), payerATA, pdaMap, feeCollector.publicKey, ataMap.feeCollector, usdcMint);
+
+    // initialize_protocol already sets up compliance on a fresh validator
+    if (!(await connection.getAccountInfo(pdaMap.compliance))) {
+      await program.methods
+        .initializeCompliance(wallet.publicKey)
+        .accountsStrict({
+          signer: wallet.publicKey,
+          mint: pdaMap.mint,
+          compliance: pdaMap.compliance,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+    }
+
+    const accounts = {
+      officer: wallet.publicKey,
+      compliance: pdaMap.compliance,
+      freezeAuthority: pdaMap.freezeAuthority,
+      mint: pdaMap.mint,
+      tokenAccount: ataMap.feeCollector,
+      tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+    };
+
+    await program.methods.freezeTokenAccount(reasonCode).accountsStrict(accounts).rpc();
+    let account = await getAccount(connection, ataMap.feeCollector, "confirmed", TOKEN_2022_PROGRAM_ID);
+    assert.ok(account.isFrozen, "Account should be frozen");
+
+    await program.methods.thawTokenAccount(reasonCode).accountsStrict(accounts).rpc();
+    account = await getAccount(connection, ataMap.feeCollector, "confirmed", TOKEN_2022_PROGRAM_ID);
+    assert.ok(!account.isFrozen, "Account should be thawed");
+  });
+
+  it("Blocks transfers to blacklisted wallets", async () => {
+    await program.methods
+      .addToBlacklist(feeCollector.publicKey, reasonCode)
+      .accountsStrict({
+        officer: wallet.publicKey,
+        compliance: pdaMap.compliance,
+        blacklistEntry: pdaMap.blacklistEntry,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    try {
+      await transferTokens(transfer_program, wallet, transferAmount, payerATA, ataMap.feeCollector, pdaMap);
+      assert.fail("Should not transfer to a blacklisted wallet");
+    } catch (err) {
+      assert.ok(err.toString().includes("0x1778"), "Hook should reject with Blacklisted");
+    }
+
+    await program.methods
+      .removeFromBlacklist(reasonCode)
+      .accountsStrict({
+        officer: wallet.publicKey,
+        compliance: pdaMap.compliance,
+        blacklistEntry: pdaMap.blacklistEntry,
+      })
+      .rpc();
+
+    await transferTokens(transfer_program, wallet, transferAmount, payerATA, ataMap.feeCollector, pdaMap);
+  });
+
+  it("Claws back tokens from a blacklisted wallet", async () => {
+    await program.methods
+      .addToBlacklist(feeCollector.publicKey, reasonCode)
+      .accountsStrict({
+        officer: wallet.publicKey,
+        compliance: pdaMap.compliance,
+        blacklistEntry: pdaMap.blacklistEntry,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    const accounts = {
+      authority: wallet.publicKey,
+      compliance: pdaMap.compliance,
+      permanentDelegate: pdaMap.permanentDelegate,
+      mint: pdaMap.mint,
+      source: ataMap.feeCollector,
+      destination: payerATA,
+      ...findBurnLotAccounts(program.programId, transfer_program.programId, feeCollector.publicKey),
+      tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+    };
+
+    const payerBefore = await getTokenBalance(connection, payerATA);
+    await program.methods
+      .clawback(transferAmount, false, "Fraudulent rewards")
+      .accountsStrict(accounts)
+      .remainingAccounts(await getTransferHookRemainingAccounts(
+        connection, ataMap.feeCollector, pdaMap.mint, payerATA, pdaMap.permanentDelegate, transferAmount
+      ))
+      .rpc();
+    const payerAfter = await getTokenBalance(connection, payerATA);
+    assert.ok(payerAfter.sub(payerBefore).eq(transferAmount), "Clawed back tokens should reach the destination");
+
+    const remaining = await getTokenBalance(connection, ataMap.feeCollector);
+    await program.methods
+      .clawback(remaining, true, "Fraudulent rewards")
+      .accountsStrict({ ...accounts, destination: null })
+      .rpc();
+    assert.ok((await getTokenBalance(connection, ataMap.feeCollector)).isZero(), "Remaining tokens should be burned");
+
+    await program.methods
+      .setClawbackAuthority(feeCollector.publicKey)
+      .accountsStrict({ authority: wallet.publicKey, compliance: pdaMap.compliance })
+      .rpc();
+
+    try {
+      await program.methods
+        .clawback(transferAmount, true, "Fraudulent rewards")
+        .accountsStrict({
+          ...accounts,
+          ...findBurnLotAccounts(program.programId, transfer_program.programId, wallet.publicKey),
+          source: payerATA,
+          destination: null,
+        })
+        .rpc();
+      assert.fail("Officer should not claw back once a clawback authority is set");
+    } catch (err) {
+      assert.ok(err.toString().includes("Unauthorized"));
+    }
+  });
+});
@@ -2,12 +2,14 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { Dao } from "../target/types/dao";
 import { assert } from "chai";
-import { createAssociatedTokenAccount, createMint, mintTo, TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { createAssociatedTokenAccount, createMint, mintTo, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID } from "@solana/spl-token";
 import { mintTokens } from "../utils/mint";
 import { initializeAll } from "../utils/initialization";
-import { EXTRA_ACCOUNT_METAS, FEES_SEED, FREEZE_SEED, TOKEN_2022_SEED, USDC_MINT_ADDRESS, USDC_SEED, USER_WHITELIST_SEED } from "../utils/constants";
-import { findATAs, findPDAs } from "../utils/setup";
-import { makeKeypairs } from "@solana-developers/helpers";
+import { COMPLIANCE_SEED, EXTRA_ACCOUNT_METAS, FEES_SEED, PERMANENT_DELEGATE_SEED, FREEZE_SEED, RWD_DECIMALS, TOKEN_2022_SEED, TREASURY_RWD_SEED, TREASURY_SEED, TREASURY_USDC_SEED, USDC_MINT_ADDRESS, USDC_SEED, USER_WHITELIST_SEED, VOTE_SEED, MEMBER_SEED, DAO_CONFIG_SEED, DELEGATION_SEED, DEPOSIT_SEED, LOCK_VAULT_SEED, RANKED_TALLY_SEED, MIN_LOCK_DURATION } from "../utils/constants";
+import { findATAs, findBurnLotAccounts, findPDAs, getTokenBalance, toBN } from "../utils/setup";
+import { getTransferHookRemainingAccounts, transferTokens } from "../utils/transfer";
+import { airdropIfRequired, makeKeypairs } from "@solana-developers/helpers";
+import { createHash } from "crypto";
 import { Rewards } from "../target/types/rewards";
 import { TransferHook } from "../target/types/transfer_hook";
 
@@ -18,7 +20,7 @@ describe("dao", () => {
   const mint_program = anchor.workspace.Rewards as Program<Rewards>;
   const transfer_program = anchor.workspace.TransferHook as Program<TransferHook>;
 
-  let member = anchor.web3.Keypair.generate();
+  const outsider = anchor.web3.Keypair.generate();
   let governanceProposal = anchor.web3.Keypair.generate();
   let daoTreasury = anchor.web3.Keypair.generate();
   let tokenMint;
@@ -44,7 +46,100 @@ describe("dao", () => {
     whitelist: [Buffer.from(USER_WHITELIST_SEED)],
 });
 
-  pdaMap = { ...pdaMap, ...pdasFromTransferProgram };
+  // DAO Program PDAs:
+  const pdasFromDaoProgram = findPDAs(program, {
+    treasury: [Buffer.from(TREASURY_SEED)],
+    treasuryRwd: [Buffer.from(TREASURY_RWD_SEED)],
+    treasuryUsdc: [Buffer.from(TREASURY_USDC_SEED)],
+    daoConfig: [Buffer.from(DAO_CONFIG_SEED)],
+    member: [Buffer.from(MEMBER_SEED), proposer.publicKey.toBuffer()],
+  });
+
+  pdaMap = { ...pdaMap, ...pdasFromTransferProgram, ...pdasFromDaoProgram };
+
+  const findVoteRecord = (proposal: anchor.web3.PublicKey, memberAccount: anchor.web3.PublicKey) =>
+    anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(VOTE_SEED), proposal.toBuffer(), memberAccount.toBuffer()],
+      program.programId
+    )[0];
+
+  const reputationConfig = {
+    proposalPoints: new anchor.BN(5),
+    votePoints: new anchor.BN(1),
+    activityPoints: new anchor.BN(2),
+    maxPoints: new anchor.BN(1000),
+    decayBps: 100,
+    decayPeriod: new anchor.BN(7 * 24 * 60 * 60),
+    multiplierBpsPerPoint: 10,
+    maxMultiplierBonusBps: 5000,
+  };
+
+  const depositConfig = {
+    amount: toBN(1, RWD_DECIMALS).div(new anchor.BN(100)),
+    minReputation: new anchor.BN(0),
+    minStake: new anchor.BN(0),
+    quorum: new anchor.BN(1),
+    forfeitThresholdBps: 2000,
+  };
+
+  const governanceConfig = {
+    quorum: new anchor.BN(1),
+    votingPeriod: new anchor.BN(5),
+  };
+
+  // Proposals only pass once they are closed after their voting period
+  const waitForVotingEnd = async (proposal: anchor.web3.PublicKey) => {
+    const { votingEndsAt } = await program.account.governanceProposal.fetch(proposal);
+    const remainingMs = votingEndsAt.toNumber() * 1000 - Date.now();
+    await new Promise((resolve) => setTimeout(resolve, Math.max(remainingMs, 0) + 2000));
+  };
+
+  // Proposals snapshot locks from slots before their own, so a lock has to land first
+  const waitForNextSlot = async () => {
+    const slot = await provider.connection.getSlot();
+    while ((await provider.connection.getSlot()) <= slot) {
+      await new Promise((resolve) => setTimeout(resolve, 100));
+    }
+  };
+
+  const findLockVault = (member: anchor.web3.PublicKey) =>
+    anchor.web3.PublicKey.findProgramAddressSync([Buffer.from(LOCK_VAULT_SEED), member.toBuffer()], program.programId)[0];
+
+  // Payment accounts are only required for quadratic ballots
+  const noQuadraticPayment = {
+    treasury: null,
+    rwdMint: null,
+    voterRwd: null,
+    treasuryRwdVault: null,
+    tokenProgram: null,
+  };
+
+  const findDepositVault = (proposal: anchor.web3.PublicKey) =>
+    anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(DEPOSIT_SEED), proposal.toBuffer()],
+      program.programId
+    )[0];
+
+  const submitAccounts = (proposal: anchor.web3.PublicKey) => ({
+    governanceProposal: proposal,
+    proposer: proposer.publicKey,
+    proposerMember: pdaMap.member,
+    daoConfig: pdaMap.daoConfig,
+    treasury: pdaMap.treasury,
+    rwdMint: pdaMap.mint,
+    proposerRwd: sourceTokenAccount,
+    depositVault: findDepositVault(proposal),
+    tokenProgram: TOKEN_2022_PROGRAM_ID,
+    systemProgram: anchor.web3.SystemProgram.programId,
+  });
+
+  const depositRemainingAccounts = (proposal: anchor.web3.PublicKey) =>
+    getTransferHookRemainingAccounts(
+      provider.connection, sourceTokenAccount, pdaMap.mint, findDepositVault(proposal), proposer.publicKey, depositConfig.amount
+    );
+
+  const descriptionUri = "https://example.com/proposals/1.md";
+  const descriptionHash = Array.from(createHash("sha256").update("This is a governance proposal").digest());
   const ownersMap = {
     payer: proposer.publicKey,
     recipient: receiver.publicKey,
@@ -94,58 +189,208 @@ describe("dao", () => {
   });
 
 
+  const [programData] = anchor.web3.PublicKey.findProgramAddressSync(
+    [program.programId.toBuffer()],
+    new anchor.web3.PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+  );
+
+  it("Rejects a DAO config initialized by someone other than the upgrade authority", async () => {
+    await airdropIfRequired(provider.connection, outsider.publicKey, anchor.web3.LAMPORTS_PER_SOL, anchor.web3.LAMPORTS_PER_SOL);
+
+    try {
+      await program.methods
+        .initializeDaoConfig(reputationConfig, depositConfig, governanceConfig)
+        .accountsStrict({
+          signer: outsider.publicKey,
+          daoConfig: pdaMap.daoConfig,
+          program: program.programId,
+          programData,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([outsider])
+        .rpc();
+      assert.fail("Should not let an outsider become DAO admin");
+    } catch (err) {
+      assert.include(err.toString(), "Unauthorized");
+    }
+  });
+
+  it("Initializes the DAO config", async () => {
+    await program.methods
+      .initializeDaoConfig(reputationConfig, depositConfig, governanceConfig)
+      .accountsStrict({
+        signer: proposer.publicKey,
+        daoConfig: pdaMap.daoConfig,
+        program: program.programId,
+        programData,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    const daoConfig = await program.account.daoConfig.fetch(pdaMap.daoConfig);
+    assert.equal(daoConfig.admin.toBase58(), proposer.publicKey.toBase58(), "Signer should become DAO admin");
+  });
+
   it("Initializes a member", async () => {
     await program.methods
       .initializeMember()
       .accountsStrict({
-        member: member.publicKey,
+        member: pdaMap.member,
         user: proposer.publicKey,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
-      .signers([member])
       .rpc();
 
-    let memberAccount = await program.account.member.fetch(member.publicKey);
+    let memberAccount = await program.account.member.fetch(pdaMap.member);
     assert.equal(memberAccount.reputationPoints.toNumber(), 0, "Member should start with 0 reputation points");
   });
 
-  it("Submits a proposal", async () => {
-    const title = "Proposal 1";
-    const description = "This is a governance proposal";
-    const options = ["Yes", "No"];
+  it("Locks RWD for voting power and keeps it locked until the lock ends", async () => {
+    const lockAmount = toBN(1, RWD_DECIMALS).div(new anchor.BN(10));
+    const lockVault = findLockVault(pdaMap.member);
 
     await program.methods
-      .submitProposal(title, description, options)
+      .createLock(lockAmount, new anchor.BN(MIN_LOCK_DURATION))
       .accountsStrict({
-        governanceProposal: governanceProposal.publicKey,
-        proposer: proposer.publicKey,
+        owner: proposer.publicKey,
+        member: pdaMap.member,
+        rwdMint: pdaMap.mint,
+        ownerRwd: sourceTokenAccount,
+        lockVault,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .remainingAccounts(await getTransferHookRemainingAccounts(
+        provider.connection, sourceTokenAccount, pdaMap.mint, lockVault, proposer.publicKey, lockAmount
+      ))
+      .rpc();
+
+    const memberAccount = await program.account.member.fetch(pdaMap.member);
+    assert.ok(memberAccount.lockedAmount.eq(lockAmount), "Member should record the locked amount");
+    assert.ok((await getTokenBalance(provider.connection, lockVault)).eq(lockAmount), "Vault should hold the locked RWD");
+
+    try {
+      await program.methods
+        .unlockTokens()
+        .accountsStrict({
+          owner: proposer.publicKey,
+          member: pdaMap.member,
+          rwdMint: pdaMap.mint,
+          ownerRwd: sourceTokenAccount,
+          lockVault,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .remainingAccounts(await getTransferHookRemainingAccounts(
+          provider.connection, lockVault, pdaMap.mint, sourceTokenAccount, pdaMap.member, lockAmount
+        ))
+        .rpc();
+      assert.fail("Should not unlock before the lock ends");
+    } catch (err) {
+      assert.include(err.toString(), "LockNotExpired");
+    }
+
+    await waitForNextSlot();
+  });
+
+  it("Initializes the DAO treasury", async () => {
+    await program.methods
+      .initializeTreasury()
+      .accountsStrict({
+        signer: proposer.publicKey,
+        treasury: pdaMap.treasury,
+        rwdMint: pdaMap.mint,
+        usdcMint,
+        rwdVault: pdaMap.treasuryRwd,
+        usdcVault: pdaMap.treasuryUsdc,
         systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        tokenProgram2022: TOKEN_2022_PROGRAM_ID,
       })
+      .rpc();
+
+    const treasuryAccount = await program.account.treasury.fetch(pdaMap.treasury);
+    assert.equal(treasuryAccount.rwdVault.toBase58(), pdaMap.treasuryRwd.toBase58(), "RWD vault mismatch");
+    assert.equal(treasuryAccount.usdcVault.toBase58(), pdaMap.treasuryUsdc.toBase58(), "USDC vault mismatch");
+  });
+
+  it("Submits a proposal", async () => {
+    const title = "Proposal 1";
+    const options = ["Yes", "No"];
+
+    await program.methods
+      .submitProposal(title, descriptionUri, descriptionHash, 0, { single: {} }, options)
+      .accountsStrict(submitAccounts(governanceProposal.publicKey))
+      .remainingAccounts(await depositRemainingAccounts(governanceProposal.publicKey))
       .signers([governanceProposal])
       .rpc();
 
     let proposalAccount = await program.account.governanceProposal.fetch(governanceProposal.publicKey);
     console.log("Fetched Proposal:", proposalAccount);
     assert.deepEqual(proposalAccount.status, { open: {} }, "Proposal should be open");
-    
+
+    let memberAccount = await program.account.member.fetch(pdaMap.member);
+    assert.equal(memberAccount.reputationPoints.toNumber(), 5, "Proposing should award proposal points");
+
+    const depositBalance = await getTokenBalance(provider.connection, findDepositVault(governanceProposal.publicKey));
+    assert.ok(depositBalance.eq(depositConfig.amount), "Deposit should be escrowed with the proposal");
+
+  });
+
+  it("Rejects a proposal with an oversized title", async () => {
+    const oversizedProposal = anchor.web3.Keypair.generate();
+    try {
+      await program.methods
+        .submitProposal("T".repeat(65), descriptionUri, descriptionHash, 0, { single: {} }, ["Yes", "No"])
+        .accountsStrict(submitAccounts(oversizedProposal.publicKey))
+        .remainingAccounts(await depositRemainingAccounts(oversizedProposal.publicKey))
+        .signers([oversizedProposal])
+        .rpc();
+      assert.fail("Should not accept a title longer than the maximum");
+    } catch (err) {
+      assert.include(err.toString(), "TitleTooLong");
+    }
+  });
+
+  it("Rejects a proposal with duplicate options", async () => {
+    const duplicateProposal = anchor.web3.Keypair.generate();
+    try {
+      await program.methods
+        .submitProposal("Duplicates", descriptionUri, descriptionHash, 0, { single: {} }, ["Yes", "Yes"])
+        .accountsStrict(submitAccounts(duplicateProposal.publicKey))
+        .remainingAccounts(await depositRemainingAccounts(duplicateProposal.publicKey))
+        .signers([duplicateProposal])
+        .rpc();
+      assert.fail("Should not accept duplicate options");
+    } catch (err) {
+      assert.include(err.toString(), "DuplicateOption");
+    }
   });
 
   it("Casts a vote", async () => {
+    const voteRecord = findVoteRecord(governanceProposal.publicKey, pdaMap.member);
     await program.methods
-      .castVote(0)
-      .accounts({
+      .castVote({ single: { optionIndex: 0 } })
+      .accountsStrict({
+        voter: proposer.publicKey,
         governanceProposal: governanceProposal.publicKey,
-        member: member.publicKey,
+        member: pdaMap.member,
+        daoConfig: pdaMap.daoConfig,
+        voteRecord,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        ...noQuadraticPayment,
       })
       .signers([proposer.payer])
       .rpc();
 
+    let voteRecordAccount = await program.account.voteRecord.fetch(voteRecord);
+    assert.equal(voteRecordAccount.ballot.single.optionIndex, 0, "Vote record should be for option 0");
+    assert.ok(voteRecordAccount.weight.gtn(0), "Locked RWD should give voting power");
+
     let proposalAccount = await program.account.governanceProposal.fetch(governanceProposal.publicKey);
-    assert.equal(proposalAccount.votes.length, 1, "Vote should be registered");
-    assert.equal(proposalAccount.votes[0].optionIndex, 0, "Vote should be for option 0");
+    assert.ok(proposalAccount.voteCounts[0].eq(voteRecordAccount.weight), "Vote should count with the member's weight");
 
-    let memberAccount = await program.account.member.fetch(member.publicKey);
-    assert.equal(memberAccount.reputationPoints.toNumber(), 1, "Voting should increase reputation points");
+    let memberAccount = await program.account.member.fetch(pdaMap.member);
+    assert.equal(memberAccount.reputationPoints.toNumber(), 6, "Voting should increase reputation points");
   });
 
   it("Funds DAO Treasury with Tokens", async () => {
@@ -171,26 +416,631 @@ describe("dao", () => {
         .closeProposal()
         .accountsStrict({
           governanceProposal: governanceProposal.publicKey,
-          dao: member.publicKey, 
+          daoConfig: pdaMap.daoConfig,
+          dao: outsider.publicKey, 
         })
-        .signers([member])
+        .signers([outsider])
         .rpc();
   
       assert.fail("Should not allow non-admin to close proposal");
     } catch (err) {
-      console.log("Expected Error Caught:", err.toString()); 
+      assert.include(err.toString(), "UnauthorizedClose");
     }
   });
   
   it("Gets proposal results", async () => {
+    await program.methods
+      .closeProposal()
+      .accountsStrict({ governanceProposal: governanceProposal.publicKey, daoConfig: pdaMap.daoConfig, dao: proposer.publicKey })
+      .rpc();
+
     let results = await program.methods
       .getProposalResults()
       .accounts({
         governanceProposal: governanceProposal.publicKey,
+        rankedTally: null,
       })
       .view();
 
-    assert.equal(results[0].toNumber(), 1, "Option 0 should have 1 vote");
+    const voteRecordAccount = await program.account.voteRecord.fetch(findVoteRecord(governanceProposal.publicKey, pdaMap.member));
+    assert.ok(results[0].eq(voteRecordAccount.weight), "Option 0 should have the member's weighted vote");
     assert.equal(results[1].toNumber(), 0, "Option 1 should have 0 votes");
   });
+
+  it("Executes an approved treasury spend proposal", async () => {
+    const spendAmount = toBN(1, RWD_DECIMALS).div(new anchor.BN(10));
+    await transferTokens(transfer_program, proposer, spendAmount, sourceTokenAccount, pdaMap.treasuryRwd, pdaMap);
+
+    const spendProposal = anchor.web3.Keypair.generate();
+    await program.methods
+      .submitActionProposal("Spend 1", descriptionUri, descriptionHash, 0, {
+        transfer: { asset: { rwd: {} }, recipient: sourceTokenAccount, amount: spendAmount },
+      })
+      .accountsStrict(submitAccounts(spendProposal.publicKey))
+      .remainingAccounts(await depositRemainingAccounts(spendProposal.publicKey))
+      .signers([spendProposal])
+      .rpc();
+
+    await program.methods
+      .castVote({ single: { optionIndex: 0 } })
+      .accountsStrict({
+        voter: proposer.publicKey,
+        governanceProposal: spendProposal.publicKey,
+        member: pdaMap.member,
+        daoConfig: pdaMap.daoConfig,
+        voteRecord: findVoteRecord(spendProposal.publicKey, pdaMap.member),
+        systemProgram: anchor.web3.SystemProgram.programId,
+        ...noQuadraticPayment,
+      })
+      .rpc();
+
+    await waitForVotingEnd(spendProposal.publicKey);
+    await program.methods
+      .closeProposal()
+      .accountsStrict({ governanceProposal: spendProposal.publicKey, daoConfig: pdaMap.daoConfig, dao: proposer.publicKey })
+      .rpc();
+
+    const remainingAccounts = await getTransferHookRemainingAccounts(
+      provider.connection, pdaMap.treasuryRwd, pdaMap.mint, sourceTokenAccount, pdaMap.treasury, spendAmount
+    );
+    const balanceBefore = await getTokenBalance(provider.connection, sourceTokenAccount);
+
+    await program.methods
+      .executeProposal()
+      .accountsStrict({
+        governanceProposal: spendProposal.publicKey,
+        treasury: pdaMap.treasury,
+        mint: pdaMap.mint,
+        vault: pdaMap.treasuryRwd,
+        recipient: sourceTokenAccount,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .remainingAccounts(remainingAccounts)
+      .rpc();
+
+    const balanceAfter = await getTokenBalance(provider.connection, sourceTokenAccount);
+    assert.ok(balanceAfter.eq(balanceBefore.add(spendAmount)), "Recipient should receive the spend amount");
+
+    const proposalAccount = await program.account.governanceProposal.fetch(spendProposal.publicKey);
+    assert.ok(proposalAccount.executed, "Proposal should be marked executed");
+  });
+
+  const settleDeposit = async (proposal: anchor.web3.PublicKey, destination: anchor.web3.PublicKey) => {
+    const depositVault = findDepositVault(proposal);
+    const remainingAccounts = await getTransferHookRemainingAccounts(
+      provider.connection, depositVault, pdaMap.mint, destination, pdaMap.treasury, depositConfig.amount
+    );
+
+    await program.methods
+      .settleDeposit()
+      .accountsStrict({
+        governanceProposal: proposal,
+        daoConfig: pdaMap.daoConfig,
+        treasury: pdaMap.treasury,
+        rwdMint: pdaMap.mint,
+        depositVault,
+        proposerRwd: sourceTokenAccount,
+        treasuryRwdVault: pdaMap.treasuryRwd,
+        proposer: proposer.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .remainingAccounts(remainingAccounts)
+      .rpc();
+  };
+
+  const [outsiderMember] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from(MEMBER_SEED), outsider.publicKey.toBuffer()],
+    program.programId
+  );
+
+  let outsiderRwd: anchor.web3.PublicKey;
+
+  it("Refunds the deposit of a proposal that reached quorum", async () => {
+    // The proposer's own vote doesn't count toward the deposit quorum, so another
+    // member with locked RWD has to vote
+    await program.methods
+      .initializeMember()
+      .accountsStrict({ member: outsiderMember, user: outsider.publicKey, systemProgram: anchor.web3.SystemProgram.programId })
+      .signers([outsider])
+      .rpc();
+
+    const lockAmount = depositConfig.amount;
+    const outsiderLockVault = findLockVault(outsiderMember);
+    outsiderRwd = await createAssociatedTokenAccount(
+      provider.connection, proposer.payer, pdaMap.mint, outsider.publicKey, undefined, TOKEN_2022_PROGRAM_ID
+    );
+    await transferTokens(transfer_program, proposer, lockAmount.muln(2), sourceTokenAccount, outsiderRwd, pdaMap);
+    await program.methods
+      .createLock(lockAmount, new anchor.BN(MIN_LOCK_DURATION))
+      .accountsStrict({
+        owner: outsider.publicKey,
+        member: outsiderMember,
+        rwdMint: pdaMap.mint,
+        ownerRwd: outsiderRwd,
+        lockVault: outsiderLockVault,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .remainingAccounts(await getTransferHookRemainingAccounts(
+        provider.connection, outsiderRwd, pdaMap.mint, outsiderLockVault, outsider.publicKey, lockAmount
+      ))
+      .signers([outsider])
+      .rpc();
+    await waitForNextSlot();
+
+    const proposal = anchor.web3.Keypair.generate();
+    await program.methods
+      .submitProposal("Quorum", descriptionUri, descriptionHash, 0, { single: {} }, ["Yes", "No"])
+      .accountsStrict(submitAccounts(proposal.publicKey))
+      .remainingAccounts(await depositRemainingAccounts(proposal.publicKey))
+      .signers([proposal])
+      .rpc();
+
+    await program.methods
+      .castVote({ single: { optionIndex: 0 } })
+      .accountsStrict({
+        voter: outsider.publicKey,
+        governanceProposal: proposal.publicKey,
+        member: outsiderMember,
+        daoConfig: pdaMap.daoConfig,
+        voteRecord: findVoteRecord(proposal.publicKey, outsiderMember),
+        systemProgram: anchor.web3.SystemProgram.programId,
+        ...noQuadraticPayment,
+      })
+      .signers([outsider])
+      .rpc();
+
+    await program.methods
+      .castVote({ single: { optionIndex: 0 } })
+      .accountsStrict({
+        voter: proposer.publicKey,
+        governanceProposal: proposal.publicKey,
+        member: pdaMap.member,
+        daoConfig: pdaMap.daoConfig,
+        voteRecord: findVoteRecord(proposal.publicKey, pdaMap.member),
+        systemProgram: anchor.web3.SystemProgram.programId,
+        ...noQuadraticPayment,
+      })
+      .rpc();
+
+    await program.methods
+      .closeProposal()
+      .accountsStrict({ governanceProposal: proposal.publicKey, daoConfig: pdaMap.daoConfig, dao: proposer.publicKey })
+      .rpc();
+
+    try {
+      await settleDeposit(proposal.publicKey, sourceTokenAccount);
+      assert.fail("Should not settle the deposit before voting ends");
+    } catch (err) {
+      assert.include(err.toString(), "VotingNotEnded");
+    }
+
+    await waitForVotingEnd(proposal.publicKey);
+    const balanceBefore = await getTokenBalance(provider.connection, sourceTokenAccount);
+    await settleDeposit(proposal.publicKey, sourceTokenAccount);
+    const balanceAfter = await getTokenBalance(provider.connection, sourceTokenAccount);
+    assert.ok(balanceAfter.eq(balanceBefore.add(depositConfig.amount)), "Deposit should be refunded to the proposer");
+
+    const proposalAccount = await program.account.governanceProposal.fetch(proposal.publicKey);
+    assert.ok(proposalAccount.depositSettled, "Deposit should be marked settled");
+  });
+
+  it("Forfeits the deposit of a proposal only the proposer voted for", async () => {
+    const proposal = anchor.web3.Keypair.generate();
+    await program.methods
+      .submitProposal("Spam", descriptionUri, descriptionHash, 0, { single: {} }, ["Yes", "No"])
+      .accountsStrict(submitAccounts(proposal.publicKey))
+      .remainingAccounts(await depositRemainingAccounts(proposal.publicKey))
+      .signers([proposal])
+      .rpc();
+
+    await program.methods
+      .castVote({ single: { optionIndex: 0 } })
+      .accountsStrict({
+        voter: proposer.publicKey,
+        governanceProposal: proposal.publicKey,
+        member: pdaMap.member,
+        daoConfig: pdaMap.daoConfig,
+        voteRecord: findVoteRecord(proposal.publicKey, pdaMap.member),
+        systemProgram: anchor.web3.SystemProgram.programId,
+        ...noQuadraticPayment,
+      })
+      .rpc();
+
+    await program.methods
+      .closeProposal()
+      .accountsStrict({ governanceProposal: proposal.publicKey, daoConfig: pdaMap.daoConfig, dao: proposer.publicKey })
+      .rpc();
+
+    try {
+      await program.methods
+        .castVote({ single: { optionIndex: 0 } })
+        .accountsStrict({
+          voter: outsider.publicKey,
+          governanceProposal: proposal.publicKey,
+          member: outsiderMember,
+          daoConfig: pdaMap.daoConfig,
+          voteRecord: findVoteRecord(proposal.publicKey, outsiderMember),
+          systemProgram: anchor.web3.SystemProgram.programId,
+          ...noQuadraticPayment,
+        })
+        .signers([outsider])
+        .rpc();
+      assert.fail("Should not accept votes on a closed proposal");
+    } catch (err) {
+      assert.include(err.toString(), "ProposalNotOpen");
+    }
+
+    await waitForVotingEnd(proposal.publicKey);
+    const treasuryBefore = await getTokenBalance(provider.connection, pdaMap.treasuryRwd);
+    await settleDeposit(proposal.publicKey, pdaMap.treasuryRwd);
+    const treasuryAfter = await getTokenBalance(provider.connection, pdaMap.treasuryRwd);
+    assert.ok(treasuryAfter.eq(treasuryBefore.add(depositConfig.amount)), "Deposit should be forfeited to the treasury");
+  });
+
+  it("Tallies a ranked-choice proposal from its vote records", async () => {
+    const proposal = anchor.web3.Keypair.generate();
+    await program.methods
+      .submitProposal("Ranked", descriptionUri, descriptionHash, 0, { rankedChoice: {} }, ["A", "B", "C"])
+      .accountsStrict(submitAccounts(proposal.publicKey))
+      .remainingAccounts(await depositRemainingAccounts(proposal.publicKey))
+      .signers([proposal])
+      .rpc();
+
+    try {
+      await program.methods
+        .castVote({ single: { optionIndex: 0 } })
+        .accountsStrict({
+          voter: proposer.publicKey,
+          governanceProposal: proposal.publicKey,
+          member: pdaMap.member,
+          daoConfig: pdaMap.daoConfig,
+          voteRecord: findVoteRecord(proposal.publicKey, pdaMap.member),
+          systemProgram: anchor.web3.SystemProgram.programId,
+          ...noQuadraticPayment,
+        })
+        .rpc();
+      assert.fail("Should not accept a single-choice ballot on a ranked proposal");
+    } catch (err) {
+      assert.include(err.toString(), "BallotModeMismatch");
+    }
+
+    try {
+      await program.methods
+        .castVote({ ranked: { ranking: Buffer.from([1, 5]) } })
+        .accountsStrict({
+          voter: proposer.publicKey,
+          governanceProposal: proposal.publicKey,
+          member: pdaMap.member,
+          daoConfig: pdaMap.daoConfig,
+          voteRecord: findVoteRecord(proposal.publicKey, pdaMap.member),
+          systemProgram: anchor.web3.SystemProgram.programId,
+          ...noQuadraticPayment,
+        })
+        .rpc();
+      assert.fail("Should not accept a ranking with a missing option");
+    } catch (err) {
+      assert.include(err.toString(), "OptionIndexOutOfRange");
+    }
+
+    const voteRecord = findVoteRecord(proposal.publicKey, pdaMap.member);
+    await program.methods
+      .castVote({ ranked: { ranking: Buffer.from([2, 0]) } })
+      .accountsStrict({
+        voter: proposer.publicKey,
+        governanceProposal: proposal.publicKey,
+        member: pdaMap.member,
+        daoConfig: pdaMap.daoConfig,
+        voteRecord,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        ...noQuadraticPayment,
+      })
+      .rpc();
+
+    await program.methods
+      .closeProposal()
+      .accountsStrict({ governanceProposal: proposal.publicKey, daoConfig: pdaMap.daoConfig, dao: proposer.publicKey })
+      .rpc();
+
+    const [rankedTally] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(RANKED_TALLY_SEED), proposal.publicKey.toBuffer()],
+      program.programId
+    );
+
+    try {
+      await program.methods
+        .getProposalResults()
+        .accounts({ governanceProposal: proposal.publicKey, rankedTally: null })
+        .view();
+      assert.fail("Should not return ranked results before the tally finishes");
+    } catch (err) {
+      assert.include(err.toString(), "IncompleteBallots");
+    }
+
+    // One voter, so a single batch counts each round; crank until the runoff settles
+    for (let round = 0; round < 3; round++) {
+      const tally = await program.account.rankedTally.fetchNullable(rankedTally);
+      if (tally?.finished) break;
+      await program.methods
+        .tallyRankedVotes()
+        .accountsStrict({
+          payer: proposer.publicKey,
+          governanceProposal: proposal.publicKey,
+          rankedTally,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .remainingAccounts([{ pubkey: voteRecord, isSigner: false, isWritable: true }])
+        .rpc();
+    }
+
+    const voteRecordAccount = await program.account.voteRecord.fetch(voteRecord);
+    const results = await program.methods
+      .getProposalResults()
+      .accounts({ governanceProposal: proposal.publicKey, rankedTally })
+      .view();
+
+    assert.equal(results[0].toNumber(), 0, "Option A should have no first preferences");
+    assert.equal(results[2].toNumber(), voteRecordAccount.weight.toNumber(), "Option C should win the runoff");
+  });
+
+  it("Admin slashes a member's reputation", async () => {
+    const before = await program.account.member.fetch(pdaMap.member);
+
+    await program.methods
+      .slashReputation(new anchor.BN(3))
+      .accountsStrict({
+        signer: proposer.publicKey,
+        daoConfig: pdaMap.daoConfig,
+        member: pdaMap.member,
+      })
+      .rpc();
+
+    const after = await program.account.member.fetch(pdaMap.member);
+    assert.equal(after.reputationPoints.toNumber(), before.reputationPoints.toNumber() - 3, "Slash should remove points");
+  });
+
+  it("Counts delegated votes and blocks the delegator from voting again", async () => {
+    const delegator = anchor.web3.Keypair.generate();
+    await airdropIfRequired(provider.connection, delegator.publicKey, anchor.web3.LAMPORTS_PER_SOL, anchor.web3.LAMPORTS_PER_SOL);
+
+    const [delegatorMember] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(MEMBER_SEED), delegator.publicKey.toBuffer()],
+      program.programId
+    );
+    const [delegation] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(DELEGATION_SEED), delegatorMember.toBuffer(), Buffer.from([0])],
+      program.programId
+    );
+
+    await program.methods
+      .initializeMember()
+      .accountsStrict({ member: delegatorMember, user: delegator.publicKey, systemProgram: anchor.web3.SystemProgram.programId })
+      .signers([delegator])
+      .rpc();
+
+    await program.methods
+      .delegateVotes(0)
+      .accountsStrict({
+        delegator: delegator.publicKey,
+        delegatorMember,
+        delegateMember: pdaMap.member,
+        delegation,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([delegator])
+      .rpc();
+
+    const delegatedProposal = anchor.web3.Keypair.generate();
+    await program.methods
+      .submitProposal("Delegated", descriptionUri, descriptionHash, 0, { single: {} }, ["Yes", "No"])
+      .accountsStrict(submitAccounts(delegatedProposal.publicKey))
+      .remainingAccounts(await depositRemainingAccounts(delegatedProposal.publicKey))
+      .signers([delegatedProposal])
+      .rpc();
+
+    const delegatorVoteRecord = findVoteRecord(delegatedProposal.publicKey, delegatorMember);
+
+    // Pre-funding the vote record address must not block the delegate from creating it
+    await provider.sendAndConfirm(new anchor.web3.Transaction().add(
+      anchor.web3.SystemProgram.transfer({
+        fromPubkey: outsider.publicKey,
+        toPubkey: delegatorVoteRecord,
+        lamports: 1_000_000,
+      })
+    ), [outsider]);
+    await program.methods
+      .castVote({ single: { optionIndex: 1 } })
+      .accountsStrict({
+        voter: proposer.publicKey,
+        governanceProposal: delegatedProposal.publicKey,
+        member: pdaMap.member,
+        daoConfig: pdaMap.daoConfig,
+        voteRecord: findVoteRecord(delegatedProposal.publicKey, pdaMap.member),
+        systemProgram: anchor.web3.SystemProgram.programId,
+        ...noQuadraticPayment,
+      })
+      .remainingAccounts([
+        { pubkey: delegation, isSigner: false, isWritable: false },
+        { pubkey: delegatorMember, isSigner: false, isWritable: false },
+        { pubkey: delegatorVoteRecord, isSigner: false, isWritable: true },
+      ])
+      .rpc();
+
+    const delegatorRecord = await program.account.voteRecord.fetch(delegatorVoteRecord);
+    assert.equal(delegatorRecord.votedBy.toBase58(), pdaMap.member.toBase58(), "Delegate should have voted for the delegator");
+    assert.ok(delegatorRecord.weight.eqn(0), "Members without locked RWD should have no voting power");
+
+    try {
+      await program.methods
+        .castVote({ single: { optionIndex: 0 } })
+        .accountsStrict({
+          voter: delegator.publicKey,
+          governanceProposal: delegatedProposal.publicKey,
+          member: delegatorMember,
+          daoConfig: pdaMap.daoConfig,
+          voteRecord: delegatorVoteRecord,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          ...noQuadraticPayment,
+        })
+        .signers([delegator])
+        .rpc();
+      assert.fail("Delegator should not be able to vote twice");
+    } catch (err) {
+      assert.notInclude(err.toString(), "Delegator should not be able to vote twice");
+    }
+  });
+
+  it("Votes with the locked power snapshotted when the proposal was created", async () => {
+    const addedAmount = toBN(1, RWD_DECIMALS).div(new anchor.BN(10));
+    const lockVault = findLockVault(pdaMap.member);
+
+    const submitSnapshotProposal = async (title: string) => {
+      const proposal = anchor.web3.Keypair.generate();
+      await program.methods
+        .submitProposal(title, descriptionUri, descriptionHash, 0, { single: {} }, ["Yes", "No"])
+        .accountsStrict(submitAccounts(proposal.publicKey))
+        .remainingAccounts(await depositRemainingAccounts(proposal.publicKey))
+        .signers([proposal])
+        .rpc();
+      return proposal.publicKey;
+    };
+
+    const voteWeight = async (proposal: anchor.web3.PublicKey) => {
+      const voteRecord = findVoteRecord(proposal, pdaMap.member);
+      await program.methods
+        .castVote({ single: { optionIndex: 0 } })
+        .accountsStrict({
+          voter: proposer.publicKey,
+          governanceProposal: proposal,
+          member: pdaMap.member,
+          daoConfig: pdaMap.daoConfig,
+          voteRecord,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          ...noQuadraticPayment,
+        })
+        .rpc();
+      return (await program.account.voteRecord.fetch(voteRecord)).weight.toNumber();
+    };
+
+    const before = await submitSnapshotProposal("Before increase");
+
+    await program.methods
+      .increaseLockAmount(addedAmount)
+      .accountsStrict({
+        owner: proposer.publicKey,
+        member: pdaMap.member,
+        rwdMint: pdaMap.mint,
+        ownerRwd: sourceTokenAccount,
+        lockVault,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .remainingAccounts(await getTransferHookRemainingAccounts(
+        provider.connection, sourceTokenAccount, pdaMap.mint, lockVault, proposer.publicKey, addedAmount
+      ))
+      .rpc();
+    await waitForNextSlot();
+
+    const after = await submitSnapshotProposal("After increase");
+
+    // Reputation is snapshotted too, so voting on the older proposal first can't lift it
+    const weightBefore = await voteWeight(before);
+    const weightAfter = await voteWeight(after);
+    assert.ok(
+      weightAfter > weightBefore,
+      "Tokens locked after a proposal was created should not count toward it"
+    );
+  });
+
+  it("Does not execute a clawback its only voter approved and closed", async () => {
+    // Quorum above what a single member can cast; proposals snapshot it at submission
+    await program.methods
+      .updateGovernanceConfig({ ...governanceConfig, quorum: toBN(1_000_000, RWD_DECIMALS) })
+      .accountsStrict({ signer: proposer.publicKey, daoConfig: pdaMap.daoConfig })
+      .rpc();
+
+    await transferTokens(transfer_program, proposer, depositConfig.amount, sourceTokenAccount, outsiderRwd, pdaMap);
+
+    const proposal = anchor.web3.Keypair.generate();
+    const depositVault = findDepositVault(proposal.publicKey);
+    await program.methods
+      .submitActionProposal("Claw back", descriptionUri, descriptionHash, 0, {
+        clawback: { tokenAccount: outsiderRwd, amount: new anchor.BN(1), burn: true },
+      })
+      .accountsStrict({
+        ...submitAccounts(proposal.publicKey),
+        proposer: outsider.publicKey,
+        proposerMember: outsiderMember,
+        proposerRwd: outsiderRwd,
+      })
+      .remainingAccounts(await getTransferHookRemainingAccounts(
+        provider.connection, outsiderRwd, pdaMap.mint, depositVault, outsider.publicKey, depositConfig.amount
+      ))
+      .signers([proposal, outsider])
+      .rpc();
+
+    await program.methods
+      .castVote({ single: { optionIndex: 0 } })
+      .accountsStrict({
+        voter: outsider.publicKey,
+        governanceProposal: proposal.publicKey,
+        member: outsiderMember,
+        daoConfig: pdaMap.daoConfig,
+        voteRecord: findVoteRecord(proposal.publicKey, outsiderMember),
+        systemProgram: anchor.web3.SystemProgram.programId,
+        ...noQuadraticPayment,
+      })
+      .signers([outsider])
+      .rpc();
+
+    const closeAccounts = { governanceProposal: proposal.publicKey, daoConfig: pdaMap.daoConfig, dao: outsider.publicKey };
+    const [compliance] = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from(COMPLIANCE_SEED)], mint_program.programId);
+    const [permanentDelegate] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(PERMANENT_DELEGATE_SEED)], mint_program.programId
+    );
+    const executeClawback = () =>
+      program.methods
+        .executeClawbackProposal()
+        .accountsStrict({
+          governanceProposal: proposal.publicKey,
+          treasury: pdaMap.treasury,
+          compliance,
+          permanentDelegate,
+          rwdMint: pdaMap.mint,
+          source: outsiderRwd,
+          rwdVault: pdaMap.treasuryRwd,
+          ...findBurnLotAccounts(mint_program.programId, transfer_program.programId, outsider.publicKey),
+          rewardsProgram: mint_program.programId,
+          tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+        })
+        .rpc();
+
+    try {
+      await program.methods.closeProposal().accountsStrict(closeAccounts).signers([outsider]).rpc();
+      assert.fail("A member should not close their own proposal while voting is open");
+    } catch (err) {
+      assert.include(err.toString(), "UnauthorizedClose");
+    }
+
+    try {
+      await executeClawback();
+      assert.fail("An open proposal should not execute");
+    } catch (err) {
+      assert.include(err.toString(), "ProposalNotApproved");
+    }
+
+    await waitForVotingEnd(proposal.publicKey);
+    await program.methods.closeProposal().accountsStrict(closeAccounts).signers([outsider]).rpc();
+
+    try {
+      await executeClawback();
+      assert.fail("A proposal below quorum should not execute");
+    } catch (err) {
+      assert.include(err.toString(), "ProposalNotApproved");
+    }
+
+    await program.methods
+      .updateGovernanceConfig(governanceConfig)
+      .accountsStrict({ signer: proposer.publicKey, daoConfig: pdaMap.daoConfig })
+      .rpc();
+  });
 });
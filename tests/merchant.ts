@@ -0,0 +1,204 @@
+import assert from "assert";
+
+import * as anchor from "@coral-xyz/anchor";
+import { TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { makeKeypairs } from "@solana-developers/helpers";
+
+import {
+  EXTRA_ACCOUNT_METAS,
+  FEES_SEED,
+  FREEZE_SEED,
+  MERCHANT_BUDGET_SEED,
+  MERCHANT_SEED,
+  RWD_DECIMALS,
+  RWD_PER_USDC,
+  TOKEN_2022_SEED,
+  USDC_MINT_ADDRESS,
+  USDC_SEED,
+} from "../utils/constants";
+import { findATAs, findBurnLotAccounts, findPDAs, getProvider, getTokenBalance, toBN } from "../utils/setup";
+import { initializeAll } from "../utils/initialization";
+import { mintTokens } from "../utils/mint";
+import { getTransferHookRemainingAccounts } from "../utils/transfer";
+import type { Rewards } from "../target/types/rewards";
+import type { TransferHook } from "../target/types/transfer_hook";
+
+describe("merchants", () => {
+  const provider = getProvider();
+  const program = anchor.workspace.Rewards as anchor.Program<Rewards>;
+  const transfer_program = anchor.workspace.TransferHook as anchor.Program<TransferHook>;
+  const connection = provider.connection;
+  const wallet = provider.wallet as anchor.Wallet;
+
+  const [feeCollector] = makeKeypairs(1);
+
+  // The wallet acts as vault authority, merchant and customer
+  let pdaMap = findPDAs(program, {
+    mint: [Buffer.from(TOKEN_2022_SEED)],
+    usdcKeeper: [Buffer.from(USDC_SEED)],
+    fees: [Buffer.from(FEES_SEED)],
+    freezeState: [Buffer.from(FREEZE_SEED)],
+    merchant: [Buffer.from(MERCHANT_SEED), wallet.publicKey.toBuffer()],
+  });
+
+  pdaMap = {
+    ...pdaMap,
+    ...findPDAs(program, {
+      budgetVault: [Buffer.from(MERCHANT_BUDGET_SEED), pdaMap.merchant.toBuffer()],
+    }),
+    ...findPDAs(transfer_program, {
+      extraAccountMetaList: [Buffer.from(EXTRA_ACCOUNT_METAS), pdaMap.mint.toBuffer()],
+    }),
+  };
+
+  const usdcMint = new anchor.web3.PublicKey(USDC_MINT_ADDRESS);
+  const ataMap = findATAs(pdaMap.mint, {
+    payer: wallet.publicKey,
+    feeCollector: feeCollector.publicKey,
+  });
+  const payerATA = ataMap.payer;
+  const settlementAccount = anchor.utils.token.associatedAddress({ mint: usdcMint, owner: wallet.publicKey });
+
+  const initFeesArgs = {
+    mintFeeBps: 100,
+    transferFeeBps: 100,
+    redemptionFeeBps: 100,
+    feeCollector: ataMap.feeCollector,
+  };
+
+  const earnRateBps = 500;
+  const budget = toBN(2, RWD_DECIMALS);
+
+  it("Registers and funds a merchant", async () => {
+    await initializeAll(program, transfer_program, wallet, usdcMint, initFeesArgs, pdaMap);
+    await mintTokens(program, wallet, toBN(10, RWD_DECIMALS), payerATA, pdaMap, feeCollector.publicKey, ataMap.feeCollector, usdcMint);
+
+    await program.methods
+      .registerMerchant({ earnRateBps })
+      .accountsStrict({
+        signer: wallet.publicKey,
+        merchantAuthority: wallet.publicKey,
+        merchant: pdaMap.merchant,
+        budgetVault: pdaMap.budgetVault,
+        mint: pdaMap.mint,
+        usdcMint,
+        usdcKeeper: pdaMap.usdcKeeper,
+        settlementAccount,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+    await program.methods
+      .fundMerchantBudget(budget)
+      .accountsStrict({
+        funder: wallet.publicKey,
+        merchant: pdaMap.merchant,
+        mint: pdaMap.mint,
+        funderAta: payerATA,
+        budgetVault: pdaMap.budgetVault,
+        tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+      })
+      .remainingAccounts(await getTransferHookRemainingAccounts(
+        connection, payerATA, pdaMap.mint, pdaMap.budgetVault, wallet.publicKey, budget
+      ))
+      .rpc();
+
+    const merchant = await program.account.merchant.fetch(pdaMap.merchant);
+    assert.ok(merchant.budget.eq(budget), "Budget should be funded");
+    assert.equal(merchant.earnRateBps, earnRateBps);
+  });
+
+  it("Awards points from the budget", async () => {
+    const purchaseAmount = toBN(10, RWD_DECIMALS);
+    const expectedAward = purchaseAmount.muln(earnRateBps).divn(10000);
+
+    await program.methods
+      .awardPoints(purchaseAmount)
+      .accountsStrict({
+        authority: wallet.publicKey,
+        merchant: pdaMap.merchant,
+        mint: pdaMap.mint,
+        budgetVault: pdaMap.budgetVault,
+        customerAta: payerATA,
+        tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+      })
+      .remainingAccounts(await getTransferHookRemainingAccounts(
+        connection, pdaMap.budgetVault, pdaMap.mint, payerATA, pdaMap.merchant, expectedAward
+      ))
+      .rpc();
+
+    const merchant = await program.account.merchant.fetch(pdaMap.merchant);
+    assert.ok(merchant.totalAwarded.eq(expectedAward), "Award should follow the earn rate");
+    assert.ok(merchant.budget.eq(budget.sub(expectedAward)), "Award should come out of the budget");
+
+    try {
+      await program.methods
+        .awardPoints(budget.muln(10000 / earnRateBps).muln(2))
+        .accountsStrict({
+          authority: wallet.publicKey,
+          merchant: pdaMap.merchant,
+          mint: pdaMap.mint,
+          budgetVault: pdaMap.budgetVault,
+          customerAta: payerATA,
+          tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+        })
+        .rpc();
+      assert.fail("Should not award beyond the budget");
+    } catch (err) {
+      assert.ok(err.toString().includes("MerchantBudgetExceeded"));
+    }
+  });
+
+  it("Redeems RWD at the merchant and settles in USDC", async () => {
+    const redeemAmount = toBN(1, RWD_DECIMALS);
+    const usdcAmount = redeemAmount.divn(RWD_PER_USDC);
+    const redeem = (amount: anchor.BN) =>
+      program.methods
+        .redeemAtMerchant(amount)
+        .accountsStrict({
+          customer: wallet.publicKey,
+          merchant: pdaMap.merchant,
+          mint: pdaMap.mint,
+          customerAta: payerATA,
+          freezeState: pdaMap.freezeState,
+          ...findBurnLotAccounts(program.programId, transfer_program.programId, wallet.publicKey),
+          tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+        })
+        .rpc();
+
+    try {
+      await redeem(redeemAmount.addn(1));
+      assert.fail("Should not burn RWD that doesn't add up to a whole USDC");
+    } catch (err) {
+      assert.ok(err.toString().includes("InvalidRedemptionAmount"));
+    }
+
+    await redeem(redeemAmount);
+
+    let merchant = await program.account.merchant.fetch(pdaMap.merchant);
+    assert.ok(merchant.pendingSettlementUsdc.eq(usdcAmount), "Redemption should be owed to the merchant");
+
+    const usdcBefore = await getTokenBalance(connection, settlementAccount);
+
+    await program.methods
+      .settleMerchant()
+      .accountsStrict({
+        signer: wallet.publicKey,
+        merchant: pdaMap.merchant,
+        usdcMint,
+        usdcKeeper: pdaMap.usdcKeeper,
+        settlementAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+
+    const usdcAfter = await getTokenBalance(connection, settlementAccount);
+    assert.ok(usdcAfter.eq(usdcBefore.add(usdcAmount)), "Merchant should receive the USDC settlement");
+
+    merchant = await program.account.merchant.fetch(pdaMap.merchant);
+    assert.ok(merchant.pendingSettlementUsdc.isZero(), "Nothing should remain pending");
+    assert.ok(merchant.totalSettledUsdc.eq(usdcAmount));
+  });
+});
@@ -0,0 +1,164 @@
+import assert from "assert";
+
+import * as anchor from "@coral-xyz/anchor";
+import { TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { airdropIfRequired, makeKeypairs } from "@solana-developers/helpers";
+
+import {
+  EXTRA_ACCOUNT_METAS,
+  FEES_SEED,
+  FREEZE_SEED,
+  LOT_LEDGER_SEED,
+  PERMANENT_DELEGATE_SEED,
+  POINTS_EXPIRY_SEED,
+  RWD_DECIMALS,
+  TOKEN_2022_SEED,
+  USDC_MINT_ADDRESS,
+  USDC_SEED,
+} from "../utils/constants";
+import { findATAs, findPDAs, getProvider, getTokenBalance, toBN } from "../utils/setup";
+import { initializeAll } from "../utils/initialization";
+import { burnTokens } from "../utils/burn";
+import { mintTokens } from "../utils/mint";
+import { transferTokens } from "../utils/transfer";
+import type { Rewards } from "../target/types/rewards";
+import type { TransferHook } from "../target/types/transfer_hook";
+
+describe("expiring points", () => {
+  const provider = getProvider();
+  const program = anchor.workspace.Rewards as anchor.Program<Rewards>;
+  const transfer_program = anchor.workspace.TransferHook as anchor.Program<TransferHook>;
+  const connection = provider.connection;
+  const wallet = provider.wallet as anchor.Wallet;
+
+  const [feeCollector] = makeKeypairs(1);
+
+  let pdaMap = findPDAs(program, {
+    mint: [Buffer.from(TOKEN_2022_SEED)],
+    usdcKeeper: [Buffer.from(USDC_SEED)],
+    fees: [Buffer.from(FEES_SEED)],
+    freezeState: [Buffer.from(FREEZE_SEED)],
+    expiryConfig: [Buffer.from(POINTS_EXPIRY_SEED)],
+    permanentDelegate: [Buffer.from(PERMANENT_DELEGATE_SEED)],
+  });
+
+  pdaMap = {
+    ...pdaMap,
+    ...findPDAs(transfer_program, {
+      extraAccountMetaList: [Buffer.from(EXTRA_ACCOUNT_METAS), pdaMap.mint.toBuffer()],
+      lotLedger: [Buffer.from(LOT_LEDGER_SEED), wallet.publicKey.toBuffer()],
+    }),
+  };
+
+  const usdcMint = new anchor.web3.PublicKey(USDC_MINT_ADDRESS);
+  const ataMap = findATAs(pdaMap.mint, {
+    payer: wallet.publicKey,
+    feeCollector: feeCollector.publicKey,
+  });
+  const payerATA = ataMap.payer;
+
+  const initFeesArgs = {
+    mintFeeBps: 100,
+    transferFeeBps: 100,
+    redemptionFeeBps: 100,
+    feeCollector: ataMap.feeCollector,
+  };
+
+  const issuedAmount = toBN(3, RWD_DECIMALS);
+  const spentAmount = toBN(1, RWD_DECIMALS);
+  const burnedAmount = toBN(1, RWD_DECIMALS);
+  const expiryPeriod = new anchor.BN(1);
+
+  it("Issues points into a lot ledger", async () => {
+    await initializeAll(program, transfer_program, wallet, usdcMint, initFeesArgs, pdaMap);
+    await mintTokens(program, wallet, toBN(1, RWD_DECIMALS), payerATA, pdaMap, feeCollector.publicKey, ataMap.feeCollector, usdcMint);
+
+    const outsider = anchor.web3.Keypair.generate();
+    await airdropIfRequired(connection, outsider.publicKey, anchor.web3.LAMPORTS_PER_SOL, anchor.web3.LAMPORTS_PER_SOL);
+    await assert.rejects(
+      program.methods
+        .initializePointsExpiry({ enabled: true, expiryPeriod })
+        .accountsStrict({
+          signer: outsider.publicKey,
+          mint: pdaMap.mint,
+          expiryConfig: pdaMap.expiryConfig,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([outsider])
+        .rpc(),
+      /Unauthorized/,
+      "Only the mint authority should configure expiry"
+    );
+
+    await program.methods
+      .initializePointsExpiry({ enabled: true, expiryPeriod })
+      .accountsStrict({
+        signer: wallet.publicKey,
+        mint: pdaMap.mint,
+        expiryConfig: pdaMap.expiryConfig,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    await program.methods
+      .issuePoints(issuedAmount)
+      .accountsStrict({
+        signer: wallet.publicKey,
+        expiryConfig: pdaMap.expiryConfig,
+        mint: pdaMap.mint,
+        recipient: wallet.publicKey,
+        recipientAta: payerATA,
+        lotLedger: pdaMap.lotLedger,
+        freezeState: pdaMap.freezeState,
+        transferHookProgram: transfer_program.programId,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+    const ledger = await transfer_program.account.lotLedger.fetch(pdaMap.lotLedger);
+    assert.equal(ledger.lots.length, 1, "Issuance should record a lot");
+    assert.ok(ledger.lots[0].amount.eq(issuedAmount));
+  });
+
+  it("Spends the oldest lot on transfer", async () => {
+    await transferTokens(transfer_program, wallet, spentAmount, payerATA, ataMap.feeCollector, pdaMap);
+
+    const ledger = await transfer_program.account.lotLedger.fetch(pdaMap.lotLedger);
+    assert.ok(ledger.lots[0].amount.eq(issuedAmount.sub(spentAmount)), "Transfer should consume the lot");
+  });
+
+  it("Spends the oldest lot on burn", async () => {
+    const { feeCollector: currentFeeCollector } = await program.account.fees.fetch(pdaMap.fees);
+    await burnTokens(program, transfer_program.programId, wallet, burnedAmount, payerATA, currentFeeCollector, pdaMap, usdcMint);
+
+    const ledger = await transfer_program.account.lotLedger.fetch(pdaMap.lotLedger);
+    assert.ok(ledger.lots[0].amount.eq(issuedAmount.sub(spentAmount).sub(burnedAmount)), "Burn should consume the lot");
+  });
+
+  it("Burns expired points", async () => {
+    await new Promise((resolve) => setTimeout(resolve, 2000 * expiryPeriod.toNumber()));
+
+    const balanceBefore = await getTokenBalance(connection, payerATA);
+
+    await program.methods
+      .expirePoints()
+      .accountsStrict({
+        expiryConfig: pdaMap.expiryConfig,
+        permanentDelegate: pdaMap.permanentDelegate,
+        mint: pdaMap.mint,
+        ownerAta: payerATA,
+        lotLedger: pdaMap.lotLedger,
+        transferHookProgram: transfer_program.programId,
+        tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+    const balanceAfter = await getTokenBalance(connection, payerATA);
+    const unspentAmount = issuedAmount.sub(spentAmount).sub(burnedAmount);
+    assert.ok(balanceAfter.eq(balanceBefore.sub(unspentAmount)), "Unspent points should be burned");
+
+    const ledger = await transfer_program.account.lotLedger.fetch(pdaMap.lotLedger);
+    assert.equal(ledger.lots.length, 0, "Expired lots should be dropped");
+  });
+});
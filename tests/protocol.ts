@@ -0,0 +1,125 @@
+import assert from "assert";
+
+import * as anchor from "@coral-xyz/anchor";
+import { airdropIfRequired, makeKeypairs } from "@solana-developers/helpers";
+
+import {
+  COMPLIANCE_SEED,
+  EXTRA_ACCOUNT_METAS,
+  FEES_SEED,
+  FREEZE_SEED,
+  TOKEN_2022_SEED,
+  USDC_MINT_ADDRESS,
+  USDC_SEED,
+} from "../utils/constants";
+import { findATAs, findPDAs, getProvider } from "../utils/setup";
+import { initializeAll, initializeProtocol } from "../utils/initialization";
+import type { Rewards } from "../target/types/rewards";
+import type { TransferHook } from "../target/types/transfer_hook";
+
+describe("protocol initialization", () => {
+  const provider = getProvider();
+  const program = anchor.workspace.Rewards as anchor.Program<Rewards>;
+  const transfer_program = anchor.workspace.TransferHook as anchor.Program<TransferHook>;
+  const connection = provider.connection;
+  const wallet = provider.wallet as anchor.Wallet;
+
+  const [feeCollector, outsider] = makeKeypairs(2);
+
+  let pdaMap = findPDAs(program, {
+    mint: [Buffer.from(TOKEN_2022_SEED)],
+    usdcKeeper: [Buffer.from(USDC_SEED)],
+    fees: [Buffer.from(FEES_SEED)],
+    freezeState: [Buffer.from(FREEZE_SEED)],
+    compliance: [Buffer.from(COMPLIANCE_SEED)],
+  });
+
+  pdaMap = {
+    ...pdaMap,
+    ...findPDAs(transfer_program, {
+      extraAccountMetaList: [Buffer.from(EXTRA_ACCOUNT_METAS), pdaMap.mint.toBuffer()],
+    }),
+  };
+
+  const usdcMint = new anchor.web3.PublicKey(USDC_MINT_ADDRESS);
+  const ataMap = findATAs(pdaMap.mint, { feeCollector: feeCollector.publicKey });
+
+  const initFeesArgs = {
+    mintFeeBps: 100,
+    transferFeeBps: 100,
+    redemptionFeeBps: 100,
+    feeCollector: ataMap.feeCollector,
+  };
+
+  it("Creates the mint, fees, freeze state and compliance in one transaction", async () => {
+    await initializeAll(program, transfer_program, wallet, usdcMint, initFeesArgs, pdaMap);
+
+    // The compliance config is only ever written by its creating transaction and the
+    // officer instructions, so its oldest signature is the protocol initialization
+    const signatures = await connection.getSignaturesForAddress(pdaMap.compliance, { limit: 1000 }, "confirmed");
+    const creation = signatures[signatures.length - 1];
+    assert.ok(!creation.err, "Protocol initialization should have succeeded");
+
+    const tx = await connection.getTransaction(creation.signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    const accountKeys = tx.transaction.message.getAccountKeys().staticAccountKeys.map((key) => key.toBase58());
+    for (const account of [pdaMap.mint, pdaMap.fees, pdaMap.freezeState, pdaMap.usdcKeeper, pdaMap.compliance]) {
+      assert.ok(accountKeys.includes(account.toBase58()), `${account.toBase58()} should be created by the same transaction`);
+    }
+    assert.ok(
+      tx.meta.logMessages.some((log) => log.includes("Instruction: InitializeProtocol")),
+      "Accounts should be created by initialize_protocol"
+    );
+
+    const freezeState = await program.account.freezeState.fetch(pdaMap.freezeState);
+    assert.equal(freezeState.authority.toBase58(), wallet.publicKey.toBase58(), "Mint authority should hold the freeze role");
+    const compliance = await program.account.compliance.fetch(pdaMap.compliance);
+    assert.equal(compliance.authority.toBase58(), wallet.publicKey.toBase58(), "Mint authority should hold the compliance role");
+  });
+
+  it("Rejects a second protocol initialization", async () => {
+    await assert.rejects(
+      initializeProtocol(program, wallet, usdcMint, initFeesArgs, outsider.publicKey, pdaMap),
+      "The protocol should only be initialized once"
+    );
+
+    const compliance = await program.account.compliance.fetch(pdaMap.compliance);
+    assert.notEqual(compliance.officer.toBase58(), outsider.publicKey.toBase58(), "Compliance officer should be unchanged");
+  });
+
+  it("Rejects freeze and compliance setup by anyone but the mint authority", async () => {
+    await airdropIfRequired(connection, outsider.publicKey, anchor.web3.LAMPORTS_PER_SOL, anchor.web3.LAMPORTS_PER_SOL);
+
+    await assert.rejects(
+      program.methods
+        .initializeFreeze()
+        .accountsStrict({
+          signer: outsider.publicKey,
+          mint: pdaMap.mint,
+          freezeState: pdaMap.freezeState,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([outsider])
+        .rpc(),
+      /Unauthorized/,
+      "Only the mint authority should set up the freeze state"
+    );
+
+    await assert.rejects(
+      program.methods
+        .initializeCompliance(outsider.publicKey)
+        .accountsStrict({
+          signer: outsider.publicKey,
+          mint: pdaMap.mint,
+          compliance: pdaMap.compliance,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([outsider])
+        .rpc(),
+      /Unauthorized/,
+      "Only the mint authority should set up compliance"
+    );
+  });
+});
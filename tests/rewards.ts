@@ -6,6 +6,7 @@ import { Rewards } from "../target/types/rewards";
 import {
   ASSOCIATED_TOKEN_PROGRAM_ID,
   createAssociatedTokenAccountInstruction,
+  getTokenMetadata,
   TOKEN_2022_PROGRAM_ID,
   TOKEN_PROGRAM_ID
 } from '@solana/spl-token';
@@ -20,11 +21,12 @@ import {
   RWD_DECIMALS,
   FREEZE_SEED,
   RWD_PER_USDC,
+  TRANSFER_POLICY_SEED,
 } from '../utils/constants';
 import { makeKeypairs, airdropIfRequired } from "@solana-developers/helpers"
 import { calcFee, findATAs, findPDAs, getTokenBalance, toBN } from "../utils/setup";
-import { initializeFreeze, initializeMint } from "../utils/initialization";
-import { getFreezeState } from "../utils/freezeOps";
+import { initializeFreeze, initializeProtocol } from "../utils/initialization";
+import { getFreezeState, unfreezeOperation } from "../utils/freezeOps";
 import { mintTokens } from "../utils/mint";
 import { burnTokens } from "../utils/burn";
 
@@ -45,6 +47,7 @@ describe("Rewards Test", () => {
     usdcKeeper: [Buffer.from(USDC_SEED)],
     fees: [Buffer.from(FEES_SEED)],
     freezeState: [Buffer.from(FREEZE_SEED)],
+    transferPolicy: [Buffer.from(TRANSFER_POLICY_SEED)],
   });
 
   // ATA Accounts: 
@@ -96,8 +99,10 @@ describe("Rewards Test", () => {
     program.programId
   );
 
-  it("Initialize token", async () => {
-    await initializeMint(program, wallet, usdcMint, pdaMap);
+  it("Initialize protocol", async () => {
+    if (!(await connection.getAccountInfo(pdaMap.mint))) {
+      await initializeProtocol(program, wallet, usdcMint, initFeesArgs, wallet.publicKey, pdaMap);
+    }
 
     const newMintInfo = await connection.getAccountInfo(pdaMap.mint);
     assert(newMintInfo, "Mint should be initialized.");
@@ -106,42 +111,45 @@ describe("Rewards Test", () => {
     assert(newUSDCKeeperInfo, "USDC keeper should be initialized.");
   });
 
-  it("Initialize fees", async () => {
-    const feesInfo = await connection.getAccountInfo(fees);
-    if (feesInfo) {
-      return; // Do not attempt to initialize if already initialized
-    }
+  it("Manage transfer whitelist", async () => {
+    const policy = await program.account.transferPolicy.fetch(pdaMap.transferPolicy);
+    assert.equal(policy.soulbound, false, "Test mint should be transferable");
 
-    const initFeesAccountSchema = borsh.struct([
-      borsh.u64("discriminator"),
-      borsh.u16("mintFeeBps"),
-      borsh.u16("transferFeeBps"),
-      borsh.u16("redemtionFeeBps"),
-      borsh.publicKey("feeCollector"),
-    ]);
+    const accounts = { authority: wallet.publicKey, transferPolicy: pdaMap.transferPolicy };
+    await program.methods.addTransferWhitelist(program.programId).accountsStrict(accounts).rpc();
 
-    const ix = await program.methods
-      .initializeFees(initFeesArgs)
-      .accountsStrict({
-        signer: wallet.publicKey,
-        fees,
-        systemProgram: anchor.web3.SystemProgram.programId,
-      })
-      .instruction();
-
-    const tx = new anchor.web3.Transaction().add(ix);
+    let updated = await program.account.transferPolicy.fetch(pdaMap.transferPolicy);
+    assert.ok(updated.whitelist.some((entry) => entry.equals(program.programId)), "Entry should be whitelisted");
 
-    const sig = await sendAndConfirmTransaction(connection, tx, [wallet.payer]);
-    assert.ok(sig);
+    await program.methods.removeTransferWhitelist(program.programId).accountsStrict(accounts).rpc();
 
-    const newFeesInfo = await connection.getAccountInfo(fees);
-    assert(newFeesInfo, "Fees should be initialized.");
+    updated = await program.account.transferPolicy.fetch(pdaMap.transferPolicy);
+    assert.equal(updated.whitelist.length, 0, "Entry should be removed");
+  });
 
-    const initFeesData = initFeesAccountSchema.decode(newFeesInfo.data);
-    assert.equal(initFeesData.mintFeeBps, initFeesArgs.mintFeeBps, "Mint fee mismatch");
-    assert.equal(initFeesData.transferFeeBps, initFeesArgs.transferFeeBps, "Transfer fee mismatch");
-    assert.equal(initFeesData.redemtionFeeBps, initFeesArgs.redemptionFeeBps, "Redemption fee mismatch");
-    assert.equal(initFeesData.feeCollector.toString(), initFeesArgs.feeCollector.toString(), "Fee collector mismatch");
+  it("Update metadata", async () => {
+    const accounts = {
+      signer: wallet.publicKey,
+      mint: pdaMap.mint,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+    };
+    const uri = "https://example.com/rwd.json";
+
+    await program.methods
+      .updateMetadata({ name: null, symbol: null, uri })
+      .accountsStrict(accounts)
+      .rpc();
+    await program.methods.setMetadataField("website", "https://example.com").accountsStrict(accounts).rpc();
+
+    let tokenMetadata = await getTokenMetadata(connection, pdaMap.mint, "confirmed", TOKEN_2022_PROGRAM_ID);
+    assert.equal(tokenMetadata.uri, uri, "URI should be updated");
+    assert.deepEqual(tokenMetadata.additionalMetadata, [["website", "https://example.com"]]);
+
+    await program.methods.removeMetadataField("website").accountsStrict(accounts).rpc();
+
+    tokenMetadata = await getTokenMetadata(connection, pdaMap.mint, "confirmed", TOKEN_2022_PROGRAM_ID);
+    assert.equal(tokenMetadata.additionalMetadata.length, 0, "Custom field should be removed");
   });
 
   it("Update fees", async () => {
@@ -151,6 +159,7 @@ describe("Rewards Test", () => {
       borsh.u16("transfer_fee_bps"),
       borsh.u16("redeem_fee_bps"),
       borsh.publicKey("fee_collector"),
+      borsh.publicKey("authority"),
     ]);
 
     const ix = await program.methods
@@ -172,6 +181,17 @@ describe("Rewards Test", () => {
     assert.equal(updateFeesData.transfer_fee_bps, initFeesArgs.transferFeeBps, "Transfer fee mismatch");
     assert.equal(updateFeesData.redeem_fee_bps, updateFeesArgs.redemptionFeeBps, "Redemption fee mismatch");
     assert.equal(updateFeesArgs.feeCollector.toString(), updateFeesData.fee_collector.toString(), "Fee collector mismatch");
+
+    try {
+      await program.methods
+        .updateFees({ ...updateFeesArgs, feeCollector: receiver.publicKey })
+        .accountsStrict({ signer: receiver.publicKey, fees })
+        .signers([receiver])
+        .rpc();
+      assert.fail("Only the fees authority should update the fees");
+    } catch (err) {
+      assert.ok(err.toString().includes("Unauthorized"));
+    }
   });
 
   it("Initialize Freeze Account", async () => {
@@ -265,7 +285,7 @@ describe("Rewards Test", () => {
 
     const fee = calcFee(burnAmount, updateFeesArgs.redemptionFeeBps);
 
-    const sig = await burnTokens(program, wallet, burnAmount, payerATA, feeCollector2ATA, pdaMap, usdcMint);
+    const sig = await burnTokens(program, transfer_program.programId, wallet, burnAmount, payerATA, feeCollector2ATA, pdaMap, usdcMint);
     console.log(sig);
 
     const postRWDBalance = await getTokenBalance(connection, payerATA);
@@ -299,4 +319,73 @@ describe("Rewards Test", () => {
       "USDC To balance should increase correctly"
     );
   });
+
+  it("Transfer freeze authority and unfreeze with pauser approvals", async () => {
+    const freezeAccounts = (signer: anchor.web3.PublicKey) => ({
+      freezeState: pdaMap.freezeState,
+      signer,
+    });
+
+    await program.methods.proposeFreezeAuthority(receiver.publicKey).accountsStrict(freezeAccounts(wallet.publicKey)).rpc();
+    await program.methods.acceptFreezeAuthority().accountsStrict(freezeAccounts(receiver.publicKey)).signers([receiver]).rpc();
+    let freezeState = await getFreezeState(program, pdaMap);
+    assert.ok(freezeState.authority.equals(receiver.publicKey), "Receiver should be the freeze authority");
+    assert.ok(freezeState.pendingAuthority === null, "Pending authority should be cleared");
+
+    await program.methods.proposeFreezeAuthority(wallet.publicKey).accountsStrict(freezeAccounts(receiver.publicKey)).signers([receiver]).rpc();
+    await program.methods.acceptFreezeAuthority().accountsStrict(freezeAccounts(wallet.publicKey)).rpc();
+
+    await program.methods
+      .setPausers([wallet.publicKey, feeCollector1.publicKey], 2)
+      .accountsStrict(freezeAccounts(wallet.publicKey))
+      .rpc();
+
+    await program.methods
+      .freeze({ mint: {} }, new anchor.BN(3600))
+      .accountsStrict({ ...freezeAccounts(feeCollector1.publicKey), mint: pdaMap.mint })
+      .signers([feeCollector1])
+      .rpc();
+    freezeState = await getFreezeState(program, pdaMap);
+    assert.ok(freezeState.freezeMint, "Any pauser should be able to freeze");
+    assert.ok(freezeState.mintFrozenUntil.gtn(0), "Timed freeze should record when it lapses");
+
+    const frozenUntil = freezeState.mintFrozenUntil;
+    await program.methods
+      .freeze({ mint: {} }, freezeState.maxPauseDuration)
+      .accountsStrict({ ...freezeAccounts(feeCollector1.publicKey), mint: pdaMap.mint })
+      .signers([feeCollector1])
+      .rpc();
+    freezeState = await getFreezeState(program, pdaMap);
+    assert.ok(
+      freezeState.mintFrozenUntil.lte(freezeState.mintFrozenSince.add(freezeState.maxPauseDuration)),
+      "Refreezing should not push the lapse past the cap from when the freeze started"
+    );
+
+    await program.methods.extendFreeze({ mint: {} }, new anchor.BN(3600)).accountsStrict(freezeAccounts(wallet.publicKey)).rpc();
+    freezeState = await getFreezeState(program, pdaMap);
+    assert.ok(
+      freezeState.mintFrozenUntil.gt(frozenUntil),
+      "Freeze authority should extend the freeze explicitly"
+    );
+
+    try {
+      await unfreezeOperation(program, wallet, { mint: {} }, pdaMap);
+      assert.fail("Authority should not unfreeze alone while pausers are set");
+    } catch (err) {
+      assert.ok(err.toString().includes("UnfreezeRequiresApprovals"));
+    }
+
+    await program.methods.approveUnfreeze({ mint: {} }).accountsStrict(freezeAccounts(wallet.publicKey)).rpc();
+    assert.ok((await getFreezeState(program, pdaMap)).freezeMint, "One approval should not be enough");
+
+    await program.methods
+      .approveUnfreeze({ mint: {} })
+      .accountsStrict(freezeAccounts(feeCollector1.publicKey))
+      .signers([feeCollector1])
+      .rpc();
+    freezeState = await getFreezeState(program, pdaMap);
+    assert.ok(!freezeState.freezeMint, "Minting should be unfrozen after 2 of 2 approvals");
+
+    await program.methods.setPausers([], 0).accountsStrict(freezeAccounts(wallet.publicKey)).rpc();
+  });
 });
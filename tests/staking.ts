@@ -0,0 +1,215 @@
+import assert from "assert";
+
+import * as anchor from "@coral-xyz/anchor";
+import { TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { airdropIfRequired, makeKeypairs } from "@solana-developers/helpers";
+
+import {
+  EXTRA_ACCOUNT_METAS,
+  FEES_SEED,
+  FREEZE_SEED,
+  REWARD_POOL_SEED,
+  REWARD_VAULT_SEED,
+  RWD_DECIMALS,
+  STAKE_SEED,
+  STAKE_VAULT_SEED,
+  TOKEN_2022_SEED,
+  USDC_MINT_ADDRESS,
+  USDC_SEED,
+} from "../utils/constants";
+import { findATAs, findPDAs, getProvider, getTokenBalance, toBN } from "../utils/setup";
+import { initializeAll } from "../utils/initialization";
+import { mintTokens } from "../utils/mint";
+import { getTransferHookRemainingAccounts } from "../utils/transfer";
+import type { Rewards } from "../target/types/rewards";
+import type { TransferHook } from "../target/types/transfer_hook";
+
+describe("staking rewards", () => {
+  const provider = getProvider();
+  const program = anchor.workspace.Rewards as anchor.Program<Rewards>;
+  const transfer_program = anchor.workspace.TransferHook as anchor.Program<TransferHook>;
+  const connection = provider.connection;
+  const wallet = provider.wallet as anchor.Wallet;
+
+  const [feeCollector] = makeKeypairs(1);
+
+  let pdaMap = findPDAs(program, {
+    mint: [Buffer.from(TOKEN_2022_SEED)],
+    usdcKeeper: [Buffer.from(USDC_SEED)],
+    fees: [Buffer.from(FEES_SEED)],
+    freezeState: [Buffer.from(FREEZE_SEED)],
+    rewardPool: [Buffer.from(REWARD_POOL_SEED)],
+    stakeVault: [Buffer.from(STAKE_VAULT_SEED)],
+    rewardVault: [Buffer.from(REWARD_VAULT_SEED)],
+    stakeAccount: [Buffer.from(STAKE_SEED), wallet.publicKey.toBuffer()],
+  });
+
+  const pdasFromTransferProgram = findPDAs(transfer_program, {
+    extraAccountMetaList: [Buffer.from(EXTRA_ACCOUNT_METAS), pdaMap.mint.toBuffer()],
+  });
+
+  pdaMap = { ...pdaMap, ...pdasFromTransferProgram };
+
+  const usdcMint = new anchor.web3.PublicKey(USDC_MINT_ADDRESS);
+  const ataMap = findATAs(pdaMap.mint, {
+    payer: wallet.publicKey,
+    feeCollector: feeCollector.publicKey,
+  });
+  const payerATA = ataMap.payer;
+
+  const initFeesArgs = {
+    mintFeeBps: 100,
+    transferFeeBps: 100,
+    redemptionFeeBps: 100,
+    feeCollector: ataMap.feeCollector,
+  };
+
+  const stakeAmount = toBN(2, RWD_DECIMALS);
+  const fundAmount = toBN(1, RWD_DECIMALS);
+
+  it("Initializes the reward pool", async () => {
+    await initializeAll(program, transfer_program, wallet, usdcMint, initFeesArgs, pdaMap);
+    await mintTokens(program, wallet, toBN(10, RWD_DECIMALS), payerATA, pdaMap, feeCollector.publicKey, ataMap.feeCollector, usdcMint);
+
+    const outsider = anchor.web3.Keypair.generate();
+    await airdropIfRequired(connection, outsider.publicKey, anchor.web3.LAMPORTS_PER_SOL, anchor.web3.LAMPORTS_PER_SOL);
+    await assert.rejects(
+      program.methods
+        .initializeRewardPool()
+        .accountsStrict({
+          signer: outsider.publicKey,
+          mint: pdaMap.mint,
+          rewardPool: pdaMap.rewardPool,
+          stakeVault: pdaMap.stakeVault,
+          rewardVault: pdaMap.rewardVault,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([outsider])
+        .rpc(),
+      /Unauthorized/,
+      "Only the mint authority should create the reward pool"
+    );
+
+    await program.methods
+      .initializeRewardPool()
+      .accountsStrict({
+        signer: wallet.publicKey,
+        mint: pdaMap.mint,
+        rewardPool: pdaMap.rewardPool,
+        stakeVault: pdaMap.stakeVault,
+        rewardVault: pdaMap.rewardVault,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+    const rewardPool = await program.account.rewardPool.fetch(pdaMap.rewardPool);
+    assert.equal(rewardPool.totalStaked.toNumber(), 0, "Pool should start empty");
+  });
+
+  it("Stakes RWD", async () => {
+    await program.methods
+      .stake(stakeAmount)
+      .accountsStrict({
+        owner: wallet.publicKey,
+        rewardPool: pdaMap.rewardPool,
+        stakeAccount: pdaMap.stakeAccount,
+        mint: pdaMap.mint,
+        ownerAta: payerATA,
+        stakeVault: pdaMap.stakeVault,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+      })
+      .remainingAccounts(await getTransferHookRemainingAccounts(
+        connection, payerATA, pdaMap.mint, pdaMap.stakeVault, wallet.publicKey, stakeAmount
+      ))
+      .rpc();
+
+    const stakeAccount = await program.account.stakeAccount.fetch(pdaMap.stakeAccount);
+    assert.ok(stakeAccount.amount.eq(stakeAmount), "Stake account should record the stake");
+    assert.ok((await getTokenBalance(connection, pdaMap.stakeVault)).eq(stakeAmount), "Vault should hold the stake");
+  });
+
+  it("Funds the pool and lets the staker claim", async () => {
+    await program.methods
+      .fundRewardPool(fundAmount)
+      .accountsStrict({
+        funder: wallet.publicKey,
+        rewardPool: pdaMap.rewardPool,
+        mint: pdaMap.mint,
+        funderAta: payerATA,
+        rewardVault: pdaMap.rewardVault,
+        tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+      })
+      .remainingAccounts(await getTransferHookRemainingAccounts(
+        connection, payerATA, pdaMap.mint, pdaMap.rewardVault, wallet.publicKey, fundAmount
+      ))
+      .rpc();
+
+    const balanceBefore = await getTokenBalance(connection, payerATA);
+
+    await program.methods
+      .claimRewards()
+      .accountsStrict({
+        owner: wallet.publicKey,
+        rewardPool: pdaMap.rewardPool,
+        stakeAccount: pdaMap.stakeAccount,
+        mint: pdaMap.mint,
+        ownerAta: payerATA,
+        rewardVault: pdaMap.rewardVault,
+        tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+      })
+      .remainingAccounts(await getTransferHookRemainingAccounts(
+        connection, pdaMap.rewardVault, pdaMap.mint, payerATA, pdaMap.rewardPool, fundAmount
+      ))
+      .rpc();
+
+    const balanceAfter = await getTokenBalance(connection, payerATA);
+    assert.ok(balanceAfter.eq(balanceBefore.add(fundAmount)), "Sole staker should receive the whole funding");
+  });
+
+  it("Unstakes RWD", async () => {
+    const balanceBefore = await getTokenBalance(connection, payerATA);
+
+    await program.methods
+      .unstake(stakeAmount)
+      .accountsStrict({
+        owner: wallet.publicKey,
+        rewardPool: pdaMap.rewardPool,
+        stakeAccount: pdaMap.stakeAccount,
+        mint: pdaMap.mint,
+        ownerAta: payerATA,
+        stakeVault: pdaMap.stakeVault,
+        tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+      })
+      .remainingAccounts(await getTransferHookRemainingAccounts(
+        connection, pdaMap.stakeVault, pdaMap.mint, payerATA, pdaMap.rewardPool, stakeAmount
+      ))
+      .rpc();
+
+    const balanceAfter = await getTokenBalance(connection, payerATA);
+    assert.ok(balanceAfter.eq(balanceBefore.add(stakeAmount)), "Stake should be returned");
+
+    const rewardPool = await program.account.rewardPool.fetch(pdaMap.rewardPool);
+    assert.equal(rewardPool.totalStaked.toNumber(), 0, "Pool should be empty again");
+
+    try {
+      await program.methods
+        .claimRewards()
+        .accountsStrict({
+          owner: wallet.publicKey,
+          rewardPool: pdaMap.rewardPool,
+          stakeAccount: pdaMap.stakeAccount,
+          mint: pdaMap.mint,
+          ownerAta: payerATA,
+          rewardVault: pdaMap.rewardVault,
+          tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+        })
+        .rpc();
+      assert.fail("Should not claim twice");
+    } catch (err) {
+      assert.ok(err.toString().includes("NothingToClaim"));
+    }
+  });
+});
@@ -12,6 +12,7 @@ import {
   createAssociatedTokenAccountInstruction,
   createMintToInstruction,
   getMint,
+  createMint,
   createTransferCheckedInstruction,
 } from "@solana/spl-token";
 import { makeKeypairs } from "@solana-developers/helpers"
@@ -20,14 +21,16 @@ import {
   EXTRA_ACCOUNT_METAS,
   FEES_SEED,
   FREEZE_SEED,
+  OWNER_STATS_SEED,
   TOKEN_2022_SEED,
+  TRANSFER_STATS_SEED,
   USDC_MINT_ADDRESS,
   USDC_SEED,
   USER_WHITELIST_SEED,
   RWD_DECIMALS
 } from "../utils/constants";
 import { findATAs, findPDAs, getProvider, toBN } from "../utils/setup";
-import { initializeAll } from "../utils/initialization";
+import { hookConfigPDA, initializeAll } from "../utils/initialization";
 import { transferTokens } from "../utils/transfer";
 import type { Rewards } from '../target/types/rewards';
 import type { TransferHook } from "../target/types/transfer_hook";
@@ -54,6 +57,9 @@ describe("transfer-hook", () => {
   const pdasFromTransferProgram = findPDAs(transfer_program, {
     extraAccountMetaList: [Buffer.from(EXTRA_ACCOUNT_METAS), pdaMap.mint.toBuffer()],
     whitelist: [Buffer.from(USER_WHITELIST_SEED)],
+    transferStats: [Buffer.from(TRANSFER_STATS_SEED)],
+    payerStats: [Buffer.from(OWNER_STATS_SEED), wallet.publicKey.toBuffer()],
+    receiverStats: [Buffer.from(OWNER_STATS_SEED), receiver.publicKey.toBuffer()],
 });
 
   pdaMap = { ...pdaMap, ...pdasFromTransferProgram };
@@ -178,4 +184,116 @@ describe("transfer-hook", () => {
     await transferTokens(transfer_program, wallet, transferAmount, sourceTokenAccount, destinationTokenAccount, pdaMap);
   });
 
-});
\ No newline at end of file
+  it("Update Extra Account Meta List", async () => {
+    await transfer_program.methods
+      .updateExtraAccountMetaList()
+      .accountsStrict({
+        authority: wallet.publicKey,
+        mint: pdaMap.mint,
+        extraAccountMetaList: pdaMap.extraAccountMetaList,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        hookConfig: hookConfigPDA(transfer_program),
+      })
+      .rpc();
+
+    try {
+      await transfer_program.methods
+        .updateExtraAccountMetaList()
+        .accountsStrict({
+          authority: receiver.publicKey,
+          mint: pdaMap.mint,
+          extraAccountMetaList: pdaMap.extraAccountMetaList,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          hookConfig: hookConfigPDA(transfer_program),
+        })
+        .signers([receiver])
+        .rpc();
+      assert.fail("Only the hook config authority should update the list");
+    } catch (err) {
+      assert.ok(err.toString().includes("Unauthorized"));
+    }
+
+    await transferTokens(transfer_program, wallet, transferAmount, sourceTokenAccount, destinationTokenAccount, pdaMap);
+  });
+
+  it("Rejects Meta Lists for Other Mints", async () => {
+    const otherMint = await createMint(
+      connection,
+      wallet.payer,
+      wallet.publicKey,
+      null,
+      RWD_DECIMALS,
+      undefined,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    const [otherMetaList] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(EXTRA_ACCOUNT_METAS), otherMint.toBuffer()],
+      transfer_program.programId
+    );
+
+    try {
+      await transfer_program.methods
+        .initializeExtraAccountMetaList()
+        .accountsStrict({
+          signer: wallet.publicKey,
+          mint: otherMint,
+          extraAccountMetaList: otherMetaList,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          hookConfig: hookConfigPDA(transfer_program),
+        })
+        .rpc();
+      assert.fail("Only the rewards mint can register a meta list");
+    } catch (err) {
+      assert.ok(err.toString().includes("MintNotRegistered"));
+    }
+  });
+
+  it("Tracks Transfer Stats", async () => {
+    if (!(await connection.getAccountInfo(pdaMap.transferStats))) {
+      await transfer_program.methods
+        .initTransferStats()
+        .accountsStrict({
+          payer: wallet.publicKey,
+          transferStats: pdaMap.transferStats,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+    }
+    for (const [owner, ownerStats] of [
+      [wallet.publicKey, pdaMap.payerStats],
+      [receiver.publicKey, pdaMap.receiverStats],
+    ]) {
+      await transfer_program.methods
+        .initOwnerStats()
+        .accountsStrict({
+          payer: wallet.publicKey,
+          owner,
+          ownerStats,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+    }
+
+    const before = await transfer_program.account.transferStats.fetch(pdaMap.transferStats);
+    await transferTokens(transfer_program, wallet, transferAmount, sourceTokenAccount, destinationTokenAccount, pdaMap);
+
+    const after = await transfer_program.account.transferStats.fetch(pdaMap.transferStats);
+    assert.equal(after.totalTransfers.sub(before.totalTransfers).toNumber(), 1);
+    assert.ok(after.totalVolume.sub(before.totalVolume).eq(transferAmount));
+    // RWD has no Token-2022 transfer fee, so nothing is withheld
+    assert.ok(after.totalFees.eq(before.totalFees));
+    // The payer has an OwnerStats account, so it counts as a unique sender
+    assert.ok(after.epochUniqueSenders.gten(1));
+
+    const payerStats = await transfer_program.account.ownerStats.fetch(pdaMap.payerStats);
+    const receiverStats = await transfer_program.account.ownerStats.fetch(pdaMap.receiverStats);
+    assert.equal(payerStats.sentCount.toNumber(), 1);
+    assert.ok(payerStats.sentAmount.eq(transferAmount));
+    assert.equal(receiverStats.receivedCount.toNumber(), 1);
+    assert.ok(receiverStats.lastActivitySlot.gtn(0));
+  });
+
+});
@@ -0,0 +1,126 @@
+import assert from "assert";
+
+import * as anchor from "@coral-xyz/anchor";
+import { makeKeypairs } from "@solana-developers/helpers";
+
+import {
+  EXTRA_ACCOUNT_METAS,
+  FEES_SEED,
+  FREEZE_SEED,
+  RWD_DECIMALS,
+  TOKEN_2022_SEED,
+  TRANSFER_COUNTER_SEED,
+  TRANSFER_LIMITS_SEED,
+  USDC_MINT_ADDRESS,
+  USDC_SEED,
+} from "../utils/constants";
+import { findATAs, findPDAs, getProvider, toBN } from "../utils/setup";
+import { initializeAll } from "../utils/initialization";
+import { mintTokens } from "../utils/mint";
+import { transferTokens } from "../utils/transfer";
+import type { Rewards } from "../target/types/rewards";
+import type { TransferHook } from "../target/types/transfer_hook";
+
+describe("transfer limits", () => {
+  const provider = getProvider();
+  const program = anchor.workspace.Rewards as anchor.Program<Rewards>;
+  const transfer_program = anchor.workspace.TransferHook as anchor.Program<TransferHook>;
+  const wallet = provider.wallet as anchor.Wallet;
+
+  const [feeCollector] = makeKeypairs(1);
+
+  let pdaMap = findPDAs(program, {
+    mint: [Buffer.from(TOKEN_2022_SEED)],
+    usdcKeeper: [Buffer.from(USDC_SEED)],
+    fees: [Buffer.from(FEES_SEED)],
+    freezeState: [Buffer.from(FREEZE_SEED)],
+    transferLimits: [Buffer.from(TRANSFER_LIMITS_SEED)],
+  });
+
+  pdaMap = {
+    ...pdaMap,
+    ...findPDAs(transfer_program, {
+      extraAccountMetaList: [Buffer.from(EXTRA_ACCOUNT_METAS), pdaMap.mint.toBuffer()],
+      transferCounter: [Buffer.from(TRANSFER_COUNTER_SEED), wallet.publicKey.toBuffer()],
+    }),
+  };
+
+  const usdcMint = new anchor.web3.PublicKey(USDC_MINT_ADDRESS);
+  const ataMap = findATAs(pdaMap.mint, {
+    payer: wallet.publicKey,
+    feeCollector: feeCollector.publicKey,
+  });
+  const payerATA = ataMap.payer;
+
+  const initFeesArgs = {
+    mintFeeBps: 100,
+    transferFeeBps: 100,
+    redemptionFeeBps: 100,
+    feeCollector: ataMap.feeCollector,
+  };
+
+  const limitsAccounts = {
+    authority: wallet.publicKey,
+    transferLimits: pdaMap.transferLimits,
+  };
+
+  it("Enforces per-transfer and per-epoch limits", async () => {
+    await initializeAll(program, transfer_program, wallet, usdcMint, initFeesArgs, pdaMap);
+    await mintTokens(program, wallet, toBN(10, RWD_DECIMALS), payerATA, pdaMap, feeCollector.publicKey, ataMap.feeCollector, usdcMint);
+
+    await program.methods
+      .initializeTransferLimits({
+        perTransferMax: toBN(2, RWD_DECIMALS),
+        epochLimit: toBN(3, RWD_DECIMALS),
+        epochDuration: new anchor.BN(86_400),
+      })
+      .accountsStrict({
+        signer: wallet.publicKey,
+        mint: pdaMap.mint,
+        transferLimits: pdaMap.transferLimits,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    // Without a counter a transfer is checked as the first of the epoch
+    await transferTokens(transfer_program, wallet, toBN(1, RWD_DECIMALS), payerATA, ataMap.feeCollector, pdaMap);
+
+    await transfer_program.methods
+      .initTransferCounter()
+      .accountsStrict({
+        payer: wallet.publicKey,
+        owner: wallet.publicKey,
+        transferCounter: pdaMap.transferCounter,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    try {
+      await transferTokens(transfer_program, wallet, toBN(3, RWD_DECIMALS), payerATA, ataMap.feeCollector, pdaMap);
+      assert.fail("Transfer above the per-transfer maximum should fail");
+    } catch (err) {
+      assert.ok(err.toString().includes("0x177a"), "Hook should reject with TransferLimitExceeded");
+    }
+
+    await transferTokens(transfer_program, wallet, toBN(2, RWD_DECIMALS), payerATA, ataMap.feeCollector, pdaMap);
+    try {
+      await transferTokens(transfer_program, wallet, toBN(2, RWD_DECIMALS), payerATA, ataMap.feeCollector, pdaMap);
+      assert.fail("Transfer above the epoch limit should fail");
+    } catch (err) {
+      assert.ok(err.toString().includes("0x177a"), "Hook should reject with TransferLimitExceeded");
+    }
+
+    await program.methods.addTransferLimitExemption(wallet.publicKey).accountsStrict(limitsAccounts).rpc();
+    await transferTokens(transfer_program, wallet, toBN(2, RWD_DECIMALS), payerATA, ataMap.feeCollector, pdaMap);
+
+    await program.methods.removeTransferLimitExemption(wallet.publicKey).accountsStrict(limitsAccounts).rpc();
+    await program.methods
+      .updateTransferLimits({
+        perTransferMax: new anchor.BN(0),
+        epochLimit: new anchor.BN(0),
+        epochDuration: null,
+      })
+      .accountsStrict(limitsAccounts)
+      .rpc();
+  });
+});
@@ -0,0 +1,164 @@
+import assert from "assert";
+
+import * as anchor from "@coral-xyz/anchor";
+import { TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { makeKeypairs } from "@solana-developers/helpers";
+
+import {
+  EXTRA_ACCOUNT_METAS,
+  FEES_SEED,
+  FREEZE_SEED,
+  RWD_DECIMALS,
+  TOKEN_2022_SEED,
+  USDC_MINT_ADDRESS,
+  USDC_SEED,
+  VESTING_ESCROW_SEED,
+  VESTING_SEED,
+} from "../utils/constants";
+import { findATAs, findPDAs, getProvider, getTokenBalance, toBN } from "../utils/setup";
+import { initializeAll } from "../utils/initialization";
+import { mintTokens } from "../utils/mint";
+import { getTransferHookRemainingAccounts } from "../utils/transfer";
+import type { Rewards } from "../target/types/rewards";
+import type { TransferHook } from "../target/types/transfer_hook";
+
+describe("vesting", () => {
+  const provider = getProvider();
+  const program = anchor.workspace.Rewards as anchor.Program<Rewards>;
+  const transfer_program = anchor.workspace.TransferHook as anchor.Program<TransferHook>;
+  const connection = provider.connection;
+  const wallet = provider.wallet as anchor.Wallet;
+
+  const [feeCollector] = makeKeypairs(1);
+
+  const scheduleId = new anchor.BN(1);
+  const totalAmount = toBN(4, RWD_DECIMALS);
+
+  let pdaMap = findPDAs(program, {
+    mint: [Buffer.from(TOKEN_2022_SEED)],
+    usdcKeeper: [Buffer.from(USDC_SEED)],
+    fees: [Buffer.from(FEES_SEED)],
+    freezeState: [Buffer.from(FREEZE_SEED)],
+    schedule: [Buffer.from(VESTING_SEED), wallet.publicKey.toBuffer(), scheduleId.toArrayLike(Buffer, "le", 8)],
+  });
+
+  pdaMap = {
+    ...pdaMap,
+    ...findPDAs(program, {
+      escrow: [Buffer.from(VESTING_ESCROW_SEED), pdaMap.schedule.toBuffer()],
+    }),
+    ...findPDAs(transfer_program, {
+      extraAccountMetaList: [Buffer.from(EXTRA_ACCOUNT_METAS), pdaMap.mint.toBuffer()],
+    }),
+  };
+
+  const usdcMint = new anchor.web3.PublicKey(USDC_MINT_ADDRESS);
+  const ataMap = findATAs(pdaMap.mint, {
+    payer: wallet.publicKey,
+    feeCollector: feeCollector.publicKey,
+  });
+  const payerATA = ataMap.payer;
+
+  const initFeesArgs = {
+    mintFeeBps: 100,
+    transferFeeBps: 100,
+    redemptionFeeBps: 100,
+    feeCollector: ataMap.feeCollector,
+  };
+
+  const now = Math.floor(Date.now() / 1000);
+  const createAccounts = {
+    authority: wallet.publicKey,
+    beneficiary: wallet.publicKey,
+    schedule: pdaMap.schedule,
+    escrow: pdaMap.escrow,
+    mint: pdaMap.mint,
+    authorityAta: payerATA,
+    systemProgram: anchor.web3.SystemProgram.programId,
+    tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+  };
+
+  it("Rejects a cliff after the end of the schedule", async () => {
+    await initializeAll(program, transfer_program, wallet, usdcMint, initFeesArgs, pdaMap);
+    await mintTokens(program, wallet, toBN(10, RWD_DECIMALS), payerATA, pdaMap, feeCollector.publicKey, ataMap.feeCollector, usdcMint);
+
+    try {
+      await program.methods
+        .createVesting({
+          scheduleId,
+          totalAmount,
+          startTs: new anchor.BN(now),
+          cliffTs: new anchor.BN(now + 2000),
+          endTs: new anchor.BN(now + 1000),
+        })
+        .accountsStrict(createAccounts)
+        .rpc();
+      assert.fail("Should not accept an invalid schedule");
+    } catch (err) {
+      assert.ok(err.toString().includes("InvalidVestingSchedule"));
+    }
+  });
+
+  it("Escrows a grant and releases the vested part", async () => {
+    await program.methods
+      .createVesting({
+        scheduleId,
+        totalAmount,
+        startTs: new anchor.BN(now - 1000),
+        cliffTs: new anchor.BN(now - 500),
+        endTs: new anchor.BN(now + 1000),
+      })
+      .accountsStrict(createAccounts)
+      .remainingAccounts(await getTransferHookRemainingAccounts(
+        connection, payerATA, pdaMap.mint, pdaMap.escrow, wallet.publicKey, totalAmount
+      ))
+      .rpc();
+
+    assert.ok((await getTokenBalance(connection, pdaMap.escrow)).eq(totalAmount), "Escrow should hold the grant");
+
+    await program.methods
+      .claimVested()
+      .accountsStrict({
+        beneficiary: wallet.publicKey,
+        schedule: pdaMap.schedule,
+        escrow: pdaMap.escrow,
+        mint: pdaMap.mint,
+        beneficiaryAta: payerATA,
+        tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+      })
+      .remainingAccounts(await getTransferHookRemainingAccounts(
+        connection, pdaMap.escrow, pdaMap.mint, payerATA, pdaMap.schedule, totalAmount
+      ))
+      .rpc();
+
+    const schedule = await program.account.vestingSchedule.fetch(pdaMap.schedule);
+    assert.ok(schedule.releasedAmount.gtn(0), "Some RWD should have vested");
+    assert.ok(schedule.releasedAmount.lt(totalAmount), "Not everything should have vested yet");
+  });
+
+  it("Revokes the unvested remainder", async () => {
+    await program.methods
+      .revokeVesting()
+      .accountsStrict({
+        authority: wallet.publicKey,
+        schedule: pdaMap.schedule,
+        escrow: pdaMap.escrow,
+        mint: pdaMap.mint,
+        authorityAta: payerATA,
+        tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+      })
+      .remainingAccounts(await getTransferHookRemainingAccounts(
+        connection, pdaMap.escrow, pdaMap.mint, payerATA, pdaMap.schedule, totalAmount
+      ))
+      .rpc();
+
+    const schedule = await program.account.vestingSchedule.fetch(pdaMap.schedule);
+    assert.ok(schedule.revoked, "Schedule should be revoked");
+
+    const escrowBalance = await getTokenBalance(connection, pdaMap.escrow);
+    assert.ok(
+      escrowBalance.eq(schedule.totalAmount.sub(schedule.releasedAmount)),
+      "Only vested but unclaimed RWD should remain in escrow"
+    );
+  });
+});
@@ -8,11 +8,13 @@ import {
 
 import { Rewards } from "../target/types/rewards";
 import { RWD_DECIMALS } from "./constants";
+import { findBurnLotAccounts } from "./setup";
 
 /**
  * Burns a specified amount of tokens from the sender's account.
  *
  * @param {anchor.Program} program - The Anchor program instance.
+ * @param {PublicKey} transferHookProgramId - The transfer hook program keeping point lots.
  * @param {anchor.Wallet} wallet - The user's wallet.
  * @param {anchor.BN} amount - The amount of tokens to burn.
  * @param {PublicKey} payerATA - The user's token account (from which tokens are burned).
@@ -22,6 +24,7 @@ import { RWD_DECIMALS } from "./constants";
  */
 export async function burnTokens(
     program: anchor.Program<Rewards>,
+    transferHookProgramId: PublicKey,
     wallet: anchor.Wallet,
     amount: anchor.BN,
     payerATA: PublicKey,
@@ -47,6 +50,7 @@ export async function burnTokens(
             usdcToAta,
             fees: pdaMap.fees,
             feeCollector,
+            ...findBurnLotAccounts(program.programId, transferHookProgramId, wallet.publicKey),
             systemProgram: anchor.web3.SystemProgram.programId,
             tokenProgram: TOKEN_PROGRAM_ID,
             tokenProgram2022: TOKEN_2022_PROGRAM_ID,
@@ -6,6 +6,29 @@ export const USDC_SEED = "usdc";
 export const EXTRA_ACCOUNT_METAS = "extra-account-metas";
 export const USER_WHITELIST_SEED = "user-whitelist";
 export const FREEZE_SEED = "freeze";
+export const REWARD_POOL_SEED = "reward-pool";
+export const STAKE_VAULT_SEED = "stake-vault";
+export const REWARD_VAULT_SEED = "reward-vault";
+export const STAKE_SEED = "stake";
+export const CAMPAIGN_SEED = "campaign";
+export const CAMPAIGN_VAULT_SEED = "campaign-vault";
+export const CLAIM_SEED = "claim";
+export const VESTING_SEED = "vesting";
+export const VESTING_ESCROW_SEED = "vesting-escrow";
+export const MERCHANT_SEED = "merchant";
+export const MERCHANT_BUDGET_SEED = "merchant-budget";
+export const POINTS_EXPIRY_SEED = "points-expiry";
+export const PERMANENT_DELEGATE_SEED = "permanent-delegate";
+export const LOT_LEDGER_SEED = "lot-ledger";
+export const TRANSFER_POLICY_SEED = "transfer-policy";
+export const FREEZE_AUTHORITY_SEED = "freeze-authority";
+export const COMPLIANCE_SEED = "compliance";
+export const BLACKLIST_SEED = "blacklist";
+export const TRANSFER_LIMITS_SEED = "transfer-limits";
+export const TRANSFER_COUNTER_SEED = "transfer-counter";
+export const HOOK_CONFIG_SEED = "hook-config";
+export const TRANSFER_STATS_SEED = "transfer-stats";
+export const OWNER_STATS_SEED = "owner-stats";
 
 
 // Mainnet - EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v
@@ -17,4 +40,18 @@ export const metadata = {
     symbol: "RWD",
     uri: "https://devnet.irys.xyz/71M9GquPesJ9LyiGiJKFxveood8kY6GqHP92GS2YvQrE",
     decimals: RWD_DECIMALS,
-}
\ No newline at end of file
+}
+
+// DAO program seeds
+export const TREASURY_SEED = "treasury";
+export const TREASURY_RWD_SEED = "treasury-rwd";
+export const TREASURY_USDC_SEED = "treasury-usdc";
+export const STREAM_SEED = "stream";
+export const DEPOSIT_SEED = "deposit";
+export const VOTE_SEED = "vote";
+export const RANKED_TALLY_SEED = "ranked-tally";
+export const MEMBER_SEED = "member";
+export const LOCK_VAULT_SEED = "lock-vault";
+export const MIN_LOCK_DURATION = 7 * 24 * 60 * 60;
+export const DAO_CONFIG_SEED = "dao-config";
+export const DELEGATION_SEED = "delegation";
@@ -10,6 +10,7 @@ import { TransferHook } from "../target/types/transfer_hook";
  * @param {PublicKey} mint - The mint account whose freeze state is being modified.
  * @param {anchor.Wallet} wallet - The admin wallet signing the transaction.
  * @param {FreezeTarget} target - Which operation to freeze.
+ * @param {anchor.BN | null} duration - Seconds until the freeze lapses; null freezes until unfrozen, which isn't allowed while a maximum pause duration is set. Refreezing can't push the lapse past the cap counted from when the freeze started; use `extendFreeze` for that.
  * @returns {Promise<string>} - Transaction signature.
  */
 export const freezeOperation = async (
@@ -17,12 +18,13 @@ export const freezeOperation = async (
     wallet: anchor.Wallet | anchor.web3.Keypair,
     target: any,
     pdaMap: Record<string, PublicKey>,
+    duration: anchor.BN | null = null,
 ): Promise<string> => {
     const signer = wallet instanceof anchor.Wallet ? wallet.payer : wallet;
     const connection = program.provider.connection;
     const tx = new anchor.web3.Transaction();
 
-    const ix = await program.methods.freeze(target)
+    const ix = await program.methods.freeze(target, duration)
         .accountsStrict({
             freezeState: pdaMap.freezeState,
             mint: pdaMap.mint,
@@ -1,4 +1,3 @@
-import assert from "assert";
 import path from "path";
 import { readFileSync } from "fs";
 import * as toml from "toml";
@@ -7,7 +6,14 @@ import * as anchor from "@coral-xyz/anchor";
 import { sendAndConfirmTransaction, PublicKey, SystemProgram } from "@solana/web3.js";
 import { ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID } from "@solana/spl-token";
 
-import { RWD_DECIMALS } from "../utils/constants";
+import {
+    COMPLIANCE_SEED,
+    FREEZE_AUTHORITY_SEED,
+    HOOK_CONFIG_SEED,
+    PERMANENT_DELEGATE_SEED,
+    RWD_DECIMALS,
+    TRANSFER_POLICY_SEED,
+} from "../utils/constants";
 import type { Rewards } from "../target/types/rewards";
 import type { TransferHook } from "../target/types/transfer_hook";
 
@@ -16,119 +22,151 @@ const anchorTomlPath = path.resolve(__dirname, '../Anchor.toml');
 const anchorToml = readFileSync(anchorTomlPath, 'utf-8');  
 const parsedToml = toml.parse(anchorToml); 
 
-export const initializeMint = async (
-    mint_program: anchor.Program<Rewards>,
+const tokenMetadata = () => ({
+    name: "Rewards Token",
+    symbol: "RWD",
+    uri: "https://f47c2zywkjkof3eoprv7wvdzb5umkf36cdp6gusrwxosxvxj5v3q.arweave.net/Lz4tZxZSVOLsjnxr-1R5D2jFF34Q3-NSUbXdK9bp7Xc",
+    decimals: RWD_DECIMALS,
+    transferHookProgramId: new PublicKey(parsedToml.programs.localnet.transfer_hook),
+    soulbound: false,
+});
+
+// PDAs the mint points at for its permanent delegate and freeze authority, plus the transfer policy
+const mintRolePDAs = (mint_program: anchor.Program<Rewards>) => {
+    const [permanentDelegate] = PublicKey.findProgramAddressSync(
+        [Buffer.from(PERMANENT_DELEGATE_SEED)],
+        mint_program.programId
+    );
+    const [freezeAuthority] = PublicKey.findProgramAddressSync(
+        [Buffer.from(FREEZE_AUTHORITY_SEED)],
+        mint_program.programId
+    );
+    const [transferPolicy] = PublicKey.findProgramAddressSync(
+        [Buffer.from(TRANSFER_POLICY_SEED)],
+        mint_program.programId
+    );
+    return { permanentDelegate, freezeAuthority, transferPolicy };
+};
+
+export const initializeFreeze = async (
+    program: anchor.Program<Rewards>,
     wallet: anchor.Wallet,
-    usdcMint: PublicKey,
     pdaMap: Record<string, anchor.web3.PublicKey>
-) => {
-    const connection = mint_program.provider.connection;
-
-    const mintInfo = await connection.getAccountInfo(pdaMap.mint);
-    if (mintInfo) {
-        console.log("Mint already exists, skipping initialization.");
-        return;
-    }
+): Promise<boolean> => {
+    const connection = program.provider.connection;
+    const freezeStateInfo = await connection.getAccountInfo(pdaMap.freezeState);
 
-    const transferHookProgramId = parsedToml.programs.localnet.transfer_hook
-    const metadata = {
-        name: "Rewards Token",
-        symbol: "RWD",
-        uri: "https://f47c2zywkjkof3eoprv7wvdzb5umkf36cdp6gusrwxosxvxj5v3q.arweave.net/Lz4tZxZSVOLsjnxr-1R5D2jFF34Q3-NSUbXdK9bp7Xc",
-        decimals: RWD_DECIMALS,
-        transferHookProgramId: new PublicKey(transferHookProgramId),
+    if (freezeStateInfo) {
+        console.log("Freeze Account already already exists, skipping initialization.");
+        return true;
     }
 
-    const ix = await mint_program.methods
-        .initializeToken(metadata)
+    const ix = await program.methods
+        .initializeFreeze()
         .accountsStrict({
             signer: wallet.publicKey,
-            mint: pdaMap.mint,
-            usdcMint,
-            usdcKeeper: pdaMap.usdcKeeper,
             systemProgram: anchor.web3.SystemProgram.programId,
-            tokenProgram: TOKEN_PROGRAM_ID,
-            tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+            freezeState: pdaMap.freezeState,
+            mint: pdaMap.mint,
         })
         .instruction();
 
     const tx = new anchor.web3.Transaction().add(ix);
 
     const sig = await sendAndConfirmTransaction(connection, tx, [wallet.payer]);
-    console.log("Mint initialized:", sig);
+    console.log("Freeze Account initialized:", sig);
 };
 
-export const initializeFees = async (
-    program: anchor.Program<Rewards>,
+/**
+ * Creates mint, metadata, USDC vault, fees, freeze state and compliance roles in one instruction
+ */
+export const initializeProtocol = async (
+    mint_program: anchor.Program<Rewards>,
     wallet: anchor.Wallet,
+    usdcMint: PublicKey,
     initFeesArgs: {
         mintFeeBps: number;
         transferFeeBps: number;
         redemptionFeeBps: number;
         feeCollector: anchor.web3.PublicKey;
     },
+    complianceOfficer: PublicKey,
     pdaMap: Record<string, anchor.web3.PublicKey>
-): Promise<boolean> => {
-    const connection = program.provider.connection;
-    const feesInfo = await connection.getAccountInfo(pdaMap.fees);
+) => {
+    const connection = mint_program.provider.connection;
 
-    if (feesInfo) {
-        console.log("Fees account already already exists, skipping initialization.");
-        return true;
-    }
+    const [compliance] = PublicKey.findProgramAddressSync(
+        [Buffer.from(COMPLIANCE_SEED)],
+        mint_program.programId
+    );
 
-    const ix = await program.methods
-        .initializeFees({
-            mintFeeBps: initFeesArgs.mintFeeBps,
-            transferFeeBps: initFeesArgs.transferFeeBps,
-            redemptionFeeBps: initFeesArgs.redemptionFeeBps,
-            feeCollector: initFeesArgs.feeCollector,
+    // Only the program's upgrade authority can initialize the protocol
+    const [programData] = PublicKey.findProgramAddressSync(
+        [mint_program.programId.toBuffer()],
+        new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+    );
+
+    const ix = await mint_program.methods
+        .initializeProtocol({
+            token: tokenMetadata(),
+            fees: initFeesArgs,
+            complianceOfficer,
         })
         .accountsStrict({
             signer: wallet.publicKey,
+            mint: pdaMap.mint,
+            ...mintRolePDAs(mint_program),
+            usdcMint,
+            usdcKeeper: pdaMap.usdcKeeper,
             fees: pdaMap.fees,
+            freezeState: pdaMap.freezeState,
+            compliance,
+            program: mint_program.programId,
+            programData,
             systemProgram: anchor.web3.SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            tokenProgram2022: TOKEN_2022_PROGRAM_ID,
         })
         .instruction();
 
     const tx = new anchor.web3.Transaction().add(ix);
 
     const sig = await sendAndConfirmTransaction(connection, tx, [wallet.payer]);
-    console.log("Fees initialized:", sig);
-
-    const newFeesData = await program.account.fees.fetch(pdaMap.fees);
-    assert(newFeesData, "Fees account should be initialized.");
-    return false;
+    console.log("Protocol initialized:", sig);
 };
 
+// Hook config binding the transfer hook to the rewards mint
+export const hookConfigPDA = (program: anchor.Program<TransferHook>) =>
+    PublicKey.findProgramAddressSync([Buffer.from(HOOK_CONFIG_SEED)], program.programId)[0];
 
-export const initializeFreeze = async (
-    program: anchor.Program<Rewards>,
+export const initializeHookConfig = async (
+    program: anchor.Program<TransferHook>,
     wallet: anchor.Wallet,
     pdaMap: Record<string, anchor.web3.PublicKey>
-): Promise<boolean> => {
+) => {
     const connection = program.provider.connection;
-    const freezeStateInfo = await connection.getAccountInfo(pdaMap.freezeState);
+    const hookConfig = hookConfigPDA(program);
 
-    if (freezeStateInfo) {
-        console.log("Freeze Account already already exists, skipping initialization.");
-        return true;
+    const hookConfigInfo = await connection.getAccountInfo(hookConfig);
+    if (hookConfigInfo) {
+        console.log("Hook config already exists, skipping initialization.");
+        return;
     }
 
     const ix = await program.methods
-        .intializeFreeze()
+        .initializeHookConfig()
         .accountsStrict({
             signer: wallet.publicKey,
-            systemProgram: anchor.web3.SystemProgram.programId,
-            freezeState: pdaMap.freezeState,
             mint: pdaMap.mint,
+            hookConfig,
+            systemProgram: SystemProgram.programId,
         })
         .instruction();
 
     const tx = new anchor.web3.Transaction().add(ix);
 
     const sig = await sendAndConfirmTransaction(connection, tx, [wallet.payer]);
-    console.log("Freeze Account initialized:", sig);
+    console.log("Hook config initialized:", sig);
 };
 
 export const initializeExtraAccountMetaList = async (
@@ -153,6 +191,7 @@ export const initializeExtraAccountMetaList = async (
             tokenProgram: TOKEN_2022_PROGRAM_ID,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
+            hookConfig: hookConfigPDA(program),
         })
         .instruction();
 
@@ -178,8 +217,13 @@ export const initializeAll = async (
     },
     pdaMap: Record<string, anchor.web3.PublicKey>) => {
 
-    await initializeMint(mint_program, wallet, usdcMint, pdaMap);
+    // A fresh validator gets everything in one atomic instruction, with the wallet as compliance officer
+    const mintInfo = await mint_program.provider.connection.getAccountInfo(pdaMap.mint);
+    if (!mintInfo) {
+        await initializeProtocol(mint_program, wallet, usdcMint, initFeesArgs, wallet.publicKey, pdaMap);
+    }
+
     await initializeFreeze(mint_program, wallet, pdaMap);
-    await initializeFees(mint_program, wallet, initFeesArgs, pdaMap);
+    await initializeHookConfig(transfer_program, wallet, pdaMap);
     await initializeExtraAccountMetaList(transfer_program, wallet, pdaMap);
 };
\ No newline at end of file
@@ -1,5 +1,6 @@
 import * as anchor from "@coral-xyz/anchor";
 import { PublicKey } from "@solana/web3.js";
+import { LOT_LEDGER_SEED, POINTS_EXPIRY_SEED } from "./constants";
 import {
   ASSOCIATED_TOKEN_PROGRAM_ID,
   TOKEN_2022_PROGRAM_ID,
@@ -54,4 +55,16 @@ export const toBN = (amount: number, decimals: number): anchor.BN => {
 
 export const calcFee = (amount: anchor.BN, feeBps: number): anchor.BN => {
   return amount.mul(new anchor.BN(feeBps)).div(new anchor.BN(10000));
-}
\ No newline at end of file
+}
+
+/**
+ * Accounts every RWD burn passes so the owner's point lots are consumed with it.
+ * @param rewardsProgramId The rewards program id.
+ * @param transferHookProgramId The transfer hook program id.
+ * @param owner Wallet whose tokens are burned.
+ */
+export const findBurnLotAccounts = (rewardsProgramId: PublicKey, transferHookProgramId: PublicKey, owner: PublicKey) => ({
+  pointsExpiry: PublicKey.findProgramAddressSync([Buffer.from(POINTS_EXPIRY_SEED)], rewardsProgramId)[0],
+  lotLedger: PublicKey.findProgramAddressSync([Buffer.from(LOT_LEDGER_SEED), owner.toBuffer()], transferHookProgramId)[0],
+  transferHookProgram: transferHookProgramId,
+});
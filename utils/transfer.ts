@@ -1,6 +1,7 @@
 import * as anchor from "@coral-xyz/anchor";
 import { PublicKey } from "@solana/web3.js";
 import {
+    createTransferCheckedWithTransferHookInstruction,
     transferCheckedWithTransferHook,
     TOKEN_2022_PROGRAM_ID,
 } from "@solana/spl-token";
@@ -44,4 +45,40 @@ export async function transferTokens(
     );
 
     return sig;
-}
\ No newline at end of file
+}
+
+/**
+ * Resolves the accounts Token-2022 needs to CPI into the transfer hook, so on-chain
+ * programs can move RWD out of their PDAs. Pass the result as `remainingAccounts`.
+ * @param connection - The cluster connection.
+ * @param source - The token account tokens are moved from.
+ * @param mint - The RWD mint.
+ * @param destination - The token account tokens are moved to.
+ * @param authority - The owner or delegate of the source account (usually a PDA).
+ * @param amount - The amount of tokens being transferred.
+ * @returns The extra account metas, hook program and meta list accounts.
+ */
+export async function getTransferHookRemainingAccounts(
+    connection: anchor.web3.Connection,
+    source: PublicKey,
+    mint: PublicKey,
+    destination: PublicKey,
+    authority: PublicKey,
+    amount: anchor.BN,
+): Promise<anchor.web3.AccountMeta[]> {
+    const ix = await createTransferCheckedWithTransferHookInstruction(
+        connection,
+        source,
+        mint,
+        destination,
+        authority,
+        BigInt(amount.toString()),
+        RWD_DECIMALS,
+        [],
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+    );
+
+    // Skip source, mint, destination and authority
+    return ix.keys.slice(4).map((meta) => ({ ...meta, isSigner: false }));
+}